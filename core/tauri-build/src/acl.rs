@@ -149,50 +149,6 @@ pub fn validate_capabilities(
   capabilities: &BTreeMap<String, Capability>,
 ) -> Result<()> {
   let target = tauri_utils::platform::Target::from_triple(&std::env::var("TARGET").unwrap());
-
-  for capability in capabilities.values() {
-    if !capability.platforms.contains(&target) {
-      continue;
-    }
-
-    for permission_entry in &capability.permissions {
-      let permission_id = permission_entry.identifier();
-      if let Some((plugin_name, permission_name)) = permission_id.get().split_once(':') {
-        let permission_exists = plugin_manifests
-          .get(plugin_name)
-          .map(|manifest| {
-            if permission_name == "default" {
-              manifest.default_permission.is_some()
-            } else {
-              manifest.permissions.contains_key(permission_name)
-                || manifest.permission_sets.contains_key(permission_name)
-            }
-          })
-          .unwrap_or(false);
-
-        if !permission_exists {
-          let mut available_permissions = Vec::new();
-          for (plugin, manifest) in plugin_manifests {
-            if manifest.default_permission.is_some() {
-              available_permissions.push(format!("{plugin}:default"));
-            }
-            for p in manifest.permissions.keys() {
-              available_permissions.push(format!("{plugin}:{p}"));
-            }
-            for p in manifest.permission_sets.keys() {
-              available_permissions.push(format!("{plugin}:{p}"));
-            }
-          }
-
-          anyhow::bail!(
-            "Permission {} not found, expected one of {}",
-            permission_id.get(),
-            available_permissions.join(", ")
-          );
-        }
-      }
-    }
-  }
-
-  Ok(())
+  tauri_utils::acl::build::validate_capabilities(plugin_manifests, capabilities, target)
+    .map_err(Into::into)
 }
@@ -101,7 +101,13 @@ impl CodegenContext {
       }
       _ => (),
     }
-    for icon in &config.tauri.bundle.icon {
+    for icon in config
+      .tauri
+      .bundle
+      .icon
+      .iter()
+      .chain(config.tauri.bundle.platform_icons.values().flatten())
+    {
       println!(
         "cargo:rerun-if-changed={}",
         config_parent.join(icon).display()
@@ -127,6 +133,8 @@ impl CodegenContext {
       // it's very hard to have a build script for unit tests, so assume this is always called from
       // outside the tauri crate, making the ::tauri root valid.
       root: quote::quote!(::tauri),
+      live_assets: false,
+      asset_chunking: false,
     })?;
 
     // get the full output file path
@@ -20,6 +20,7 @@ use heck::AsShoutySnakeCase;
 use tauri_utils::{
   acl::build::parse_capabilities,
   config::{BundleResources, Config, WebviewInstallMode},
+  platform::Target,
   resources::{external_binaries, ResourcePaths},
 };
 
@@ -424,7 +425,7 @@ pub fn try_build(attributes: Attributes) -> Result<()> {
   cfg_alias("mobile", mobile);
 
   let target_triple = std::env::var("TARGET").unwrap();
-  let target = tauri_utils::platform::Target::from_triple(&target_triple);
+  let target = Target::from_triple(&target_triple);
 
   let mut config = serde_json::from_value(tauri_utils::config::parse::read_from(
     target,
@@ -436,7 +437,7 @@ pub fn try_build(attributes: Attributes) -> Result<()> {
   }
   let config: Config = serde_json::from_value(config)?;
 
-  let s = config.tauri.bundle.identifier.split('.');
+  let s = config.tauri.bundle.identifier_for(target).split('.');
   let last = s.clone().count() - 1;
   let mut android_package_prefix = String::new();
   for (i, w) in s.enumerate() {
@@ -449,6 +450,7 @@ pub fn try_build(attributes: Attributes) -> Result<()> {
   println!("cargo:rustc-env=TAURI_ANDROID_PACKAGE_PREFIX={android_package_prefix}");
 
   if let Some(project_dir) = var_os("TAURI_ANDROID_PROJECT_PATH").map(PathBuf::from) {
+    mobile::generate_tauri_properties(&project_dir, &config)?;
     mobile::generate_gradle_files(project_dir)?;
   }
 
@@ -471,6 +473,16 @@ pub fn try_build(attributes: Attributes) -> Result<()> {
   let out_dir = PathBuf::from(std::env::var("OUT_DIR").unwrap());
 
   manifest::check(&config, &mut manifest)?;
+
+  if config.tauri.bundle.platform_icons.contains_key(&target) {
+    for icon in config.tauri.bundle.icons_for(target) {
+      if !Path::new(icon).exists() {
+        return Err(anyhow::anyhow!(
+          "`{icon}` not found; referenced by `tauri > bundle > platformIcons > {target}`"
+        ));
+      }
+    }
+  }
   let plugin_manifests = acl::get_plugin_manifests()?;
   std::fs::write(
     out_dir.join(PLUGIN_MANIFESTS_FILE_NAME),
@@ -564,7 +576,7 @@ pub fn try_build(attributes: Attributes) -> Result<()> {
       let icon_path = config
         .tauri
         .bundle
-        .icon
+        .icons_for(Target::Windows)
         .iter()
         .find(|i| predicate(i))
         .cloned()
@@ -10,6 +10,7 @@ use std::{
 
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use tauri_utils::config::Config;
 
 #[derive(Debug, Default, Deserialize, Serialize, Eq, PartialEq)]
 pub(crate) struct PluginMetadata {
@@ -290,6 +291,29 @@ dependencies {"
   Ok(())
 }
 
+/// Writes the `versionName` and `versionCode` read by the Android app's `build.gradle.kts`.
+pub(crate) fn generate_tauri_properties(project_dir: &Path, config: &Config) -> Result<()> {
+  let version = config
+    .package
+    .version
+    .clone()
+    .unwrap_or_else(|| var("CARGO_PKG_VERSION").unwrap());
+  let version_code = config.tauri.bundle.android.version_code_for(Some(&version));
+
+  let properties_path = project_dir.join("app").join("tauri.properties");
+  let properties = format!(
+    "# THIS IS AN AUTOGENERATED FILE. DO NOT EDIT THIS FILE DIRECTLY.
+tauri.android.versionName={version}
+tauri.android.versionCode={version_code}
+"
+  );
+  if read_to_string(&properties_path).ok().as_deref() != Some(properties.as_str()) {
+    write(&properties_path, properties).context("failed to write tauri.properties")?;
+  }
+
+  Ok(())
+}
+
 #[cfg(test)]
 mod tests {
   #[test]
@@ -32,6 +32,16 @@ pub struct ContextData {
   pub config: Config,
   pub config_parent: PathBuf,
   pub root: TokenStream,
+  /// Read the frontend assets from the `devPath` directory at runtime instead of embedding them,
+  /// see [`tauri_utils::assets::LiveAssets`].
+  ///
+  /// Ignored on release builds, which always embed the assets.
+  pub live_assets: bool,
+  /// Generate the embedded assets in several smaller statics instead of a single map.
+  ///
+  /// This speeds up the compilation and incremental rebuilds of apps with a large number of assets,
+  /// at the cost of a few more lookups when an asset is requested. The assets are otherwise identical.
+  pub asset_chunking: bool,
 }
 
 fn map_core_assets(
@@ -126,6 +136,8 @@ pub fn context_codegen(data: ContextData) -> Result<TokenStream, EmbeddedAssetsE
     config,
     config_parent,
     root,
+    live_assets,
+    asset_chunking,
   } = data;
 
   let target = std::env::var("TARGET")
@@ -163,6 +175,8 @@ pub fn context_codegen(data: ContextData) -> Result<TokenStream, EmbeddedAssetsE
     &config.build.dist_dir
   };
 
+  // the directory the assets are read from at runtime when using `assets = "live"`
+  let mut live_assets_path = None;
   let assets = match app_url {
     AppUrl::Url(url) => match url {
       WebviewUrl::External(_) => Default::default(),
@@ -181,7 +195,18 @@ pub fn context_codegen(data: ContextData) -> Result<TokenStream, EmbeddedAssetsE
             path
           )
         }
-        EmbeddedAssets::new(assets_path, &options, map_core_assets(&options, target))?
+        if dev && live_assets {
+          if let PatternKind::Isolation { .. } = config.tauri.pattern {
+            panic!("`assets = \"live\"` does not support the isolation pattern");
+          }
+          if !assets_path.is_dir() {
+            panic!("`assets = \"live\"` requires the `devPath` configuration to be a directory")
+          }
+          live_assets_path.replace(assets_path);
+          Default::default()
+        } else {
+          EmbeddedAssets::new(assets_path, &options, map_core_assets(&options, target))?
+        }
       }
       _ => unimplemented!(),
     },
@@ -194,6 +219,15 @@ pub fn context_codegen(data: ContextData) -> Result<TokenStream, EmbeddedAssetsE
       map_core_assets(&options, target),
     )?,
     _ => unimplemented!(),
+  }
+  .chunked(asset_chunking);
+
+  let assets = match live_assets_path {
+    Some(path) => {
+      let path = path.canonicalize().unwrap_or(path).display().to_string();
+      quote!(#root::utils::assets::LiveAssets::new(::std::path::PathBuf::from(#path)))
+    }
+    None => quote!(#assets),
   };
 
   let out_dir = {
@@ -214,6 +248,7 @@ pub fn context_codegen(data: ContextData) -> Result<TokenStream, EmbeddedAssetsE
       let icon_path = find_icon(
         &config,
         &config_parent,
+        target,
         |i| i.ends_with(".ico"),
         "icons/icon.ico",
       );
@@ -223,6 +258,7 @@ pub fn context_codegen(data: ContextData) -> Result<TokenStream, EmbeddedAssetsE
         let icon_path = find_icon(
           &config,
           &config_parent,
+          target,
           |i| i.ends_with(".png"),
           "icons/icon.png",
         );
@@ -233,6 +269,7 @@ pub fn context_codegen(data: ContextData) -> Result<TokenStream, EmbeddedAssetsE
       let icon_path = find_icon(
         &config,
         &config_parent,
+        target,
         |i| i.ends_with(".png"),
         "icons/icon.png",
       );
@@ -244,6 +281,7 @@ pub fn context_codegen(data: ContextData) -> Result<TokenStream, EmbeddedAssetsE
     let mut icon_path = find_icon(
       &config,
       &config_parent,
+      target,
       |i| i.ends_with(".icns"),
       "icons/icon.png",
     );
@@ -251,6 +289,7 @@ pub fn context_codegen(data: ContextData) -> Result<TokenStream, EmbeddedAssetsE
       icon_path = find_icon(
         &config,
         &config_parent,
+        target,
         |i| i.ends_with(".png"),
         "icons/icon.png",
       );
@@ -342,11 +381,11 @@ pub fn context_codegen(data: ContextData) -> Result<TokenStream, EmbeddedAssetsE
   let pattern = match &options.pattern {
     PatternKind::Brownfield => quote!(#root::Pattern::Brownfield(std::marker::PhantomData)),
     #[cfg(not(feature = "isolation"))]
-    PatternKind::Isolation { dir: _ } => {
+    PatternKind::Isolation { .. } => {
       quote!(#root::Pattern::Brownfield(std::marker::PhantomData))
     }
     #[cfg(feature = "isolation")]
-    PatternKind::Isolation { dir } => {
+    PatternKind::Isolation { dir, .. } => {
       let dir = config_parent.join(dir);
       if !dir.exists() {
         panic!("The isolation application path is set to `{dir:?}` but it does not exist")
@@ -532,13 +571,14 @@ fn write_if_changed(out_path: &Path, data: &[u8]) -> std::io::Result<()> {
 fn find_icon<F: Fn(&&String) -> bool>(
   config: &Config,
   config_parent: &Path,
+  target: Target,
   predicate: F,
   default: &str,
 ) -> PathBuf {
   let icon_path = config
     .tauri
     .bundle
-    .icon
+    .icons_for(target)
     .iter()
     .find(|i| predicate(i))
     .cloned()
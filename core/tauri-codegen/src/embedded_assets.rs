@@ -4,7 +4,7 @@
 
 use base64::Engine;
 use proc_macro2::TokenStream;
-use quote::{quote, ToTokens, TokenStreamExt};
+use quote::{format_ident, quote, ToTokens, TokenStreamExt};
 use sha2::{Digest, Sha256};
 use std::{
   collections::HashMap,
@@ -23,6 +23,12 @@ use brotli::enc::backward_references::BrotliEncoderParams;
 /// The subdirectory inside the target directory we want to place assets.
 const TARGET_PATH: &str = "tauri-codegen-assets";
 
+/// The maximum number of assets in a chunk when chunking is enabled.
+const ASSET_CHUNK_COUNT: usize = 64;
+
+/// The maximum size in bytes of the compressed assets of a chunk when chunking is enabled.
+const ASSET_CHUNK_SIZE: u64 = 4 * 1024 * 1024;
+
 /// (key, (original filepath, compressed bytes))
 type Asset = (AssetKey, (PathBuf, PathBuf));
 
@@ -74,6 +80,7 @@ pub enum EmbeddedAssetsError {
 pub struct EmbeddedAssets {
   assets: HashMap<AssetKey, (PathBuf, PathBuf)>,
   csp_hashes: CspHashes,
+  chunked: bool,
 }
 
 pub struct EmbeddedAssetsInput(Vec<PathBuf>);
@@ -276,7 +283,47 @@ impl EmbeddedAssets {
       },
     )?;
 
-    Ok(Self { assets, csp_hashes })
+    Ok(Self {
+      assets,
+      csp_hashes,
+      chunked: false,
+    })
+  }
+
+  /// Generate the assets in several smaller maps instead of a single one.
+  ///
+  /// A chunk holds at most 64 assets and 4 MiB of compressed assets, unless a single asset is larger.
+  #[must_use]
+  pub fn chunked(mut self, chunked: bool) -> Self {
+    self.chunked = chunked;
+    self
+  }
+
+  /// Split the assets into chunks, sorted by key so the generated code is stable.
+  fn chunks(&self) -> Vec<Vec<(&AssetKey, &(PathBuf, PathBuf))>> {
+    let mut assets = self.assets.iter().collect::<Vec<_>>();
+    assets.sort_by_key(|(key, _)| *key);
+
+    let mut chunks = Vec::new();
+    let mut chunk = Vec::new();
+    let mut chunk_size = 0;
+    for asset in assets {
+      let size = std::fs::metadata(&(asset.1).1)
+        .map(|metadata| metadata.len())
+        .unwrap_or_default();
+      if !chunk.is_empty()
+        && (chunk.len() == ASSET_CHUNK_COUNT || chunk_size + size > ASSET_CHUNK_SIZE)
+      {
+        chunks.push(std::mem::take(&mut chunk));
+        chunk_size = 0;
+      }
+      chunk.push(asset);
+      chunk_size += size;
+    }
+    if !chunk.is_empty() {
+      chunks.push(chunk);
+    }
+    chunks
   }
 
   /// Use highest compression level for release, the fastest one for everything else
@@ -394,21 +441,26 @@ impl EmbeddedAssets {
   }
 }
 
+fn asset_map_entries<'a>(
+  assets: impl IntoIterator<Item = (&'a AssetKey, &'a (PathBuf, PathBuf))>,
+) -> TokenStream {
+  let mut entries = TokenStream::new();
+  for (key, (input, output)) in assets {
+    let key: &str = key.as_ref();
+    let input = input.display().to_string();
+    let output = output.display().to_string();
+
+    // add original asset as a compiler dependency, rely on dead code elimination to clean it up
+    entries.append_all(quote!(#key => {
+      const _: &[u8] = include_bytes!(#input);
+      include_bytes!(#output)
+    },));
+  }
+  entries
+}
+
 impl ToTokens for EmbeddedAssets {
   fn to_tokens(&self, tokens: &mut TokenStream) {
-    let mut assets = TokenStream::new();
-    for (key, (input, output)) in &self.assets {
-      let key: &str = key.as_ref();
-      let input = input.display().to_string();
-      let output = output.display().to_string();
-
-      // add original asset as a compiler dependency, rely on dead code elimination to clean it up
-      assets.append_all(quote!(#key => {
-        const _: &[u8] = include_bytes!(#input);
-        include_bytes!(#output)
-      },));
-    }
-
     let mut global_hashes = TokenStream::new();
     for script_hash in &self.csp_hashes.scripts {
       let hash = script_hash.as_str();
@@ -432,10 +484,84 @@ impl ToTokens for EmbeddedAssets {
     }
 
     // we expect phf related items to be in path when generating the path code
-    tokens.append_all(quote! {{
-        #[allow(unused_imports)]
-        use ::tauri::utils::assets::{CspHash, EmbeddedAssets, phf, phf::phf_map};
-        EmbeddedAssets::new(phf_map! { #assets }, &[#global_hashes], phf_map! { #html_hashes })
-    }});
+    if self.chunked {
+      let chunks = self
+        .chunks()
+        .into_iter()
+        .map(asset_map_entries)
+        .collect::<Vec<_>>();
+      let idents = (0..chunks.len())
+        .map(|i| format_ident!("ASSET_CHUNK_{}", i))
+        .collect::<Vec<_>>();
+      let len = chunks.len();
+      tokens.append_all(quote! {{
+          #[allow(unused_imports)]
+          use ::tauri::utils::assets::{CspHash, EmbeddedAssetMap, EmbeddedAssets, phf, phf::phf_map};
+          #(static #idents: EmbeddedAssetMap = phf_map! { #chunks };)*
+          static ASSET_CHUNKS: [&EmbeddedAssetMap; #len] = [#(&#idents),*];
+          EmbeddedAssets::new_chunked(&ASSET_CHUNKS, &[#global_hashes], phf_map! { #html_hashes })
+      }});
+    } else {
+      let assets = asset_map_entries(&self.assets);
+      tokens.append_all(quote! {{
+          #[allow(unused_imports)]
+          use ::tauri::utils::assets::{CspHash, EmbeddedAssets, phf, phf::phf_map};
+          EmbeddedAssets::new(phf_map! { #assets }, &[#global_hashes], phf_map! { #html_hashes })
+      }});
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn chunked_assets() {
+    let dir = std::env::temp_dir().join(format!("tauri-codegen-chunks-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let mut assets = HashMap::new();
+    for i in 0..130 {
+      let path = dir.join(format!("{i:03}.js"));
+      std::fs::write(&path, "console.log()").unwrap();
+      assets.insert(AssetKey::from(format!("{i:03}.js")), (path.clone(), path));
+    }
+    // a compressed asset larger than a whole chunk gets a chunk of its own
+    let large = dir.join("050-large.js");
+    File::create(&large)
+      .unwrap()
+      .set_len(ASSET_CHUNK_SIZE + 1)
+      .unwrap();
+    assets.insert(AssetKey::from("050-large.js"), (large.clone(), large));
+
+    let embedded = EmbeddedAssets {
+      assets,
+      csp_hashes: CspHashes::default(),
+      chunked: true,
+    };
+    let chunks = embedded.chunks();
+    let lens = chunks.iter().map(Vec::len).collect::<Vec<_>>();
+    assert_eq!(lens, [50, 1, 64, 16]);
+    assert_eq!(chunks[1][0].0.as_ref(), "/050-large.js");
+    let keys = chunks
+      .iter()
+      .flatten()
+      .map(|(key, _)| key.as_ref())
+      .collect::<Vec<&str>>();
+    let mut sorted = keys.clone();
+    sorted.sort_unstable();
+    assert_eq!(keys, sorted);
+
+    let tokens = embedded.to_token_stream().to_string();
+    assert!(tokens.contains("static ASSET_CHUNK_3 : EmbeddedAssetMap"));
+    assert!(!tokens.contains("ASSET_CHUNK_4"));
+    assert!(tokens.contains("EmbeddedAssets :: new_chunked (& ASSET_CHUNKS"));
+
+    let tokens = embedded.chunked(false).to_token_stream().to_string();
+    assert!(!tokens.contains("ASSET_CHUNK"));
+    assert!(tokens.contains("EmbeddedAssets :: new (phf_map !"));
+
+    std::fs::remove_dir_all(dir).unwrap();
   }
 }
@@ -2,7 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
-use quote::format_ident;
+use std::collections::HashMap;
+
+use quote::{format_ident, ToTokens};
 use syn::{
   parse::{Parse, ParseBuffer, ParseStream},
   Attribute, Ident, Path, Token,
@@ -34,7 +36,7 @@ impl Parse for Handler {
     let command_defs = input.parse_terminated(CommandDef::parse, Token![,])?;
 
     // parse the command names and wrappers from the passed paths
-    let (commands, wrappers) = command_defs
+    let (commands, wrappers): (Vec<Ident>, Vec<Path>) = command_defs
       .iter()
       .map(|command_def| {
         let mut wrapper = command_def.path.clone();
@@ -50,6 +52,8 @@ impl Parse for Handler {
       })
       .unzip();
 
+    check_duplicate_commands(command_defs.iter().zip(&commands))?;
+
     Ok(Self {
       command_defs: command_defs.into_iter().collect(), // remove punctuation separators
       commands,
@@ -58,6 +62,44 @@ impl Parse for Handler {
   }
 }
 
+/// Errors when two commands resolve to the same name, since only the first one would ever be called.
+///
+/// Commands with different attributes are allowed to share a name,
+/// e.g. `#[cfg(windows)] windows::open, #[cfg(unix)] unix::open`.
+fn check_duplicate_commands<'a>(
+  commands: impl Iterator<Item = (&'a CommandDef, &'a Ident)>,
+) -> syn::Result<()> {
+  let mut seen = HashMap::new();
+  let mut error: Option<syn::Error> = None;
+  for (def, command) in commands {
+    let attrs = def
+      .attrs
+      .iter()
+      .map(|attr| attr.to_token_stream().to_string())
+      .collect::<Vec<_>>();
+    let first = *seen
+      .entry((command.to_string(), attrs))
+      .or_insert(&def.path);
+    if !std::ptr::eq(first, &def.path) {
+      let mut e = syn::Error::new_spanned(
+        &def.path,
+        format!(
+          "duplicate command `{command}`, a command with the same name is already registered"
+        ),
+      );
+      e.combine(syn::Error::new_spanned(
+        first,
+        format!("command `{command}` first registered here"),
+      ));
+      match &mut error {
+        Some(error) => error.combine(e),
+        None => error = Some(e),
+      }
+    }
+  }
+  error.map_or(Ok(()), Err)
+}
+
 impl From<Handler> for proc_macro::TokenStream {
   fn from(
     Handler {
@@ -84,3 +126,28 @@ impl From<Handler> for proc_macro::TokenStream {
     .into()
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::Handler;
+
+  #[test]
+  fn duplicate_commands() {
+    assert!(syn::parse_str::<Handler>("a::open, b::close, close_all").is_ok());
+    assert!(
+      syn::parse_str::<Handler>("#[cfg(windows)] win::open, #[cfg(unix)] unix::open").is_ok()
+    );
+
+    let error = syn::parse_str::<Handler>("a::open, close, b::open")
+      .err()
+      .expect("duplicate commands must not parse");
+    let messages = error.into_iter().map(|e| e.to_string()).collect::<Vec<_>>();
+    assert_eq!(
+      messages,
+      [
+        "duplicate command `open`, a command with the same name is already registered",
+        "command `open` first registered here",
+      ]
+    );
+  }
+}
@@ -38,6 +38,7 @@ struct WrapperAttributes {
   root: TokenStream2,
   execution_context: ExecutionContext,
   argument_case: ArgumentCase,
+  deprecated: Option<String>,
 }
 
 impl Parse for WrapperAttributes {
@@ -46,6 +47,7 @@ impl Parse for WrapperAttributes {
       root: quote!(::tauri),
       execution_context: ExecutionContext::Blocking,
       argument_case: ArgumentCase::Camel,
+      deprecated: None,
     };
 
     let attrs = Punctuated::<WrapperAttributeKind, Token![,]>::parse_terminated(input)?;
@@ -87,15 +89,55 @@ impl Parse for WrapperAttributes {
                 quote!(#ident)
               };
             }
+          } else if v.path.is_ident("deprecated") {
+            if let Expr::Lit(ExprLit {
+              lit: Lit::Str(s),
+              attrs: _,
+            }) = v.value
+            {
+              wrapper_attributes.deprecated = Some(s.value());
+            } else {
+              return Err(syn::Error::new(
+                v.value.span(),
+                "expected a string describing the replacement of the deprecated command",
+              ));
+            }
           }
         }
+        WrapperAttributeKind::Meta(Meta::Path(p)) if p.is_ident("deprecated") => {
+          wrapper_attributes.deprecated = Some(String::new());
+        }
+        WrapperAttributeKind::Meta(Meta::Path(p)) if p.is_ident("threaded") => {
+          if let ExecutionContext::MainThread = wrapper_attributes.execution_context {
+            return Err(syn::Error::new(
+              p.span(),
+              "`threaded` cannot be used together with `on_main_thread`",
+            ));
+          }
+          wrapper_attributes.execution_context = ExecutionContext::Async;
+        }
+        WrapperAttributeKind::Meta(Meta::Path(p)) if p.is_ident("on_main_thread") => {
+          if let ExecutionContext::Async = wrapper_attributes.execution_context {
+            return Err(syn::Error::new(
+              p.span(),
+              "`on_main_thread` cannot be used together with `threaded` or `async`",
+            ));
+          }
+          wrapper_attributes.execution_context = ExecutionContext::MainThread;
+        }
         WrapperAttributeKind::Meta(Meta::Path(_)) => {
           return Err(syn::Error::new(
             input.span(),
-            "unexpected input, expected one of `rename_all`, `root`, `async`",
+            "unexpected input, expected one of `rename_all`, `root`, `async`, `threaded`, `on_main_thread`, `deprecated`",
           ));
         }
         WrapperAttributeKind::Async => {
+          if let ExecutionContext::MainThread = wrapper_attributes.execution_context {
+            return Err(syn::Error::new(
+              input.span(),
+              "`async` cannot be used together with `on_main_thread`",
+            ));
+          }
           wrapper_attributes.execution_context = ExecutionContext::Async;
         }
       }
@@ -107,8 +149,12 @@ impl Parse for WrapperAttributes {
 
 /// The execution context of the command.
 enum ExecutionContext {
+  /// Runs on the async runtime thread pool.
   Async,
+  /// Runs inline on the thread that received the IPC message.
   Blocking,
+  /// Runs on the main thread (event loop thread).
+  MainThread,
 }
 
 /// The case of each argument name.
@@ -132,7 +178,9 @@ pub fn wrapper(attributes: TokenStream, item: TokenStream) -> TokenStream {
   let wrapper = super::format_command_wrapper(&function.sig.ident);
   let visibility = &function.vis;
 
-  if function.sig.asyncness.is_some() {
+  if function.sig.asyncness.is_some()
+    && !matches!(attrs.execution_context, ExecutionContext::MainThread)
+  {
     attrs.execution_context = ExecutionContext::Async;
   }
 
@@ -216,6 +264,8 @@ pub fn wrapper(attributes: TokenStream, item: TokenStream) -> TokenStream {
       .unwrap_or_else(syn::Error::into_compile_error),
     ExecutionContext::Blocking => body_blocking(&plugin_name, &function, &invoke, &attrs)
       .unwrap_or_else(syn::Error::into_compile_error),
+    ExecutionContext::MainThread => body_main_thread(&plugin_name, &function, &invoke, &attrs)
+      .unwrap_or_else(syn::Error::into_compile_error),
   };
 
   let Invoke {
@@ -230,6 +280,8 @@ pub fn wrapper(attributes: TokenStream, item: TokenStream) -> TokenStream {
     ExecutionContext::Async if function.sig.asyncness.is_none() => "sync_threadpool",
     ExecutionContext::Async => "async",
     ExecutionContext::Blocking => "sync",
+    ExecutionContext::MainThread if function.sig.asyncness.is_some() => "async_main_thread",
+    ExecutionContext::MainThread => "sync_main_thread",
   };
 
   let loc = function.span().start();
@@ -252,6 +304,21 @@ pub fn wrapper(attributes: TokenStream, item: TokenStream) -> TokenStream {
     quote!()
   };
 
+  // warn once per command, in debug builds of the app only
+  let maybe_deprecation_warning = match &attrs.deprecated {
+    Some(note) => quote!(
+      if cfg!(debug_assertions) {
+        static DEPRECATION_WARNING: ::std::sync::Once = ::std::sync::Once::new();
+        #root::command::private::warn_deprecated_command(
+          &DEPRECATION_WARNING,
+          #message.command(),
+          #note,
+        );
+      }
+    ),
+    None => quote!(),
+  };
+
   // Rely on rust 2018 edition to allow importing a macro from a path.
   quote!(
     #async_command_check
@@ -271,6 +338,8 @@ pub fn wrapper(attributes: TokenStream, item: TokenStream) -> TokenStream {
 
           #maybe_span
 
+          #maybe_deprecation_warning
+
           #body
       }};
     }
@@ -364,6 +433,69 @@ fn body_blocking(
   })
 }
 
+/// Generates a command response that is computed on the main thread.
+///
+/// The command (and its argument parsing) is moved to the event loop thread via `run_on_main_thread`.
+/// Async functions are driven to completion on the main thread, blocking the event loop while they run.
+///
+/// See the [`tauri::command`] module for all the items and traits that make this possible.
+///
+/// [`tauri::command`]: https://docs.rs/tauri/*/tauri/runtime/index.html
+fn body_main_thread(
+  plugin_name: &TokenStream2,
+  function: &ItemFn,
+  invoke: &Invoke,
+  attributes: &WrapperAttributes,
+) -> syn::Result<TokenStream2> {
+  let Invoke {
+    message,
+    resolver,
+    acl,
+  } = invoke;
+  let args = parse_args(plugin_name, function, message, acl, attributes)?;
+  let root = &attributes.root;
+
+  let maybe_span = if cfg!(feature = "tracing") {
+    quote!(let _span = tracing::debug_span!("ipc::request::run").entered();)
+  } else {
+    quote!()
+  };
+
+  let run = if function.sig.asyncness.is_some() {
+    quote! {
+      let result = #root::async_runtime::block_on(async move {
+        let result = $path(#(#args?),*);
+        let kind = (&result).async_kind();
+        kind.future(result).await
+      });
+      #resolver.respond(result);
+    }
+  } else {
+    // the body of a `match` to early return any argument that wasn't successful in parsing.
+    let match_body = quote!({
+      Ok(arg) => arg,
+      Err(err) => { #resolver.invoke_error(err); return },
+    });
+    quote! {
+      let result = $path(#(match #args #match_body),*);
+      let kind = (&result).blocking_kind();
+      kind.block(result, #resolver);
+    }
+  };
+
+  Ok(quote! {
+    let __tauri_webview__ = #message.webview();
+    let __tauri_fallback_resolver__ = #resolver.clone();
+    if let Err(e) = __tauri_webview__.run_on_main_thread(move || {
+      #maybe_span
+      #run
+    }) {
+      __tauri_fallback_resolver__.invoke_error(#root::ipc::InvokeError::from_error(e));
+    }
+    return true;
+  })
+}
+
 /// Parse all arguments for the command wrapper to use from the signature of the command function.
 fn parse_args(
   plugin_name: &TokenStream2,
@@ -8,7 +8,7 @@ use std::{env::VarError, path::PathBuf};
 use syn::{
   parse::{Parse, ParseBuffer},
   punctuated::Punctuated,
-  LitStr, PathArguments, PathSegment, Token,
+  LitBool, LitStr, PathArguments, PathSegment, Token,
 };
 use tauri_codegen::{context_codegen, get_config, ContextData};
 use tauri_utils::{config::parse::does_supported_file_name_exist, platform::Target};
@@ -16,6 +16,8 @@ use tauri_utils::{config::parse::does_supported_file_name_exist, platform::Targe
 pub(crate) struct ContextItems {
   config_file: PathBuf,
   root: syn::Path,
+  live_assets: bool,
+  asset_chunking: bool,
 }
 
 impl Parse for ContextItems {
@@ -26,7 +28,8 @@ impl Parse for ContextItems {
       .map(Target::from_triple)
       .unwrap_or_else(|_| Target::current());
 
-    let config_file = if input.is_empty() {
+    let config_file_given = input.peek(LitStr);
+    let config_file = if !config_file_given {
       std::env::var("CARGO_MANIFEST_DIR").map(|m| PathBuf::from(m).join("tauri.conf.json"))
     } else {
       let raw: LitStr = input.parse()?;
@@ -53,7 +56,53 @@ impl Parse for ContextItems {
     })
     .map_err(|e| input.error(e))?;
 
-    let context_path = if input.is_empty() {
+    let mut root = None;
+    let mut live_assets = false;
+    let mut asset_chunking = false;
+    let mut first = config_file_given;
+    while !input.is_empty() {
+      if first {
+        first = false;
+      } else {
+        let _: Token![,] = input.parse()?;
+      }
+
+      if input.peek(syn::Ident) && input.peek2(Token![=]) {
+        let key: Ident = input.parse()?;
+        let _: Token![=] = input.parse()?;
+        match key.to_string().as_str() {
+          "assets" => {
+            let value: LitStr = input.parse()?;
+            live_assets = match value.value().as_str() {
+              "live" => true,
+              "embed" => false,
+              _ => {
+                return Err(syn::Error::new(
+                  value.span(),
+                  "expected `assets` to be either \"live\" or \"embed\"",
+                ))
+              }
+            };
+          }
+          "asset_chunking" => {
+            let value: LitBool = input.parse()?;
+            asset_chunking = value.value;
+          }
+          _ => {
+            return Err(syn::Error::new(
+              key.span(),
+              "unknown option, expected `assets` or `asset_chunking`",
+            ))
+          }
+        }
+      } else if root.is_none() {
+        root.replace(input.call(syn::Path::parse_mod_style)?);
+      } else {
+        return Err(input.error("the context root path can only be set once"));
+      }
+    }
+
+    let root = root.unwrap_or_else(|| {
       let mut segments = Punctuated::new();
       segments.push(PathSegment {
         ident: Ident::new("tauri", Span::call_site()),
@@ -63,14 +112,13 @@ impl Parse for ContextItems {
         leading_colon: Some(Token![::](Span::call_site())),
         segments,
       }
-    } else {
-      let _: Token![,] = input.parse()?;
-      input.call(syn::Path::parse_mod_style)?
-    };
+    });
 
     Ok(Self {
       config_file,
-      root: context_path,
+      root,
+      live_assets,
+      asset_chunking,
     })
   }
 }
@@ -83,6 +131,8 @@ pub(crate) fn generate_context(context: ContextItems) -> TokenStream {
       config,
       config_parent,
       root: context.root.to_token_stream(),
+      live_assets: context.live_assets,
+      asset_chunking: context.asset_chunking,
     })
     .and_then(|data| context_codegen(data).map_err(|e| e.to_string()));
 
@@ -25,6 +25,28 @@ mod context;
 
 /// Mark a function as a command handler. It creates a wrapper function with the necessary glue code.
 ///
+/// # Execution context
+///
+/// By default `async fn` commands run on the async runtime thread pool
+/// and regular functions run inline on the thread that received the IPC message.
+/// This can be changed with the following arguments:
+///
+/// - `#[command(threaded)]` (or `#[command(async)]`): runs the command on the async runtime thread pool.
+///   Useful for synchronous commands doing blocking IO, which would otherwise stall the IPC thread.
+///   Arguments and return values must be `Send`.
+/// - `#[command(on_main_thread)]`: runs the command on the main (event loop) thread,
+///   which is required by some platform UI APIs.
+///   The event loop is blocked while the command runs, including any `.await` in an `async fn` command,
+///   so keep these commands short.
+///
+/// `threaded` and `on_main_thread` cannot be used together.
+///
+/// # Deprecation
+///
+/// `#[command(deprecated = "use getUserV2 instead")]` (or `#[command(deprecated)]`) marks the command as deprecated.
+/// It keeps working, but its first invocation prints a warning with the given note to help find the callers still using it.
+/// The warning is only printed in debug builds of the app.
+///
 /// # Stability
 /// The output of this macro is managed internally by Tauri,
 /// and should not be accessed directly on normal applications.
@@ -67,6 +89,11 @@ pub fn generate_handler(item: TokenStream) -> TokenStream {
 
 /// Reads a Tauri config file and generates a `::tauri::Context` based on the content.
 ///
+/// Use `assets = "live"` to read the frontend assets from the `devPath` directory at runtime
+/// instead of embedding them on development builds. This option is ignored when the `custom-protocol` feature is enabled.
+///
+/// Use `asset_chunking = true` to generate the embedded assets in several smaller statics.
+///
 /// # Stability
 /// The output of this macro is managed internally by Tauri,
 /// and should not be accessed directly on normal applications.
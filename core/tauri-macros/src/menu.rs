@@ -3,10 +3,11 @@
 // SPDX-License-Identifier: MIT
 
 use proc_macro2::{Ident, Span, TokenStream};
-use quote::quote;
+use quote::{quote, quote_spanned};
 use syn::{
   parse::{Parse, ParseStream},
   punctuated::Punctuated,
+  spanned::Spanned,
   Expr, Token,
 };
 
@@ -19,6 +20,15 @@ pub struct DoMenuItemInput {
   kinds: Vec<NegatedIdent>,
 }
 
+/// Menu item kinds expanded when no kind is selected and the resource type each of them maps to.
+const ITEM_KINDS: &[(&str, &str)] = &[
+  ("MenuItem", "MenuItem"),
+  ("Submenu", "Submenu"),
+  ("Predefined", "PredefinedMenuItem"),
+  ("Check", "CheckMenuItem"),
+  ("Icon", "IconMenuItem"),
+];
+
 #[derive(Clone)]
 struct NegatedIdent(bool, Ident);
 
@@ -45,6 +55,15 @@ impl Parse for DoMenuItemInput {
     let _: syn::Result<Token![,]> = input.parse();
     let kinds = Punctuated::<NegatedIdent, Token![|]>::parse_terminated(input)?;
 
+    if let Some(first) = kinds.first() {
+      if let Some(mixed) = kinds.iter().find(|k| k.0 != first.0) {
+        return Err(syn::Error::new(
+          mixed.1.span(),
+          "cannot mix negated and non-negated menu item kinds",
+        ));
+      }
+    }
+
     Ok(Self {
       resources_table,
       rid,
@@ -63,55 +82,48 @@ pub fn do_menu_item(input: DoMenuItemInput) -> TokenStream {
     kind,
     expr,
     var,
-    mut kinds,
+    kinds,
   } = input;
 
-  let defaults = vec![
-    NegatedIdent(false, Ident::new("Submenu", Span::call_site())),
-    NegatedIdent(false, Ident::new("MenuItem", Span::call_site())),
-    NegatedIdent(false, Ident::new("Predefined", Span::call_site())),
-    NegatedIdent(false, Ident::new("Check", Span::call_site())),
-    NegatedIdent(false, Ident::new("Icon", Span::call_site())),
-  ];
-
-  if kinds.is_empty() {
-    kinds.extend(defaults.clone());
-  }
-
   let has_negated = kinds.iter().any(|n| n.0);
 
-  if has_negated {
-    kinds.extend(defaults);
-    kinds.sort_by(|a, b| a.1.cmp(&b.1));
-    kinds.dedup_by(|a, b| a.1 == b.1);
-  }
-
-  let (kinds, types): (Vec<Ident>, Vec<Ident>) = kinds
-    .into_iter()
-    .filter_map(|nident| {
-      if nident.0 {
-        None
+  // the user expression keeps its own span so a method missing on one of the kinds is reported
+  // by the type checker on the offending call instead of the macro invocation
+  let expr_span = expr.span();
+  let arms = ITEM_KINDS
+    .iter()
+    .filter_map(|(name, ty)| {
+      let selected = kinds.iter().find(|n| n.1 == name);
+      let included = if kinds.is_empty() || has_negated {
+        selected.is_none()
       } else {
-        match nident.1 {
-          i if i == "MenuItem" => Some((i, Ident::new("MenuItem", Span::call_site()))),
-          i if i == "Submenu" => Some((i, Ident::new("Submenu", Span::call_site()))),
-          i if i == "Predefined" => Some((i, Ident::new("PredefinedMenuItem", Span::call_site()))),
-          i if i == "Check" => Some((i, Ident::new("CheckMenuItem", Span::call_site()))),
-          i if i == "Icon" => Some((i, Ident::new("IconMenuItem", Span::call_site()))),
-          _ => None,
+        selected.is_some()
+      };
+      included.then(|| {
+        let kind = selected
+          .map(|n| n.1.clone())
+          .unwrap_or_else(|| Ident::new(name, Span::call_site()));
+        let ty = Ident::new(ty, Span::call_site());
+        quote_spanned! {expr_span=>
+          ItemKind::#kind => {
+            let #var = #resources_table.get::<#ty<R>>(#rid)?;
+            #expr
+          }
         }
-      }
+      })
     })
-    .unzip();
+    .collect::<Vec<_>>();
+
+  // excluded and unknown kinds get their own arm so a misspelled kind fails to resolve on its span
+  let unmatched = kinds
+    .iter()
+    .filter(|n| n.0 || !ITEM_KINDS.iter().any(|(name, _)| n.1 == name))
+    .map(|n| &n.1);
 
   quote! {
     match #kind {
-      #(
-        ItemKind::#kinds => {
-        let #var = #resources_table.get::<#types<R>>(#rid)?;
-        #expr
-      }
-      )*
+      #(#arms)*
+      #(ItemKind::#unmatched => unreachable!(),)*
       _ => unreachable!(),
     }
   }
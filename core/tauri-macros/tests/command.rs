@@ -0,0 +1,9 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+#[test]
+fn command_errors() {
+  let t = trybuild::TestCases::new();
+  t.compile_fail("tests/ui/command/*.rs");
+}
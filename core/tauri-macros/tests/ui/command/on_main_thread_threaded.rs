@@ -0,0 +1,10 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use tauri_macros::command;
+
+#[command(on_main_thread, threaded)]
+fn greet() {}
+
+fn main() {}
@@ -0,0 +1,15 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+#[path = "../support/menu.rs"]
+mod support;
+
+use support::*;
+use tauri_macros::do_menu_item;
+
+fn text<R>(resources_table: ResourceTable, rid: u32, kind: ItemKind) -> Result<String, ()> {
+  do_menu_item!(resources_table, rid, kind, |i| i.text(), Check | Submeun)
+}
+
+fn main() {}
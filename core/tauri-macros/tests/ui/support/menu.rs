@@ -0,0 +1,62 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+// Minimal stand-ins for the types `do_menu_item!` expands to inside `tauri::menu::plugin`.
+
+#![allow(dead_code)]
+
+use std::{marker::PhantomData, sync::Arc};
+
+pub enum ItemKind {
+  Menu,
+  MenuItem,
+  Predefined,
+  Submenu,
+  Check,
+  Icon,
+}
+
+macro_rules! item {
+  ($($ty:ident),*) => {
+    $(
+      pub struct $ty<R>(PhantomData<R>);
+
+      impl<R> $ty<R> {
+        pub fn text(&self) -> Result<String, ()> {
+          Ok(String::new())
+        }
+      }
+    )*
+  };
+}
+
+item!(
+  MenuItem,
+  Submenu,
+  PredefinedMenuItem,
+  CheckMenuItem,
+  IconMenuItem
+);
+
+macro_rules! accelerator {
+  ($($ty:ident),*) => {
+    $(
+      impl<R> $ty<R> {
+        pub fn set_accelerator(&self, _accelerator: Option<&str>) -> Result<(), ()> {
+          Ok(())
+        }
+      }
+    )*
+  };
+}
+
+accelerator!(MenuItem, CheckMenuItem, IconMenuItem);
+
+pub struct ResourceTable;
+
+impl ResourceTable {
+  pub fn get<T>(&self, _rid: u32) -> Result<Arc<T>, ()> {
+    Err(())
+  }
+}
@@ -2,18 +2,115 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
-use std::path::Path;
+use std::{
+  collections::{BTreeMap, HashMap},
+  path::{Path, PathBuf},
+};
 
 use cargo_metadata::{Metadata, MetadataCommand};
-use tauri::utils::acl::{self, Error};
+use tauri::utils::{
+  acl::{
+    self,
+    plugin::{Manifest, PermissionFile},
+    Error,
+  },
+  platform::Target,
+};
 
 pub struct Builder<'a> {
   commands: &'a [&'static str],
+  command_descriptions: HashMap<&'static str, &'static str>,
+  command_prefix: Option<&'static str>,
+  offline: bool,
+  permission_dirs: Vec<PathBuf>,
+  schema_out_dir: Option<PathBuf>,
+  commands_out_dir: Option<PathBuf>,
+  example_capabilities: Option<String>,
 }
 
 impl<'a> Builder<'a> {
   pub fn new(commands: &'a [&'static str]) -> Self {
-    Self { commands }
+    Self {
+      commands,
+      command_descriptions: HashMap::new(),
+      command_prefix: None,
+      offline: false,
+      permission_dirs: Vec::new(),
+      schema_out_dir: None,
+      commands_out_dir: None,
+      example_capabilities: None,
+    }
+  }
+
+  /// Namespaces the plugin commands with the given prefix, e.g. `fs` exposes the `read_file` command as `fs:read_file`.
+  ///
+  /// The autogenerated command permissions reference the prefixed command names
+  /// and their identifiers include the prefix, e.g. `allow-fs-read-file`.
+  /// The prefix is exposed to the crate as the `TAURI_PLUGIN_COMMAND_PREFIX` environment variable,
+  /// which must be given to the runtime `tauri::plugin::Builder::command_prefix` so invocations are matched.
+  pub fn command_prefix(mut self, prefix: &'static str) -> Self {
+    self.command_prefix.replace(prefix);
+    self
+  }
+
+  /// Describes the commands in their autogenerated permissions, keyed by the command name.
+  ///
+  /// The descriptions show up in the permissions schema and in the capabilities schema of the apps using the plugin.
+  /// Commands without a description keep the generic one.
+  pub fn command_descriptions(mut self, descriptions: HashMap<&'static str, &'static str>) -> Self {
+    self.command_descriptions.extend(descriptions);
+    self
+  }
+
+  /// Resolves the crate metadata without accessing the network,
+  /// passing `--offline` and `--frozen` to `cargo metadata`.
+  ///
+  /// Useful for air-gapped builds. Requires an up to date `Cargo.lock` file.
+  pub fn offline(mut self, offline: bool) -> Self {
+    self.offline = offline;
+    self
+  }
+
+  /// Merges the permissions defined in the given directories with the ones in `./permissions`.
+  ///
+  /// Useful when a plugin is split into several crates, each with its own `permissions` directory,
+  /// and the umbrella crate needs to expose all of them under the same plugin name.
+  /// Relative paths are resolved from the crate root.
+  ///
+  /// Defining the same permission identifier in more than one directory is an error.
+  pub fn include_permissions_from(mut self, dirs: &[PathBuf]) -> Self {
+    self.permission_dirs.extend_from_slice(dirs);
+    self
+  }
+
+  /// Writes the permissions JSON schema to the given directory instead of `./permissions`,
+  /// e.g. a schema directory shared by all plugins of a monorepo.
+  ///
+  /// The directory is created if it does not exist. Relative paths are resolved from the crate root.
+  /// The autogenerated command permissions are not affected, see [`Self::commands_out_dir`].
+  pub fn schema_out_dir(mut self, dir: PathBuf) -> Self {
+    self.schema_out_dir.replace(dir);
+    self
+  }
+
+  /// Writes the autogenerated command permissions to the given directory
+  /// instead of `./permissions/autogenerated/commands`.
+  ///
+  /// The directory is created if it does not exist, and its permissions are merged with the ones in `./permissions`.
+  /// Relative paths are resolved from the crate root.
+  pub fn commands_out_dir(mut self, dir: PathBuf) -> Self {
+    self.commands_out_dir.replace(dir);
+    self
+  }
+
+  /// Validates the example capability files matching the given glob pattern, e.g. `examples/*/capabilities/*`,
+  /// failing the build if they reference a permission of this plugin that does not exist.
+  ///
+  /// The capabilities are checked for all of their platforms, like the app build does for its target platform.
+  /// Permissions of other plugins are not checked. Relative patterns are resolved from the crate root.
+  pub fn validate_examples(mut self, glob: &str) -> Self {
+    self.example_capabilities.replace(glob.into());
+    self
   }
 
   /// [`Self::try_build`] but will exit automatically if an error is found.
@@ -40,18 +137,94 @@ impl<'a> Builder<'a> {
     // requirement: links MUST be set and MUST match the name
     let _links = build_var("CARGO_MANIFEST_LINKS")?;
 
-    let autogenerated = Path::new("permissions/autogenerated/");
-    let commands_dir = &autogenerated.join("commands");
+    if let Some(prefix) = self.command_prefix {
+      let valid = !prefix.is_empty()
+        && prefix
+          .chars()
+          .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+      if !valid {
+        return Err(Error::CommandPrefix(prefix.into()));
+      }
+      println!("cargo:rustc-env=TAURI_PLUGIN_COMMAND_PREFIX={prefix}");
+    }
+
+    let permissions = self.generate_permissions(&name)?;
+    self.check_examples(&name, permissions)?;
+
+    let metadata = find_metadata(self.offline)?;
+    println!("{metadata:#?}");
+
+    Ok(())
+  }
+}
+
+impl Builder<'_> {
+  /// Autogenerates the command permissions, then collects all permissions and writes their schema.
+  fn generate_permissions(&self, name: &str) -> Result<Vec<PermissionFile>, Error> {
+    let commands_dir = self
+      .commands_out_dir
+      .clone()
+      .unwrap_or_else(|| Path::new("permissions/autogenerated/").join("commands"));
 
     if !self.commands.is_empty() {
-      acl::build::autogenerate_command_permissions(commands_dir, self.commands, "");
+      acl::build::autogenerate_described_command_permissions(
+        &commands_dir,
+        self.commands,
+        self.command_prefix,
+        &self.command_descriptions,
+        "",
+      );
     }
 
-    let permissions = acl::build::define_permissions("./permissions/**/*.*", &name)?;
-    acl::build::generate_schema(&permissions, "./permissions")?;
+    let patterns = std::iter::once("./permissions/**/*.*".to_string())
+      .chain(
+        self
+          .permission_dirs
+          .iter()
+          // overlapping directories are deduplicated, so the default commands directory can be listed again
+          .chain(std::iter::once(&commands_dir))
+          .map(|dir| format!("{}/**/*.*", dir.display())),
+      )
+      .collect::<Vec<_>>();
+    let patterns = patterns.iter().map(String::as_str).collect::<Vec<_>>();
+    let permissions = acl::build::define_permissions_from(&patterns, name)?;
+    acl::build::generate_schema(
+      &permissions,
+      self
+        .schema_out_dir
+        .as_deref()
+        .unwrap_or_else(|| Path::new("./permissions")),
+    )?;
 
-    let metadata = find_metadata()?;
-    println!("{metadata:#?}");
+    Ok(permissions)
+  }
+
+  /// Validates the example capabilities against the plugin permissions, see [`Builder::validate_examples`].
+  fn check_examples(&self, name: &str, permissions: Vec<PermissionFile>) -> Result<(), Error> {
+    let Some(pattern) = &self.example_capabilities else {
+      return Ok(());
+    };
+
+    // the app build refers to the plugin permissions without the crate prefix
+    let plugin_name = name.strip_prefix("tauri-plugin-").unwrap_or(name);
+    let manifests = BTreeMap::from([(plugin_name.to_string(), Manifest::from_files(permissions))]);
+
+    let mut capabilities = acl::build::parse_capabilities(pattern)?;
+    for capability in capabilities.values_mut() {
+      capability
+        .permissions
+        .retain(|entry| entry.identifier().get_prefix() == Some(plugin_name));
+    }
+
+    for target in [
+      Target::Linux,
+      Target::MacOS,
+      Target::Windows,
+      Target::Android,
+      Target::Ios,
+    ] {
+      acl::build::validate_capabilities(&manifests, &capabilities, target)?;
+    }
 
     Ok(())
   }
@@ -62,12 +235,111 @@ fn build_var(key: &'static str) -> Result<String, Error> {
   std::env::var(key).map_err(|_| Error::BuildVar(key))
 }
 
-fn find_metadata() -> Result<Metadata, Error> {
+fn find_metadata(offline: bool) -> Result<Metadata, Error> {
   build_var("CARGO_MANIFEST_DIR").and_then(|dir| {
-    MetadataCommand::new()
-      .current_dir(dir)
-      .no_deps()
-      .exec()
-      .map_err(Error::Metadata)
+    let mut command = MetadataCommand::new();
+    command.current_dir(dir).no_deps();
+    if offline {
+      command.other_options(vec!["--offline".into(), "--frozen".into()]);
+    }
+    command.exec().map_err(|e| {
+      if offline {
+        Error::MetadataOffline(e)
+      } else {
+        Error::Metadata(e)
+      }
+    })
   })
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn schema_out_dir() {
+    let root = std::env::temp_dir().join(format!("tauri-plugin-schema-{}", std::process::id()));
+    let schema_dir = root.join("schemas/ping");
+    let commands_dir = root.join("commands");
+
+    Builder::new(&["ping"])
+      .schema_out_dir(schema_dir.clone())
+      .commands_out_dir(commands_dir.clone())
+      .generate_permissions("tauri-plugin-ping")
+      .unwrap();
+
+    assert!(commands_dir.join("ping.toml").exists());
+    let schema =
+      std::fs::read_to_string(schema_dir.join(acl::build::PERMISSION_SCHEMA_FILE_NAME)).unwrap();
+    assert!(schema.contains("allow-ping"));
+    assert!(!Path::new("permissions").exists());
+
+    std::fs::remove_dir_all(root).unwrap();
+  }
+
+  #[test]
+  fn command_descriptions() {
+    let root =
+      std::env::temp_dir().join(format!("tauri-plugin-descriptions-{}", std::process::id()));
+    let schema_dir = root.join("schemas");
+    let commands_dir = root.join("commands");
+
+    Builder::new(&["ping", "pong"])
+      .command_descriptions(HashMap::from([("ping", "Replies with \"pong\".")]))
+      .schema_out_dir(schema_dir.clone())
+      .commands_out_dir(commands_dir.clone())
+      .generate_permissions("tauri-plugin-ping")
+      .unwrap();
+
+    let permissions = std::fs::read_to_string(commands_dir.join("ping.toml")).unwrap();
+    assert!(permissions.contains(
+      r#"description = "Enables the ping command without any pre-configured scope. Replies with \"pong\".""#
+    ));
+    let schema =
+      std::fs::read_to_string(schema_dir.join(acl::build::PERMISSION_SCHEMA_FILE_NAME)).unwrap();
+    assert!(schema.contains(r#"allow-ping -> Enables the ping command without any pre-configured scope. Replies with \"pong\".""#));
+    assert!(
+      schema.contains("allow-pong -> Enables the pong command without any pre-configured scope.\"")
+    );
+
+    std::fs::remove_dir_all(root).unwrap();
+  }
+
+  #[test]
+  fn validate_examples() {
+    let root = std::env::temp_dir().join(format!("tauri-plugin-examples-{}", std::process::id()));
+    let examples_dir = root.join("examples");
+    std::fs::create_dir_all(&examples_dir).unwrap();
+    // permissions of other plugins are not checked
+    std::fs::write(
+      examples_dir.join("valid.json"),
+      r#"{ "identifier": "valid", "windows": ["main"], "permissions": ["ping:allow-ping", "fs:default"] }"#,
+    )
+    .unwrap();
+    std::fs::write(
+      examples_dir.join("invalid.json"),
+      r#"{ "identifier": "invalid", "windows": ["main"], "permissions": ["ping:allow-pong"] }"#,
+    )
+    .unwrap();
+
+    let check = |example: &str| {
+      let builder = Builder::new(&["ping"])
+        .schema_out_dir(root.join("schemas"))
+        .commands_out_dir(root.join("commands"))
+        .validate_examples(&examples_dir.join(example).display().to_string());
+      let permissions = builder.generate_permissions("tauri-plugin-ping").unwrap();
+      builder.check_examples("tauri-plugin-ping", permissions)
+    };
+
+    check("valid.json").unwrap();
+    assert!(matches!(
+      check("invalid.json"),
+      Err(Error::InvalidCapabilities(errors)) if matches!(
+        &errors[..],
+        [Error::CapabilityPermissionNotFound { permission, .. }] if permission == "ping:allow-pong"
+      )
+    ));
+
+    std::fs::remove_dir_all(root).unwrap();
+  }
+}
@@ -655,6 +655,12 @@ pub struct WindowBuilderWrapper {
   center: bool,
   #[cfg(target_os = "macos")]
   tabbing_identifier: Option<String>,
+  #[cfg(target_os = "macos")]
+  auto_tabbing: Option<bool>,
+  #[cfg(target_os = "macos")]
+  full_screen_auxiliary: bool,
+  #[cfg(target_os = "macos")]
+  titlebar_height: Option<f64>,
 }
 
 impl std::fmt::Debug for WindowBuilderWrapper {
@@ -663,7 +669,10 @@ impl std::fmt::Debug for WindowBuilderWrapper {
     s.field("inner", &self.inner).field("center", &self.center);
     #[cfg(target_os = "macos")]
     {
-      s.field("tabbing_identifier", &self.tabbing_identifier);
+      s.field("tabbing_identifier", &self.tabbing_identifier)
+        .field("auto_tabbing", &self.auto_tabbing)
+        .field("full_screen_auxiliary", &self.full_screen_auxiliary)
+        .field("titlebar_height", &self.titlebar_height);
     }
     s.finish()
   }
@@ -684,12 +693,20 @@ impl WindowBuilder for WindowBuilderWrapper {
 
     #[cfg(target_os = "macos")]
     {
+      // the native title bar cannot be resized
+      if config.title_bar_style != TitleBarStyle::Visible {
+        window.titlebar_height = config.titlebar_height;
+      }
       window = window
         .hidden_title(config.hidden_title)
         .title_bar_style(config.title_bar_style);
       if let Some(identifier) = &config.tabbing_identifier {
         window = window.tabbing_identifier(identifier);
+        if let Some(auto_tabbing) = config.auto_tabbing {
+          window = window.auto_tabbing(auto_tabbing);
+        }
       }
+      window.full_screen_auxiliary = config.visible_on_all_workspaces.full_screen_auxiliary();
     }
 
     #[cfg(any(not(target_os = "macos"), feature = "macos-private-api"))]
@@ -712,30 +729,37 @@ impl WindowBuilder for WindowBuilderWrapper {
     {
       // Mouse event is disabled on Linux since sudden event bursts could block event loop.
       window.inner = window.inner.with_cursor_moved_event(false);
+
+      // the margin reserved by the client-side decorations is see-through
+      if config.client_side_decorations && !config.decorations {
+        window = window.transparent(true);
+      }
     }
 
     #[cfg(desktop)]
     {
+      let (resizable_horizontal, resizable_vertical) = config.resizable_axes();
       window = window
         .title(config.title.to_string())
         .inner_size(config.width, config.height)
         .visible(config.visible)
-        .resizable(config.resizable)
+        .resizable(resizable_horizontal || resizable_vertical)
         .fullscreen(config.fullscreen)
         .decorations(config.decorations)
         .maximized(config.maximized)
         .always_on_bottom(config.always_on_bottom)
         .always_on_top(config.always_on_top)
-        .visible_on_all_workspaces(config.visible_on_all_workspaces)
+        .visible_on_all_workspaces(config.visible_on_all_workspaces.visible_on_all())
         .content_protected(config.content_protected)
         .skip_taskbar(config.skip_taskbar)
         .theme(config.theme)
         .shadow(config.shadow);
 
-      if let (Some(min_width), Some(min_height)) = (config.min_width, config.min_height) {
+      let (min_size, max_size) = config.inner_size_constraints();
+      if let Some((min_width, min_height)) = min_size {
         window = window.min_inner_size(min_width, min_height);
       }
-      if let (Some(max_width), Some(max_height)) = (config.max_width, config.max_height) {
+      if let Some((max_width, max_height)) = max_size {
         window = window.max_inner_size(max_width, max_height);
       }
       if let (Some(x), Some(y)) = (config.x, config.y) {
@@ -808,9 +832,11 @@ impl WindowBuilder for WindowBuilderWrapper {
 
   fn fullscreen(mut self, fullscreen: bool) -> Self {
     self.inner = if fullscreen {
+      // fullscreen takes precedence over maximized
       self
         .inner
         .with_fullscreen(Some(Fullscreen::Borderless(None)))
+        .with_maximized(false)
     } else {
       self.inner.with_fullscreen(None)
     };
@@ -823,7 +849,8 @@ impl WindowBuilder for WindowBuilderWrapper {
   }
 
   fn maximized(mut self, maximized: bool) -> Self {
-    self.inner = self.inner.with_maximized(maximized);
+    let fullscreen = self.inner.window.fullscreen.is_some();
+    self.inner = self.inner.with_maximized(maximized && !fullscreen);
     self
   }
 
@@ -934,6 +961,12 @@ impl WindowBuilder for WindowBuilderWrapper {
     self
   }
 
+  #[cfg(target_os = "macos")]
+  fn auto_tabbing(mut self, enabled: bool) -> Self {
+    self.auto_tabbing.replace(enabled);
+    self
+  }
+
   fn icon(mut self, icon: Icon) -> Result<Self> {
     self.inner = self
       .inner
@@ -954,13 +987,10 @@ impl WindowBuilder for WindowBuilderWrapper {
 
   #[allow(unused_variables, unused_mut)]
   fn theme(mut self, theme: Option<Theme>) -> Self {
-    self.inner = self.inner.with_theme(if let Some(t) = theme {
-      match t {
-        Theme::Dark => Some(TaoTheme::Dark),
-        _ => Some(TaoTheme::Light),
-      }
-    } else {
-      None
+    self.inner = self.inner.with_theme(match theme {
+      Some(Theme::Dark) => Some(TaoTheme::Dark),
+      Some(Theme::System) | None => None,
+      Some(_) => Some(TaoTheme::Light),
     });
 
     self
@@ -1157,6 +1187,7 @@ pub enum WebviewMessage {
   SetPosition(Position),
   SetSize(Size),
   SetFocus,
+  SetZoom(f64),
   // Getters
   Url(Sender<Url>),
   Position(Sender<PhysicalPosition<i32>>),
@@ -1334,6 +1365,17 @@ impl<T: UserEvent> WebviewDispatch<T> for WryWebviewDispatcher<T> {
     )
   }
 
+  fn set_zoom(&self, scale_factor: f64) -> Result<()> {
+    send_user_message(
+      &self.context,
+      Message::Webview(
+        self.window_id,
+        self.webview_id,
+        WebviewMessage::SetZoom(scale_factor),
+      ),
+    )
+  }
+
   #[cfg(all(feature = "tracing", not(target_os = "android")))]
   fn eval_script<S: Into<String>>(&self, script: S) -> Result<()> {
     // use a channel so the EvaluateScript task uses the current span as parent
@@ -1841,6 +1883,8 @@ pub struct WindowWrapper {
   webviews: Vec<WebviewWrapper>,
   window_event_listeners: WindowEventListeners,
   is_window_transparent: bool,
+  #[cfg(target_os = "macos")]
+  titlebar_height: Option<f64>,
   #[cfg(windows)]
   surface: Option<softbuffer::Surface<Arc<Window>, Arc<Window>>>,
 }
@@ -2016,6 +2060,10 @@ impl<T: UserEvent> RuntimeHandle<T> for WryHandle<T> {
       .collect()
   }
 
+  fn cursor_position(&self) -> Result<PhysicalPosition<f64>> {
+    cursor_position(&self.context.main_thread.window_target)
+  }
+
   #[cfg(target_os = "macos")]
   fn show(&self) -> tauri_runtime::Result<()> {
     send_user_message(
@@ -2257,6 +2305,10 @@ impl<T: UserEvent> Runtime<T> for Wry<T> {
       .collect()
   }
 
+  fn cursor_position(&self) -> Result<PhysicalPosition<f64>> {
+    cursor_position(&self.context.main_thread.window_target)
+  }
+
   #[cfg(target_os = "macos")]
   fn set_activation_policy(&mut self, activation_policy: ActivationPolicy) {
     self
@@ -2677,6 +2729,9 @@ fn handle_user_message<T: UserEvent>(
           WebviewMessage::SetFocus => {
             webview.focus();
           }
+          WebviewMessage::SetZoom(scale_factor) => {
+            webview.zoom(scale_factor);
+          }
           WebviewMessage::WebviewEvent(_event) => { /* already handled */ }
           WebviewMessage::WithWebview(f) => {
             #[cfg(any(
@@ -2813,6 +2868,8 @@ fn handle_user_message<T: UserEvent>(
             window_event_listeners: Default::default(),
             webviews: Vec::new(),
             is_window_transparent,
+            #[cfg(target_os = "macos")]
+            titlebar_height: None,
             #[cfg(windows)]
             surface,
           },
@@ -2970,6 +3027,14 @@ fn handle_event_loop<T: UserEvent>(
             }
           }
           TaoWindowEvent::Resized(size) => {
+            // AppKit restores the title bar layout when the window is resized
+            #[cfg(target_os = "macos")]
+            if let Some(window) = windows.borrow().get(&window_id) {
+              if let (Some(inner), Some(height)) = (&window.inner, window.titlebar_height) {
+                set_titlebar_height(inner, height);
+              }
+            }
+
             if let Some(webviews) = windows.borrow().get(&window_id).map(|w| w.webviews.clone()) {
               for webview in webviews {
                 if let Some(bounds) = &webview.bounds {
@@ -3072,6 +3137,66 @@ pub fn center_window(window: &Window, window_size: TaoPhysicalSize<u32>) -> Resu
   }
 }
 
+/// The position of the mouse cursor, in physical pixels relative to the larger full screen area.
+#[allow(unused_variables)]
+fn cursor_position<T: UserEvent>(
+  event_loop: &EventLoopWindowTarget<Message<T>>,
+) -> Result<PhysicalPosition<f64>> {
+  #[cfg(windows)]
+  {
+    use windows::Win32::{Foundation::POINT, UI::WindowsAndMessaging::GetCursorPos};
+
+    let mut point = POINT::default();
+    unsafe { GetCursorPos(&mut point) }.map_err(|_| Error::FailedToGetCursorPosition)?;
+    Ok(PhysicalPosition::new(point.x as f64, point.y as f64))
+  }
+
+  #[cfg(target_os = "macos")]
+  {
+    use cocoa::{appkit::NSEvent, base::nil};
+
+    // the location is in points, relative to the bottom left corner of the primary monitor
+    let location = unsafe { NSEvent::mouseLocation(nil) };
+    let monitor = event_loop
+      .primary_monitor()
+      .ok_or(Error::FailedToGetMonitor)?;
+    let scale_factor = monitor.scale_factor();
+    let height = monitor.size().height as f64 / scale_factor;
+    Ok(PhysicalPosition::new(
+      location.x * scale_factor,
+      (height - location.y) * scale_factor,
+    ))
+  }
+
+  #[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+  ))]
+  {
+    let display = gtk::gdk::Display::default().ok_or(Error::FailedToGetCursorPosition)?;
+    let pointer = display
+      .default_seat()
+      .and_then(|seat| seat.pointer())
+      .ok_or(Error::FailedToGetCursorPosition)?;
+    // the position is in logical pixels
+    let (_, x, y) = pointer.position();
+    let scale_factor = display
+      .monitor_at_point(x, y)
+      .map(|monitor| monitor.scale_factor())
+      .unwrap_or(1);
+    Ok(PhysicalPosition::new(
+      (x * scale_factor) as f64,
+      (y * scale_factor) as f64,
+    ))
+  }
+
+  #[cfg(mobile)]
+  Err(Error::FailedToGetCursorPosition)
+}
+
 fn create_window<T: UserEvent, F: Fn(RawWindow) + Send + 'static>(
   window_id: WindowId,
   webview_id: u32,
@@ -3109,8 +3234,54 @@ fn create_window<T: UserEvent, F: Fn(RawWindow) + Send + 'static>(
     }
   }
 
+  // the window is merged into a tab group when it is shown, so it is built hidden until its tabbing mode is set
+  #[cfg(target_os = "macos")]
+  let tabbing_mode = window_builder
+    .tabbing_identifier
+    .as_ref()
+    .and(window_builder.auto_tabbing);
+  #[cfg(target_os = "macos")]
+  let show_after_tabbing_mode = tabbing_mode.is_some() && window_builder.inner.window.visible;
+  #[cfg(target_os = "macos")]
+  if show_after_tabbing_mode {
+    window_builder.inner = window_builder.inner.with_visible(false);
+  }
+
   let window = window_builder.inner.build(event_loop).unwrap();
 
+  #[cfg(target_os = "macos")]
+  if window_builder.full_screen_auxiliary {
+    use cocoa::appkit::{NSWindow, NSWindowCollectionBehavior};
+    let ns_window = window.ns_window() as cocoa::base::id;
+    unsafe {
+      ns_window.setCollectionBehavior_(
+        ns_window.collectionBehavior()
+          | NSWindowCollectionBehavior::NSWindowCollectionBehaviorFullScreenAuxiliary,
+      );
+    }
+  }
+
+  #[cfg(target_os = "macos")]
+  if let Some(auto_tabbing) = tabbing_mode {
+    use cocoa::appkit::{NSWindow, NSWindowTabbingMode};
+    let ns_window = window.ns_window() as cocoa::base::id;
+    unsafe {
+      ns_window.setTabbingMode_(if auto_tabbing {
+        NSWindowTabbingMode::NSWindowTabbingModePreferred
+      } else {
+        NSWindowTabbingMode::NSWindowTabbingModeDisallowed
+      });
+    }
+    if show_after_tabbing_mode {
+      window.set_visible(true);
+    }
+  }
+
+  #[cfg(target_os = "macos")]
+  if let Some(height) = window_builder.titlebar_height {
+    set_titlebar_height(&window, height);
+  }
+
   #[cfg(feature = "tracing")]
   {
     drop(window_create_span);
@@ -3195,11 +3366,48 @@ fn create_window<T: UserEvent, F: Fn(RawWindow) + Send + 'static>(
     webviews,
     window_event_listeners,
     is_window_transparent,
+    #[cfg(target_os = "macos")]
+    titlebar_height: window_builder.titlebar_height,
     #[cfg(windows)]
     surface,
   })
 }
 
+/// Resizes the title bar of an `Overlay` or `Transparent` title bar window,
+/// vertically centering the traffic lights in it.
+#[cfg(target_os = "macos")]
+fn set_titlebar_height(window: &Window, height: f64) {
+  use cocoa::{
+    appkit::{NSView, NSWindow, NSWindowButton},
+    base::{id, nil},
+    foundation::{NSPoint, NSSize},
+  };
+
+  let ns_window = window.ns_window() as id;
+  unsafe {
+    let close = ns_window.standardWindowButton_(NSWindowButton::NSWindowCloseButton);
+    if close == nil {
+      return;
+    }
+    let miniaturize = ns_window.standardWindowButton_(NSWindowButton::NSWindowMiniaturizeButton);
+    let zoom = ns_window.standardWindowButton_(NSWindowButton::NSWindowZoomButton);
+
+    // the buttons are in the title bar view, itself in the title bar container view
+    let container = close.superview().superview();
+    let window_height = NSWindow::frame(ns_window).size.height;
+    let width = NSView::frame(container).size.width;
+    container.setFrameSize(NSSize::new(width, height));
+    container.setFrameOrigin(NSPoint::new(0., window_height - height));
+
+    let y = (height - NSView::frame(close).size.height) / 2.;
+    for button in [close, miniaturize, zoom] {
+      if button != nil {
+        button.setFrameOrigin(NSPoint::new(NSView::frame(button).origin.x, y));
+      }
+    }
+  }
+}
+
 // the kind of the webview
 enum WebviewKind {
   // webview is the entire window content
@@ -3469,6 +3677,13 @@ fn create_webview<T: UserEvent>(
     .build()
     .map_err(|e| Error::CreateWebview(Box::new(e)))?;
 
+  if webview_attributes.open_devtools {
+    #[cfg(any(debug_assertions, feature = "devtools"))]
+    webview.open_devtools();
+    #[cfg(not(any(debug_assertions, feature = "devtools")))]
+    debug_eprintln!("`open_devtools` is ignored because the devtools are not enabled");
+  }
+
   #[cfg(windows)]
   {
     let controller = webview.controller();
@@ -199,8 +199,11 @@ impl<T: UserEvent, R: Runtime<T>> PartialEq for DetachedWebview<T, R> {
 #[derive(Debug, Clone)]
 pub struct WebviewAttributes {
   pub url: WebviewUrl,
+  pub url_query: Option<String>,
+  pub url_fragment: Option<String>,
   pub user_agent: Option<String>,
   pub initialization_scripts: Vec<String>,
+  pub with_global_tauri: Option<bool>,
   pub data_directory: Option<PathBuf>,
   pub file_drop_handler_enabled: bool,
   pub clipboard: bool,
@@ -208,26 +211,43 @@ pub struct WebviewAttributes {
   pub additional_browser_args: Option<String>,
   pub window_effects: Option<WindowEffectsConfig>,
   pub incognito: bool,
+  pub context_menu: bool,
+  pub open_devtools: bool,
+  pub client_side_decorations: bool,
   pub transparent: bool,
   pub bounds: Option<(Position, Size)>,
   pub auto_resize: bool,
+  pub navigation_allowlist: Option<Vec<String>>,
 }
 
 impl From<&WindowConfig> for WebviewAttributes {
   fn from(config: &WindowConfig) -> Self {
     let mut builder = Self::new(config.url.clone());
+    builder.url_query = config.url_query.clone();
+    builder.url_fragment = config.url_fragment.clone();
+    builder.with_global_tauri = config.with_global_tauri;
     builder = builder.incognito(config.incognito);
+    builder = builder.context_menu(config.enable_context_menu);
+    builder = builder.open_devtools(config.open_devtools);
+    builder =
+      builder.client_side_decorations(config.client_side_decorations && !config.decorations);
     #[cfg(any(not(target_os = "macos"), feature = "macos-private-api"))]
     {
       builder = builder.transparent(config.transparent);
     }
     builder = builder.accept_first_mouse(config.accept_first_mouse);
+    if let Some(allowlist) = &config.navigation_allowlist {
+      builder = builder.navigation_allowlist(allowlist.clone());
+    }
     if !config.file_drop_enabled {
       builder = builder.disable_file_drop_handler();
     }
     if let Some(user_agent) = &config.user_agent {
       builder = builder.user_agent(user_agent);
     }
+    if let Some(script) = &config.initialization_script {
+      builder = builder.initialization_script(script);
+    }
     if let Some(additional_browser_args) = &config.additional_browser_args {
       builder = builder.additional_browser_args(additional_browser_args);
     }
@@ -243,8 +263,11 @@ impl WebviewAttributes {
   pub fn new(url: WebviewUrl) -> Self {
     Self {
       url,
+      url_query: None,
+      url_fragment: None,
       user_agent: None,
       initialization_scripts: Vec::new(),
+      with_global_tauri: None,
       data_directory: None,
       file_drop_handler_enabled: true,
       clipboard: false,
@@ -252,9 +275,13 @@ impl WebviewAttributes {
       additional_browser_args: None,
       window_effects: None,
       incognito: false,
+      context_menu: true,
+      open_devtools: false,
+      client_side_decorations: false,
       transparent: false,
       bounds: None,
       auto_resize: false,
+      navigation_allowlist: None,
     }
   }
 
@@ -272,6 +299,13 @@ impl WebviewAttributes {
     self
   }
 
+  /// Sets whether the Tauri API is injected on `window.__TAURI__`, overriding the build configuration.
+  #[must_use]
+  pub fn with_global_tauri(mut self, enabled: bool) -> Self {
+    self.with_global_tauri = Some(enabled);
+    self
+  }
+
   /// Data directory for the webview.
   #[must_use]
   pub fn data_directory(mut self, data_directory: PathBuf) -> Self {
@@ -324,6 +358,27 @@ impl WebviewAttributes {
     self
   }
 
+  /// Enable or disable the default context menu of the WebView.
+  #[must_use]
+  pub fn context_menu(mut self, enabled: bool) -> Self {
+    self.context_menu = enabled;
+    self
+  }
+
+  /// Opens the devtools when the webview is created. Ignored when the devtools are not compiled in.
+  #[must_use]
+  pub fn open_devtools(mut self, open: bool) -> Self {
+    self.open_devtools = open;
+    self
+  }
+
+  /// Reserve a transparent margin around the content that can be styled with CSS. Only supported on Linux.
+  #[must_use]
+  pub fn client_side_decorations(mut self, enabled: bool) -> Self {
+    self.client_side_decorations = enabled;
+    self
+  }
+
   /// Enable or disable transparency for the WebView.
   #[cfg(any(not(target_os = "macos"), feature = "macos-private-api"))]
   #[must_use]
@@ -332,6 +387,13 @@ impl WebviewAttributes {
     self
   }
 
+  /// Restricts the navigations of the webview to the URLs matching the glob patterns.
+  #[must_use]
+  pub fn navigation_allowlist(mut self, allowlist: Vec<String>) -> Self {
+    self.navigation_allowlist = Some(allowlist);
+    self
+  }
+
   /// Sets the webview to automatically grow and shrink its size and position when the parent window resizes.
   #[must_use]
   pub fn auto_resize(mut self) -> Self {
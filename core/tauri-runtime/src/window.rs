@@ -266,6 +266,8 @@ pub trait WindowBuilder: WindowBuilderBase {
   fn title<S: Into<String>>(self, title: S) -> Self;
 
   /// Whether to start the window in fullscreen or not.
+  ///
+  /// Takes precedence over [`Self::maximized`], so a fullscreen window is never maximized on creation.
   #[must_use]
   fn fullscreen(self, fullscreen: bool) -> Self;
 
@@ -274,6 +276,8 @@ pub trait WindowBuilder: WindowBuilderBase {
   fn focused(self, focused: bool) -> Self;
 
   /// Whether the window should be maximized upon creation.
+  ///
+  /// Ignored when the window starts in [fullscreen](Self::fullscreen).
   #[must_use]
   fn maximized(self, maximized: bool) -> Self;
 
@@ -385,7 +389,15 @@ pub trait WindowBuilder: WindowBuilderBase {
   #[must_use]
   fn tabbing_identifier(self, identifier: &str) -> Self;
 
-  /// Forces a theme or uses the system settings if None was provided.
+  /// Whether the window always opens as a tab (`true`) or never does (`false`) on macOS,
+  /// instead of following the system setting.
+  ///
+  /// Only applies to windows with a [tabbing identifier](Self::tabbing_identifier).
+  #[cfg(target_os = "macos")]
+  #[must_use]
+  fn auto_tabbing(self, enabled: bool) -> Self;
+
+  /// Forces a theme or uses the system settings if None or [`Theme::System`] was provided.
   fn theme(self, theme: Option<Theme>) -> Self;
 
   /// Whether the icon was set or not.
@@ -5,21 +5,27 @@
 //! ACL items that are only useful inside of build script/codegen context.
 
 use std::{
-  collections::{BTreeMap, HashMap},
+  collections::{BTreeMap, HashMap, HashSet},
   env::{current_dir, vars_os},
   fs::{create_dir_all, read_to_string, File},
   io::{BufWriter, Write},
   path::{Path, PathBuf},
 };
 
-use crate::acl::Error;
+use crate::{
+  acl::{Error, COMMAND_PREFIX_SEPARATOR},
+  platform::Target,
+};
 use schemars::{
   schema::{InstanceType, Metadata, RootSchema, Schema, SchemaObject, SubschemaValidation},
   schema_for,
 };
 use serde::Deserialize;
 
-use super::{capability::Capability, plugin::PermissionFile};
+use super::{
+  capability::Capability,
+  plugin::{Manifest, PermissionFile},
+};
 
 /// Cargo cfg key for permissions file paths
 pub const PERMISSION_FILES_PATH_KEY: &str = "PERMISSION_FILES_PATH";
@@ -53,23 +59,40 @@ pub enum CapabilityFile {
 
 /// Write the permissions to a temporary directory and pass it to the immediate consuming crate.
 pub fn define_permissions(pattern: &str, pkg_name: &str) -> Result<Vec<PermissionFile>, Error> {
-  let permission_files = glob::glob(pattern)?
-    .flatten()
-    .flat_map(|p| p.canonicalize())
-    // filter extension
-    .filter(|p| {
-      p.extension()
-        .and_then(|e| e.to_str())
-        .map(|e| PERMISSION_FILE_EXTENSIONS.contains(&e))
-        .unwrap_or_default()
-    })
-    // filter schema file
-    .filter(|p| {
-      p.file_name()
-        .map(|name| name != PERMISSION_SCHEMA_FILE_NAME)
-        .unwrap_or(true)
-    })
-    .collect::<Vec<PathBuf>>();
+  define_permissions_from(&[pattern], pkg_name)
+}
+
+/// Same as [`define_permissions`], but merges the permission files matched by all the given glob patterns.
+///
+/// Errors with [`Error::DuplicatePermission`] if a permission or permission set identifier is defined more than once.
+pub fn define_permissions_from(
+  patterns: &[&str],
+  pkg_name: &str,
+) -> Result<Vec<PermissionFile>, Error> {
+  let mut permission_files = Vec::new();
+  for pattern in patterns {
+    permission_files.extend(
+      glob::glob(pattern)?
+        .flatten()
+        .flat_map(|p| p.canonicalize())
+        // filter extension
+        .filter(|p| {
+          p.extension()
+            .and_then(|e| e.to_str())
+            .map(|e| PERMISSION_FILE_EXTENSIONS.contains(&e))
+            .unwrap_or_default()
+        })
+        // filter schema file
+        .filter(|p| {
+          p.file_name()
+            .map(|name| name != PERMISSION_SCHEMA_FILE_NAME)
+            .unwrap_or(true)
+        }),
+    );
+  }
+  // overlapping patterns must not define the same file twice
+  permission_files.sort();
+  permission_files.dedup();
 
   for path in &permission_files {
     println!("cargo:rerun-if-changed={}", path.display());
@@ -94,7 +117,29 @@ pub fn define_permissions(pattern: &str, pkg_name: &str) -> Result<Vec<Permissio
     );
   }
 
-  parse_permissions(permission_files)
+  let permissions = parse_permissions(permission_files)?;
+  check_duplicate_permissions(&permissions)?;
+  Ok(permissions)
+}
+
+fn check_duplicate_permissions(permission_files: &[PermissionFile]) -> Result<(), Error> {
+  let mut identifiers = HashSet::new();
+  for permission_file in permission_files {
+    let default = permission_file.default.as_ref().map(|_| "default");
+    let sets = permission_file.set.iter().map(|s| s.identifier.as_str());
+    let permissions = permission_file
+      .permission
+      .iter()
+      .map(|p| p.identifier.as_str());
+    for identifier in default.into_iter().chain(sets).chain(permissions) {
+      if !identifiers.insert(identifier) {
+        return Err(Error::DuplicatePermission {
+          permission: identifier.to_string(),
+        });
+      }
+    }
+  }
+  Ok(())
 }
 
 /// Parses all capability files with the given glob pattern.
@@ -139,6 +184,102 @@ pub fn parse_capabilities(
   Ok(capabilities_map)
 }
 
+/// Validates the capabilities applying to the given target against the plugin manifests.
+///
+/// Errors if a capability references a permission that does not exist
+/// or that is not available on the target platform.
+/// All the problems are reported at once with [`Error::InvalidCapabilities`].
+pub fn validate_capabilities(
+  plugin_manifests: &BTreeMap<String, Manifest>,
+  capabilities: &BTreeMap<String, Capability>,
+  target: Target,
+) -> Result<(), Error> {
+  let mut errors = Vec::new();
+
+  for capability in capabilities.values() {
+    if let Err(error) = capability.context.domain_patterns() {
+      errors.push(Error::InvalidCapability {
+        capability: capability.identifier.clone(),
+        error: Box::new(error),
+      });
+    }
+
+    if !capability.platforms.contains(&target) {
+      continue;
+    }
+
+    for permission_entry in &capability.permissions {
+      let permission_id = permission_entry.identifier();
+      if let Some((plugin_name, permission_name)) = permission_id.get().split_once(':') {
+        let manifest = plugin_manifests.get(plugin_name);
+        let permission_exists = manifest
+          .map(|manifest| {
+            if permission_name == "default" {
+              manifest.default_permission.is_some()
+            } else {
+              manifest.permissions.contains_key(permission_name)
+                || manifest.permission_sets.contains_key(permission_name)
+            }
+          })
+          .unwrap_or(false);
+
+        if !permission_exists {
+          // suggest the permissions of the plugin, or of all plugins if it is unknown
+          let candidates = match manifest {
+            Some(manifest) => vec![(plugin_name, manifest)],
+            None => plugin_manifests
+              .iter()
+              .map(|(plugin, manifest)| (plugin.as_str(), manifest))
+              .collect(),
+          };
+          let mut available_permissions = Vec::new();
+          for (plugin, manifest) in candidates {
+            if manifest.default_permission.is_some() {
+              available_permissions.push(format!("{plugin}:default"));
+            }
+            for p in manifest.permissions.keys() {
+              available_permissions.push(format!("{plugin}:{p}"));
+            }
+            for p in manifest.permission_sets.keys() {
+              available_permissions.push(format!("{plugin}:{p}"));
+            }
+          }
+
+          errors.push(Error::CapabilityPermissionNotFound {
+            capability: capability.identifier.clone(),
+            permission: permission_id.get().to_string(),
+            available: available_permissions.join(", "),
+          });
+          continue;
+        }
+
+        if let Some(platforms) = manifest
+          .and_then(|manifest| manifest.permissions.get(permission_name))
+          .filter(|permission| !permission.is_active(&target))
+          .and_then(|permission| permission.platforms.as_ref())
+        {
+          errors.push(Error::UnavailablePermission {
+            permission: permission_id.get().to_string(),
+            capability: capability.identifier.clone(),
+            target,
+            platforms: platforms
+              .iter()
+              .map(ToString::to_string)
+              .collect::<Vec<_>>()
+              .join(", "),
+          });
+        }
+      }
+    }
+  }
+
+  if errors.is_empty() {
+    Ok(())
+  } else {
+    Err(Error::InvalidCapabilities(errors))
+  }
+}
+
 fn permissions_schema(permissions: &[PermissionFile]) -> RootSchema {
   let mut schema = schema_for!(PermissionFile);
 
@@ -276,6 +417,40 @@ fn parse_permissions(paths: Vec<PathBuf>) -> Result<Vec<PermissionFile>, Error>
 
 /// Autogenerate permission files for a list of commands.
 pub fn autogenerate_command_permissions(path: &Path, commands: &[&str], license_header: &str) {
+  autogenerate_prefixed_command_permissions(path, commands, None, license_header)
+}
+
+/// Autogenerate permission files for a list of commands namespaced with the given prefix.
+///
+/// With a `fs` prefix, the `read_file` command permissions are `allow-fs-read-file` and `deny-fs-read-file`
+/// and they reference the `fs:read_file` command.
+pub fn autogenerate_prefixed_command_permissions(
+  path: &Path,
+  commands: &[&str],
+  prefix: Option<&str>,
+  license_header: &str,
+) {
+  autogenerate_described_command_permissions(
+    path,
+    commands,
+    prefix,
+    &HashMap::new(),
+    license_header,
+  )
+}
+
+/// Autogenerate permission files for a list of commands namespaced with the given prefix,
+/// see [`autogenerate_prefixed_command_permissions`].
+///
+/// The description of a command, looked up by its unprefixed name in `descriptions`,
+/// is appended to the description of its permissions so it shows up in the permissions and capabilities schemas.
+pub fn autogenerate_described_command_permissions(
+  path: &Path,
+  commands: &[&str],
+  prefix: Option<&str>,
+  descriptions: &HashMap<&str, &str>,
+  license_header: &str,
+) {
   if !path.exists() {
     create_dir_all(path).expect("unable to create autogenerated commands dir");
   }
@@ -287,8 +462,24 @@ pub fn autogenerate_command_permissions(path: &Path, commands: &[&str], license_
     .collect::<PathBuf>()
     .join(PERMISSION_SCHEMA_FILE_NAME);
 
-  for command in commands {
-    let slugified_command = command.replace('_', "-");
+  for command_name in commands {
+    let (command, slugified_command) = match prefix {
+      Some(prefix) => (
+        format!("{prefix}{COMMAND_PREFIX_SEPARATOR}{command_name}"),
+        format!("{prefix}-{command_name}").replace('_', "-"),
+      ),
+      None => (command_name.to_string(), command_name.replace('_', "-")),
+    };
+    let description = |action: &str| {
+      let description = match descriptions.get(command_name) {
+        Some(description) => {
+          format!("{action} the {command} command without any pre-configured scope. {description}")
+        }
+        None => format!("{action} the {command} command without any pre-configured scope."),
+      };
+      // JSON strings are valid TOML basic strings
+      serde_json::to_string(&description).unwrap()
+    };
     let toml = format!(
       r###"{license_header}# Automatically generated - DO NOT EDIT!
 
@@ -296,26 +487,28 @@ pub fn autogenerate_command_permissions(path: &Path, commands: &[&str], license_
 
 [[permission]]
 identifier = "allow-{slugified_command}"
-description = "Enables the {command} command without any pre-configured scope."
+description = {allow_description}
 commands.allow = ["{command}"]
 
 [[permission]]
 identifier = "deny-{slugified_command}"
-description = "Denies the {command} command without any pre-configured scope."
+description = {deny_description}
 commands.deny = ["{command}"]
 "###,
       command = command,
       slugified_command = slugified_command,
+      allow_description = description("Enables"),
+      deny_description = description("Denies"),
       schema_path = dunce::simplified(&schema_path)
         .display()
         .to_string()
         .replace('\\', "/")
     );
 
-    let out_path = path.join(format!("{command}.toml"));
+    let out_path = path.join(format!("{command_name}.toml"));
     if toml != read_to_string(&out_path).unwrap_or_default() {
       std::fs::write(out_path, toml)
-        .unwrap_or_else(|_| panic!("unable to autogenerate ${command}.toml"));
+        .unwrap_or_else(|_| panic!("unable to autogenerate ${command_name}.toml"));
     }
   }
 }
@@ -7,7 +7,7 @@
 use crate::{acl::Identifier, platform::Target};
 use serde::{Deserialize, Serialize};
 
-use super::Scopes;
+use super::{Error, Scopes};
 
 /// An entry for a permission value in a [`Capability`] can be either a raw permission [`Identifier`]
 /// or an object that references a permission and extends its scope.
@@ -90,5 +90,151 @@ pub enum CapabilityContext {
   Remote {
     /// Remote domains this capability refers to. Can use glob patterns.
     domains: Vec<String>,
+    /// The maximum number of labels a `*` label of the domain patterns matches, e.g. `1` to let `*.example.com`
+    /// match `api.example.com` but not `evil.api.example.com`. Must be at least `1`.
+    ///
+    /// When set, the domains are matched label by label: a label that is exactly `*` matches between one
+    /// and this number of labels, and the other labels, including the ones with a partial wildcard like `api-*`,
+    /// match exactly one label. When not set, the domains are matched as globs against the whole host,
+    /// so `*` matches any number of labels.
+    #[serde(
+      default,
+      rename = "subdomainDepth",
+      alias = "subdomain-depth",
+      skip_serializing_if = "Option::is_none"
+    )]
+    subdomain_depth: Option<u8>,
   },
 }
+
+impl CapabilityContext {
+  /// Compiles the remote domain glob patterns of this context.
+  ///
+  /// Returns an empty list for [`Self::Local`] and fails on the first pattern that does not compile.
+  pub fn domain_patterns(&self) -> Result<Vec<glob::Pattern>, Error> {
+    match self {
+      Self::Local => Ok(Vec::new()),
+      Self::Remote {
+        subdomain_depth: Some(0),
+        ..
+      } => Err(Error::InvalidSubdomainDepth),
+      Self::Remote { domains, .. } => domains
+        .iter()
+        .map(|domain| {
+          glob::Pattern::new(domain).map_err(|error| Error::RemoteDomainPattern {
+            domain: domain.clone(),
+            error,
+          })
+        })
+        .collect(),
+    }
+  }
+
+  /// Whether the given host matches one of the remote domain patterns of this context.
+  ///
+  /// Always `false` for [`Self::Local`]. Patterns that do not compile never match.
+  pub fn matches_domain(&self, host: &str) -> bool {
+    match self {
+      Self::Local => false,
+      Self::Remote {
+        domains,
+        subdomain_depth,
+      } => domains
+        .iter()
+        .any(|domain| matches_remote_domain(domain, *subdomain_depth, host)),
+    }
+  }
+}
+
+/// Whether the host matches the remote domain glob pattern, limiting the labels matched by a `*` label
+/// to the subdomain depth if set. See [`CapabilityContext::Remote`] for the matching semantics.
+///
+/// Patterns that do not compile never match.
+pub fn matches_remote_domain(pattern: &str, subdomain_depth: Option<u8>, host: &str) -> bool {
+  match subdomain_depth {
+    None => glob::Pattern::new(pattern)
+      .map(|pattern| pattern.matches(host))
+      .unwrap_or(false),
+    Some(depth) => {
+      let pattern = pattern.split('.').collect::<Vec<_>>();
+      let host = host.split('.').collect::<Vec<_>>();
+      matches_labels(&pattern, &host, depth.into())
+    }
+  }
+}
+
+fn matches_labels(pattern: &[&str], host: &[&str], depth: usize) -> bool {
+  match pattern.split_first() {
+    None => host.is_empty(),
+    Some((&"*", pattern)) => {
+      (1..=depth.min(host.len())).any(|labels| matches_labels(pattern, &host[labels..], depth))
+    }
+    Some((label, pattern)) => match host.split_first() {
+      Some((host_label, host)) => {
+        glob::Pattern::new(label)
+          .map(|label| label.matches(host_label))
+          .unwrap_or(false)
+          && matches_labels(pattern, host, depth)
+      }
+      None => false,
+    },
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::CapabilityContext;
+
+  fn remote(domains: &[&str]) -> CapabilityContext {
+    CapabilityContext::Remote {
+      domains: domains.iter().map(|d| d.to_string()).collect(),
+      subdomain_depth: None,
+    }
+  }
+
+  #[test]
+  fn remote_domain_matching() {
+    let context = remote(&["*.example.com", "tauri.app"]);
+    assert!(context.matches_domain("api.example.com"));
+    assert!(context.matches_domain("tauri.app"));
+    assert!(!context.matches_domain("example.org"));
+    assert!(!context.matches_domain("tauri.app.evil.com"));
+    assert!(!CapabilityContext::Local.matches_domain("tauri.app"));
+  }
+
+  #[test]
+  fn invalid_domain_pattern() {
+    let context = remote(&["tauri.app", "*.example.[com"]);
+    let error = context.domain_patterns().unwrap_err();
+    assert!(error.to_string().contains("`*.example.[com`"));
+    assert!(!context.matches_domain("api.example.com"));
+    assert_eq!(remote(&["tauri.app"]).domain_patterns().unwrap().len(), 1);
+  }
+
+  #[test]
+  fn subdomain_depth() {
+    let context = |subdomain_depth| CapabilityContext::Remote {
+      domains: vec!["*.example.com".into(), "api-*.tauri.app".into()],
+      subdomain_depth,
+    };
+
+    let unlimited = context(None);
+    assert!(unlimited.matches_domain("api.example.com"));
+    assert!(unlimited.matches_domain("evil.api.example.com"));
+
+    let single = context(Some(1));
+    assert!(single.matches_domain("api.example.com"));
+    assert!(!single.matches_domain("evil.api.example.com"));
+    assert!(!single.matches_domain("example.com"));
+    assert!(single.matches_domain("api-v2.tauri.app"));
+    assert!(!single.matches_domain("api-v2.evil.tauri.app"));
+    assert!(!single.matches_domain("api.example.com.evil.com"));
+
+    let double = context(Some(2));
+    assert!(double.matches_domain("evil.api.example.com"));
+    assert!(!double.matches_domain("a.b.c.example.com"));
+
+    let error = context(Some(0)).domain_patterns().unwrap_err();
+    assert!(matches!(error, crate::acl::Error::InvalidSubdomainDepth));
+  }
+}
@@ -9,6 +9,8 @@ use serde::{Deserialize, Serialize};
 use std::num::NonZeroU64;
 use thiserror::Error;
 
+use crate::platform::Target;
+
 pub use self::{identifier::*, value::*};
 
 #[cfg(feature = "build")]
@@ -19,6 +21,9 @@ pub mod plugin;
 pub mod resolved;
 pub mod value;
 
+/// Separator between a plugin command prefix and the command name, e.g. `fs:read_file`.
+pub const COMMAND_PREFIX_SEPARATOR: char = ':';
+
 /// Possible errors while processing ACL files.
 #[derive(Debug, Error)]
 pub enum Error {
@@ -59,10 +64,28 @@ pub enum Error {
   #[error("failed to execute: {0}")]
   Metadata(#[from] ::cargo_metadata::Error),
 
+  /// [`cargo_metadata`] was not able to complete successfully in offline mode
+  #[cfg(feature = "build")]
+  #[error("failed to execute in offline mode, make sure the Cargo.lock file exists and is up to date: {0}")]
+  MetadataOffline(::cargo_metadata::Error),
+
   /// Invalid glob
   #[error("failed to run glob: {0}")]
   Glob(#[from] glob::PatternError),
 
+  /// Invalid remote domain glob pattern in a capability context.
+  #[error("invalid remote domain pattern `{domain}`: {error}")]
+  RemoteDomainPattern {
+    /// The domain pattern.
+    domain: String,
+    /// The glob compilation error.
+    error: glob::PatternError,
+  },
+
+  /// The subdomain depth of a remote capability context is `0`.
+  #[error("the remote subdomain depth must be at least 1")]
+  InvalidSubdomainDepth,
+
   /// Invalid TOML encountered
   #[error("failed to parse TOML: {0}")]
   Toml(#[from] toml::de::Error),
@@ -88,6 +111,10 @@ pub enum Error {
     set: String,
   },
 
+  /// Invalid plugin command prefix.
+  #[error("invalid command prefix `{0}`, it must be a non-empty string of lowercase ASCII alphanumeric characters, hyphens or underscores")]
+  CommandPrefix(String),
+
   /// Plugin has no default permission.
   #[error("plugin {plugin} has no default permission")]
   MissingDefaultPermission {
@@ -113,6 +140,64 @@ pub enum Error {
     /// Permission identifier.
     permission: String,
   },
+
+  /// Invalid capability.
+  #[error("Invalid capability {capability}: {error}")]
+  InvalidCapability {
+    /// Capability identifier.
+    capability: String,
+    /// The validation error.
+    error: Box<Error>,
+  },
+
+  /// Permission referenced in a capability not found.
+  #[error("Permission {permission} referenced by capability {capability} not found, expected one of {available}")]
+  CapabilityPermissionNotFound {
+    /// Capability identifier.
+    capability: String,
+    /// Permission identifier.
+    permission: String,
+    /// Available permissions.
+    available: String,
+  },
+
+  /// Permission referenced in a capability is not available on the target platform.
+  #[error("Permission {permission} referenced by capability {capability} is not available on {target}, it only applies to {platforms}")]
+  UnavailablePermission {
+    /// Permission identifier.
+    permission: String,
+    /// Capability identifier.
+    capability: String,
+    /// The target platform.
+    target: Target,
+    /// The platforms the permission applies to.
+    platforms: String,
+  },
+
+  /// A permission or permission set identifier is defined more than once.
+  #[error("permission {permission} is defined more than once")]
+  DuplicatePermission {
+    /// Permission or permission set identifier.
+    permission: String,
+  },
+
+  /// All the problems found when validating the capabilities, in the order they were found.
+  #[error("{}", display_capability_errors(.0))]
+  InvalidCapabilities(Vec<Error>),
+}
+
+fn display_capability_errors(errors: &[Error]) -> String {
+  match errors {
+    [error] => error.to_string(),
+    errors => {
+      let mut message = format!("found {} capability errors:", errors.len());
+      for error in errors {
+        message.push_str("\n  - ");
+        message.push_str(&error.to_string());
+      }
+      message
+    }
+  }
 }
 
 /// Allowed and denied commands inside a permission.
@@ -168,6 +253,24 @@ pub struct Permission {
   /// Allowed or denied scoped when using this permission.
   #[serde(default)]
   pub scope: Scopes,
+
+  /// Target platforms this permission applies to. By default all platforms apply.
+  ///
+  /// A capability cannot reference the permission directly on other platforms,
+  /// and permission sets including it skip it there.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub platforms: Option<Vec<Target>>,
+}
+
+impl Permission {
+  /// Whether the permission applies to the given target platform.
+  pub fn is_active(&self, target: &Target) -> bool {
+    self
+      .platforms
+      .as_ref()
+      .map(|platforms| platforms.contains(target))
+      .unwrap_or(true)
+  }
 }
 
 /// A set of direct permissions grouped together under a new name.
@@ -193,6 +296,8 @@ pub enum ExecutionContext {
   Remote {
     /// The domain trying to access the IPC (glob pattern).
     domain: Pattern,
+    /// The maximum number of labels a `*` label of the domain matches, see [`capability::CapabilityContext::Remote`].
+    subdomain_depth: Option<u8>,
   },
 }
 
@@ -210,9 +315,13 @@ mod build_ {
         Self::Local => {
           quote! { #prefix::Local }
         }
-        Self::Remote { domain } => {
+        Self::Remote {
+          domain,
+          subdomain_depth,
+        } => {
           let domain = domain.as_str();
-          quote! { #prefix::Remote { domain: #domain.parse().unwrap() } }
+          let subdomain_depth = crate::tokens::opt_lit(subdomain_depth.as_ref());
+          quote! { #prefix::Remote { domain: #domain.parse().unwrap(), subdomain_depth: #subdomain_depth } }
         }
       });
     }
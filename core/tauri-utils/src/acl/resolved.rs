@@ -9,8 +9,6 @@ use std::{
   hash::{Hash, Hasher},
 };
 
-use glob::Pattern;
-
 use crate::platform::Target;
 
 use super::{
@@ -91,6 +89,10 @@ impl Resolved {
           let permissions = get_permissions(plugin_name, permission_name, &acl)?;
 
           for permission in permissions {
+            if !permission.is_active(&target) {
+              continue;
+            }
+
             let scope = match permission_entry {
               PermissionEntry::PermissionRef(_) => permission.scope.clone(),
               PermissionEntry::ExtendedPermission {
@@ -237,6 +239,61 @@ impl Resolved {
   }
 }
 
+/// Builds a [`Resolved`] ACL at runtime, e.g. for the capabilities of a dynamically loaded plugin.
+///
+/// The capabilities can only reference the plugins whose manifest is added to the builder.
+#[derive(Debug)]
+pub struct ResolvedBuilder {
+  acl: BTreeMap<String, Manifest>,
+  capabilities: BTreeMap<String, Capability>,
+  target: Target,
+}
+
+impl Default for ResolvedBuilder {
+  fn default() -> Self {
+    Self {
+      acl: BTreeMap::new(),
+      capabilities: BTreeMap::new(),
+      target: Target::current(),
+    }
+  }
+}
+
+impl ResolvedBuilder {
+  /// Creates a builder resolving the capabilities for the current platform.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Sets the platform the capabilities are resolved for.
+  #[must_use]
+  pub fn target(mut self, target: Target) -> Self {
+    self.target = target;
+    self
+  }
+
+  /// Adds the permission manifest of the plugin `name`.
+  #[must_use]
+  pub fn plugin(mut self, name: impl Into<String>, manifest: Manifest) -> Self {
+    self.acl.insert(name.into(), manifest);
+    self
+  }
+
+  /// Adds a capability, replacing the one with the same identifier if any.
+  #[must_use]
+  pub fn capability(mut self, capability: Capability) -> Self {
+    self
+      .capabilities
+      .insert(capability.identifier.clone(), capability);
+    self
+  }
+
+  /// Resolves the capabilities against the plugin manifests.
+  pub fn build(self) -> Result<Resolved, Error> {
+    Resolved::resolve(self.acl, self.capabilities, self.target)
+  }
+}
+
 fn parse_window_patterns(windows: HashSet<String>) -> Result<Vec<glob::Pattern>, Error> {
   let mut patterns = Vec::new();
   for window in windows {
@@ -262,11 +319,16 @@ fn resolve_command(
     CapabilityContext::Local => {
       vec![ExecutionContext::Local]
     }
-    CapabilityContext::Remote { domains } => domains
-      .iter()
+    CapabilityContext::Remote {
+      subdomain_depth, ..
+    } => capability
+      .context
+      .domain_patterns()
+      .unwrap_or_else(|e| panic!("{e}"))
+      .into_iter()
       .map(|domain| ExecutionContext::Remote {
-        domain: Pattern::new(domain)
-          .unwrap_or_else(|e| panic!("invalid glob pattern for remote domain {domain}: {e}")),
+        domain,
+        subdomain_depth: *subdomain_depth,
       })
       .collect(),
   };
@@ -9,8 +9,14 @@
 pub use phf;
 use std::{
   borrow::Cow,
-  path::{Component, Path},
+  io::{Cursor, Read},
+  path::{Component, Path, PathBuf},
 };
+#[cfg(feature = "compression")]
+use std::{collections::BTreeMap, sync::Mutex};
+
+/// Iterator over the assets, yielding the key and content of each asset.
+pub type AssetsIter<'a> = dyn Iterator<Item = (Cow<'a, str>, Cow<'a, [u8]>)> + 'a;
 
 /// Represent an asset file path in a normalized way.
 ///
@@ -109,34 +115,115 @@ pub trait Assets: Send + Sync + 'static {
   /// Get the content of the passed [`AssetKey`].
   fn get(&self, key: &AssetKey) -> Option<Cow<'_, [u8]>>;
 
+  /// Get the length in bytes of the content of the passed [`AssetKey`].
+  ///
+  /// The default implementation reads the whole asset with [`Self::get`].
+  /// Implementations should override it when the length is known without reading the asset.
+  fn content_len(&self, key: &AssetKey) -> Option<u64> {
+    self.get(key).map(|content| content.len() as u64)
+  }
+
+  /// Get a reader over the content of the passed [`AssetKey`], starting `offset` bytes into it,
+  /// so large assets such as videos can be streamed instead of being loaded into memory at once.
+  ///
+  /// The default implementation reads the whole asset with [`Self::get`].
+  /// Implementations embedding their assets in the binary can return a [`Cursor`] over the embedded bytes,
+  /// while implementations reading their assets from disk can return the opened and seeked [`std::fs::File`].
+  fn get_reader(&self, key: &AssetKey, offset: u64) -> Option<Box<dyn Read + Send + '_>> {
+    self.get(key).map(|content| {
+      let mut cursor = Cursor::new(content);
+      cursor.set_position(offset);
+      Box::new(cursor) as Box<dyn Read + Send + '_>
+    })
+  }
+
   /// Iterator for the assets.
-  fn iter(&self) -> Box<dyn Iterator<Item = (&&str, &&[u8])> + '_>;
+  fn iter(&self) -> Box<AssetsIter<'_>>;
 
   /// Gets the hashes for the CSP tag of the HTML on the given path.
   fn csp_hashes(&self, html_path: &AssetKey) -> Box<dyn Iterator<Item = CspHash<'_>> + '_>;
 }
 
+/// A map of compile-time embedded assets.
+pub type EmbeddedAssetMap = phf::Map<&'static str, &'static [u8]>;
+
+/// The embedded assets, in a single map or split into several chunks.
+#[derive(Debug)]
+enum AssetMaps {
+  Single(EmbeddedAssetMap),
+  Chunked(&'static [&'static EmbeddedAssetMap]),
+}
+
+impl AssetMaps {
+  fn get(&self, key: &str) -> Option<&'static [u8]> {
+    self.get_key_value(key).map(|(_, asset)| asset)
+  }
+
+  fn get_key_value(&self, key: &str) -> Option<(&'static str, &'static [u8])> {
+    match self {
+      Self::Single(map) => map.get_entry(key),
+      Self::Chunked(chunks) => chunks.iter().find_map(|chunk| chunk.get_entry(key)),
+    }
+    .map(|(key, asset)| (*key, *asset))
+  }
+
+  fn iter(&self) -> Box<dyn Iterator<Item = (&'static str, &'static [u8])> + '_> {
+    match self {
+      Self::Single(map) => Box::new(map.entries().map(|(key, asset)| (*key, *asset))),
+      Self::Chunked(chunks) => Box::new(
+        chunks
+          .iter()
+          .flat_map(|chunk| chunk.entries())
+          .map(|(key, asset)| (*key, *asset)),
+      ),
+    }
+  }
+}
+
 /// [`Assets`] implementation that only contains compile-time compressed and embedded assets.
 #[derive(Debug)]
 pub struct EmbeddedAssets {
-  assets: phf::Map<&'static str, &'static [u8]>,
+  assets: AssetMaps,
   // Hashes that must be injected to the CSP of every HTML file.
   global_hashes: &'static [CspHash<'static>],
   // Hashes that are associated to the CSP of the HTML file identified by the map key (the HTML asset key).
   html_hashes: phf::Map<&'static str, &'static [CspHash<'static>]>,
+  // Decompressed length of the assets, computed the first time it is requested.
+  #[cfg(feature = "compression")]
+  content_lengths: Mutex<BTreeMap<&'static str, u64>>,
 }
 
 impl EmbeddedAssets {
   /// Creates a new instance from the given asset map and script hash list.
   pub const fn new(
-    map: phf::Map<&'static str, &'static [u8]>,
+    map: EmbeddedAssetMap,
     global_hashes: &'static [CspHash<'static>],
     html_hashes: phf::Map<&'static str, &'static [CspHash<'static>]>,
   ) -> Self {
     Self {
-      assets: map,
+      assets: AssetMaps::Single(map),
       global_hashes,
       html_hashes,
+      #[cfg(feature = "compression")]
+      content_lengths: Mutex::new(BTreeMap::new()),
+    }
+  }
+
+  /// Creates a new instance from assets split into several maps, each key being in a single chunk.
+  ///
+  /// Used by the `asset_chunking` option of `generate_context!`, the assets behave exactly like
+  /// the ones in a single map created with [`Self::new`].
+  pub const fn new_chunked(
+    chunks: &'static [&'static EmbeddedAssetMap],
+    global_hashes: &'static [CspHash<'static>],
+    html_hashes: phf::Map<&'static str, &'static [CspHash<'static>]>,
+  ) -> Self {
+    Self {
+      assets: AssetMaps::Chunked(chunks),
+      global_hashes,
+      html_hashes,
+      #[cfg(feature = "compression")]
+      content_lengths: Mutex::new(BTreeMap::new()),
     }
   }
 }
@@ -147,7 +234,7 @@ impl Assets for EmbeddedAssets {
     self
       .assets
       .get(key.as_ref())
-      .map(|&(mut asdf)| {
+      .map(|mut asdf| {
         // with the exception of extremely small files, output should usually be
         // at least as large as the compressed version.
         let mut buf = Vec::with_capacity(asdf.len());
@@ -162,12 +249,58 @@ impl Assets for EmbeddedAssets {
     self
       .assets
       .get(key.as_ref())
-      .copied()
       .map(|a| Cow::Owned(a.to_vec()))
   }
 
-  fn iter(&self) -> Box<dyn Iterator<Item = (&&str, &&[u8])> + '_> {
-    Box::new(self.assets.into_iter())
+  #[cfg(feature = "compression")]
+  fn content_len(&self, key: &AssetKey) -> Option<u64> {
+    let (key, asset) = self.assets.get_key_value(key.as_ref())?;
+    let mut lengths = self.content_lengths.lock().unwrap();
+    if let Some(len) = lengths.get(key) {
+      return Some(*len);
+    }
+    // brotli does not store the decompressed length, so the asset is decompressed once
+    let len = std::io::copy(
+      &mut brotli::Decompressor::new(asset, 4096),
+      &mut std::io::sink(),
+    )
+    .ok()?;
+    lengths.insert(key, len);
+    Some(len)
+  }
+
+  #[cfg(not(feature = "compression"))]
+  fn content_len(&self, key: &AssetKey) -> Option<u64> {
+    self
+      .assets
+      .get(key.as_ref())
+      .map(|asset| asset.len() as u64)
+  }
+
+  /// The compressed assets cannot be seeked, so the first `offset` bytes are decompressed and skipped.
+  #[cfg(feature = "compression")]
+  fn get_reader(&self, key: &AssetKey, offset: u64) -> Option<Box<dyn Read + Send + '_>> {
+    let mut reader = brotli::Decompressor::new(self.assets.get(key.as_ref())?, 4096);
+    std::io::copy(&mut (&mut reader).take(offset), &mut std::io::sink()).ok()?;
+    Some(Box::new(reader))
+  }
+
+  #[cfg(not(feature = "compression"))]
+  fn get_reader(&self, key: &AssetKey, offset: u64) -> Option<Box<dyn Read + Send + '_>> {
+    self.assets.get(key.as_ref()).map(|asset| {
+      let mut cursor = Cursor::new(asset);
+      cursor.set_position(offset);
+      Box::new(cursor) as Box<dyn Read + Send + '_>
+    })
+  }
+
+  fn iter(&self) -> Box<AssetsIter<'_>> {
+    Box::new(
+      self
+        .assets
+        .iter()
+        .map(|(key, asset)| (Cow::Borrowed(key), Cow::Borrowed(asset))),
+    )
   }
 
   fn csp_hashes(&self, html_path: &AssetKey) -> Box<dyn Iterator<Item = CspHash<'_>> + '_> {
@@ -187,3 +320,192 @@ impl Assets for EmbeddedAssets {
     )
   }
 }
+
+/// Assets read from a directory on disk when they are requested instead of being embedded in the binary.
+///
+/// Used by `generate_context!(assets = "live")` on development builds, so changes to the frontend
+/// are picked up without rebuilding the app. The files are served as they are on disk:
+/// unlike [`EmbeddedAssets`], the CSP is not injected into the HTML files and no CSP hashes are computed.
+#[derive(Debug)]
+pub struct LiveAssets {
+  root: PathBuf,
+}
+
+impl LiveAssets {
+  /// Creates the assets serving the files of the `root` directory.
+  pub fn new(root: PathBuf) -> Self {
+    Self { root }
+  }
+
+  /// The path of the file for the given key, unless the key escapes the root directory.
+  fn path(&self, key: &AssetKey) -> Option<PathBuf> {
+    let relative = Path::new(key.as_ref().trim_start_matches('/'));
+    relative
+      .components()
+      .all(|component| matches!(component, Component::Normal(_)))
+      .then(|| self.root.join(relative))
+  }
+}
+
+impl Assets for LiveAssets {
+  fn get(&self, key: &AssetKey) -> Option<Cow<'_, [u8]>> {
+    std::fs::read(self.path(key)?).ok().map(Cow::Owned)
+  }
+
+  fn content_len(&self, key: &AssetKey) -> Option<u64> {
+    let metadata = std::fs::metadata(self.path(key)?).ok()?;
+    metadata.is_file().then_some(metadata.len())
+  }
+
+  fn get_reader(&self, key: &AssetKey, offset: u64) -> Option<Box<dyn Read + Send + '_>> {
+    use std::io::{Seek, SeekFrom};
+    let mut file = std::fs::File::open(self.path(key)?).ok()?;
+    file.seek(SeekFrom::Start(offset)).ok()?;
+    Some(Box::new(file))
+  }
+
+  fn iter(&self) -> Box<AssetsIter<'_>> {
+    let mut paths = Vec::new();
+    let mut dirs = vec![self.root.clone()];
+    while let Some(dir) = dirs.pop() {
+      for entry in std::fs::read_dir(dir).into_iter().flatten().flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+          dirs.push(path);
+        } else {
+          paths.push(path);
+        }
+      }
+    }
+    paths.sort();
+
+    Box::new(paths.into_iter().filter_map(|path| {
+      let key = AssetKey::from(path.strip_prefix(&self.root).ok()?);
+      let content = std::fs::read(&path).ok()?;
+      Some((Cow::Owned(key.into()), Cow::Owned(content)))
+    }))
+  }
+
+  fn csp_hashes(&self, _html_path: &AssetKey) -> Box<dyn Iterator<Item = CspHash<'_>> + '_> {
+    Box::new(std::iter::empty())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::collections::HashMap;
+
+  struct MapAssets(HashMap<AssetKey, Vec<u8>>);
+
+  impl Assets for MapAssets {
+    fn get(&self, key: &AssetKey) -> Option<Cow<'_, [u8]>> {
+      self
+        .0
+        .get(key)
+        .map(|content| Cow::Borrowed(content.as_slice()))
+    }
+
+    fn iter(&self) -> Box<AssetsIter<'_>> {
+      Box::new(std::iter::empty())
+    }
+
+    fn csp_hashes(&self, _html_path: &AssetKey) -> Box<dyn Iterator<Item = CspHash<'_>> + '_> {
+      Box::new(std::iter::empty())
+    }
+  }
+
+  #[test]
+  fn default_get_reader() {
+    let video = (0..=u8::MAX).cycle().take(10_000).collect::<Vec<u8>>();
+    let assets = MapAssets(HashMap::from([("/video.mp4".into(), video.clone())]));
+
+    let mut reader = assets.get_reader(&"video.mp4".into(), 0).unwrap();
+    let mut chunk = [0; 16];
+    reader.read_exact(&mut chunk).unwrap();
+    assert_eq!(chunk, video[..16]);
+    let mut rest = Vec::new();
+    reader.read_to_end(&mut rest).unwrap();
+    assert_eq!(rest, video[16..]);
+
+    let mut rest = Vec::new();
+    assets
+      .get_reader(&"video.mp4".into(), 9_000)
+      .unwrap()
+      .read_to_end(&mut rest)
+      .unwrap();
+    assert_eq!(rest, video[9_000..]);
+    assert_eq!(assets.content_len(&"video.mp4".into()), Some(10_000));
+
+    assert!(assets.get_reader(&"missing.mp4".into(), 0).is_none());
+    assert!(assets.content_len(&"missing.mp4".into()).is_none());
+  }
+
+  #[test]
+  fn chunked_embedded_assets() {
+    static CHUNK_0: EmbeddedAssetMap = phf::phf_map! { "/index.html" => b"index" };
+    static CHUNK_1: EmbeddedAssetMap =
+      phf::phf_map! { "/main.js" => b"main", "/style.css" => b"style" };
+    static CHUNKS: [&EmbeddedAssetMap; 2] = [&CHUNK_0, &CHUNK_1];
+
+    let single = EmbeddedAssets::new(
+      phf::phf_map! { "/index.html" => b"index", "/main.js" => b"main", "/style.css" => b"style" },
+      &[],
+      phf::phf_map! {},
+    );
+    let chunked = EmbeddedAssets::new_chunked(&CHUNKS, &[], phf::phf_map! {});
+
+    let mut single_assets = single.iter().collect::<Vec<_>>();
+    let mut chunked_assets = chunked.iter().collect::<Vec<_>>();
+    single_assets.sort();
+    chunked_assets.sort();
+    assert_eq!(single_assets, chunked_assets);
+
+    for key in ["/index.html", "/main.js", "/style.css", "/missing.js"] {
+      let key = AssetKey::from(key);
+      assert_eq!(single.get(&key), chunked.get(&key));
+    }
+  }
+
+  #[test]
+  fn live_assets() {
+    let root = std::env::temp_dir().join(format!("tauri-live-assets-{}", std::process::id()));
+    std::fs::create_dir_all(root.join("assets")).unwrap();
+    std::fs::write(root.join("index.html"), "<html></html>").unwrap();
+    std::fs::write(root.join("assets").join("main.js"), "console.log(1)").unwrap();
+    let assets = LiveAssets::new(root.clone());
+
+    assert_eq!(
+      assets.get(&"index.html".into()).as_deref(),
+      Some(&b"<html></html>"[..])
+    );
+    assert_eq!(assets.content_len(&"/assets/main.js".into()), Some(14));
+    let mut rest = String::new();
+    assets
+      .get_reader(&"/assets/main.js".into(), 8)
+      .unwrap()
+      .read_to_string(&mut rest)
+      .unwrap();
+    assert_eq!(rest, "log(1)");
+
+    // picked up without recreating the assets
+    std::fs::write(root.join("index.html"), "<html>changed</html>").unwrap();
+    assert_eq!(
+      assets.get(&"index.html".into()).as_deref(),
+      Some(&b"<html>changed</html>"[..])
+    );
+
+    assert!(assets.get(&"missing.js".into()).is_none());
+    assert!(assets.get(&"/".into()).is_none());
+    assert!(assets.content_len(&"assets".into()).is_none());
+    assert!(assets.get(&"../index.html".into()).is_none());
+
+    let keys = assets
+      .iter()
+      .map(|(key, _)| key.into_owned())
+      .collect::<Vec<_>>();
+    assert_eq!(keys, ["/assets/main.js", "/index.html"]);
+
+    std::fs::remove_dir_all(root).unwrap();
+  }
+}
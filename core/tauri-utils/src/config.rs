@@ -16,7 +16,7 @@ use heck::ToKebabCase;
 use schemars::JsonSchema;
 use semver::Version;
 use serde::{
-  de::{Deserializer, Error as DeError, Visitor},
+  de::{DeserializeOwned, Deserializer, Error as DeError, Visitor},
   Deserialize, Serialize, Serializer,
 };
 use serde_json::Value as JsonValue;
@@ -24,17 +24,18 @@ use serde_with::skip_serializing_none;
 use url::Url;
 
 use std::{
-  collections::HashMap,
+  collections::{HashMap, HashSet},
   fmt::{self, Display},
   fs::read_to_string,
-  path::PathBuf,
+  path::{Path, PathBuf},
   str::FromStr,
 };
 
+mod lenient;
 /// Items to help with parsing content into a [`Config`].
 pub mod parse;
 
-use crate::{TitleBarStyle, WindowEffect, WindowEffectState};
+use crate::{platform::Target, TitleBarStyle, WindowEffect, WindowEffectState};
 
 pub use self::parse::parse;
 
@@ -71,6 +72,71 @@ impl Default for WebviewUrl {
   }
 }
 
+impl WebviewUrl {
+  /// Joins a path, optionally with a query string, onto this URL.
+  ///
+  /// For [`Self::App`], the path is appended to the app path, ignoring empty and `.` segments,
+  /// and both `/` and `\` are treated as separators. `..` segments and segments that are not plain names,
+  /// e.g. the `C:` drive prefix on Windows, are rejected.
+  ///
+  /// For [`Self::External`], the path is resolved relative to the URL like a link on its page would be,
+  /// so it replaces the last path segment unless the URL ends with `/`.
+  /// Paths resolving to another origin, e.g. absolute URLs, are rejected.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use tauri_utils::config::WebviewUrl;
+  ///
+  /// let url = WebviewUrl::App("settings".into()).join("users/john").unwrap();
+  /// assert_eq!(url, WebviewUrl::App("settings/users/john".into()));
+  ///
+  /// let url = WebviewUrl::External("https://tauri.app/docs/".parse().unwrap())
+  ///   .join("guides?lang=en")
+  ///   .unwrap();
+  /// assert_eq!(url.to_string(), "https://tauri.app/docs/guides?lang=en");
+  ///
+  /// assert!(WebviewUrl::App("settings".into()).join("../secret").is_err());
+  /// ```
+  pub fn join(&self, path: &str) -> crate::Result<Self> {
+    match self {
+      Self::External(url) => {
+        let joined = url.join(path)?;
+        // origins of non-special schemes are opaque and never equal, so compare their parts instead
+        if joined.scheme() == url.scheme()
+          && joined.host() == url.host()
+          && joined.port_or_known_default() == url.port_or_known_default()
+        {
+          Ok(Self::External(joined))
+        } else {
+          Err(crate::Error::WebviewUrlEscape(path.into()))
+        }
+      }
+      Self::App(base) => {
+        let mut joined = base.clone();
+        for segment in path.split(['/', '\\']) {
+          match segment {
+            "" | "." => (),
+            ".." => return Err(crate::Error::WebviewUrlEscape(path.into())),
+            segment => {
+              // e.g. `C:` on Windows would replace the base path when pushed
+              let mut components = Path::new(segment).components();
+              if !matches!(
+                (components.next(), components.next()),
+                (Some(std::path::Component::Normal(_)), None)
+              ) {
+                return Err(crate::Error::WebviewUrlEscape(path.into()));
+              }
+              joined.push(segment)
+            }
+          }
+        }
+        Ok(Self::App(joined))
+      }
+    }
+  }
+}
+
 /// A bundle referenced by tauri-bundler.
 #[derive(Debug, PartialEq, Eq, Clone)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -94,6 +160,50 @@ pub enum BundleType {
   Updater,
 }
 
+impl BundleType {
+  /// All bundle types, in declaration order.
+  const ALL: &'static [Self] = &[
+    Self::Deb,
+    Self::Rpm,
+    Self::AppImage,
+    Self::Msi,
+    Self::Nsis,
+    Self::App,
+    Self::Dmg,
+    Self::Updater,
+  ];
+
+  /// The file extension of the artifacts of this bundle type, without the leading `.`.
+  ///
+  /// Note that the [`Nsis`](Self::Nsis) installer is an `.exe` file,
+  /// the [`App`](Self::App) bundle is a directory named `<product name>.app`
+  /// and the [`Updater`](Self::Updater) archives are `.tar.gz` files on macOS and Linux
+  /// while on Windows they are the zipped installers, e.g. `.msi.zip` and `.nsis.zip` files.
+  pub fn extension(&self) -> &'static str {
+    match self {
+      Self::Deb => "deb",
+      Self::Rpm => "rpm",
+      Self::AppImage => "AppImage",
+      Self::Msi => "msi",
+      Self::Nsis => "exe",
+      Self::App => "app",
+      Self::Dmg => "dmg",
+      Self::Updater => "tar.gz",
+    }
+  }
+
+  /// The bundle type producing artifacts with the given file extension, see [`Self::extension`].
+  ///
+  /// The extension is case insensitive and may have a leading `.`.
+  pub fn from_extension(extension: &str) -> Option<Self> {
+    let extension = extension.strip_prefix('.').unwrap_or(extension);
+    Self::ALL
+      .iter()
+      .find(|bundle_type| bundle_type.extension().eq_ignore_ascii_case(extension))
+      .cloned()
+  }
+}
+
 impl Display for BundleType {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     write!(
@@ -242,13 +352,50 @@ impl<'de> Deserialize<'de> for BundleTarget {
   }
 }
 
+/// Parses a comma-separated, case-insensitive list of bundle targets, or `all`.
+///
+/// Used for the `TAURI_BUNDLE_TARGETS` environment variable.
+impl FromStr for BundleTarget {
+  type Err = serde::de::value::Error;
+
+  fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+    use serde::de::IntoDeserializer;
+
+    if s.trim().eq_ignore_ascii_case("all") {
+      return Ok(Self::All);
+    }
+
+    let targets = s
+      .split(',')
+      .map(str::trim)
+      .filter(|t| !t.is_empty())
+      .map(|t| BundleType::deserialize(t.into_deserializer()))
+      .collect::<std::result::Result<Vec<_>, _>>()?;
+    if targets.is_empty() {
+      Err(DeError::custom("no bundle targets"))
+    } else {
+      Ok(Self::List(targets))
+    }
+  }
+}
+
 impl BundleTarget {
   /// Gets the bundle targets as a [`Vec`]. The vector is empty when set to [`BundleTarget::All`].
+  ///
+  /// Duplicated targets are removed, keeping the order in which they were first listed.
   #[allow(dead_code)]
   pub fn to_vec(&self) -> Vec<BundleType> {
     match self {
       Self::All => vec![],
-      Self::List(list) => list.clone(),
+      Self::List(list) => {
+        let mut targets = Vec::with_capacity(list.len());
+        for target in list {
+          if !targets.contains(target) {
+            targets.push(target.clone());
+          }
+        }
+        targets
+      }
       Self::One(i) => vec![i.clone()],
     }
   }
@@ -456,6 +603,134 @@ pub struct MacConfig {
   pub provider_short_name: Option<String>,
   /// Path to the entitlements file.
   pub entitlements: Option<String>,
+  /// Additional keys to add to the bundle's `Info.plist` file,
+  /// e.g. `{ "NSCameraUsageDescription": "Used to take your profile picture" }`.
+  ///
+  /// JSON values are mapped to their equivalent plist types. `null` values are not allowed.
+  /// Keys managed by Tauri (e.g. `CFBundleIdentifier`) take precedence over the values set here,
+  /// while an `Info.plist` file next to the Tauri configuration file overrides all keys.
+  #[serde(default, alias = "info-plist", deserialize_with = "de_info_plist")]
+  pub info_plist: HashMap<String, JsonValue>,
+  /// Glob patterns, relative to the bundle's `Contents` directory, matching nested components
+  /// that must not be re-signed, e.g. `["Resources/helpers/*.app", "Frameworks/Prebuilt.framework"]`.
+  ///
+  /// Nested code is signed inside out, before the app bundle itself, and excluded components are skipped entirely.
+  /// They must already be validly signed (with the hardened runtime and a secure timestamp),
+  /// otherwise notarization of the app bundle fails.
+  #[serde(
+    default,
+    alias = "exclude-from-signing",
+    deserialize_with = "de_exclude_from_signing"
+  )]
+  pub exclude_from_signing: Vec<String>,
+  /// Whether to sign the external binaries (sidecars) with the app's signing identity. Defaults to `true`.
+  ///
+  /// Notarization requires every executable in the app bundle to be signed with the hardened runtime,
+  /// so it is effectively required for apps with sidecars, unless they are already signed and excluded
+  /// with [`Self::exclude_from_signing`].
+  #[serde(default = "default_true", alias = "sign-external-binaries")]
+  pub sign_external_binaries: bool,
+  /// The credentials used to notarize the app with `notarytool`.
+  ///
+  /// When not set, the credentials are read from the `APPLE_ID`, `APPLE_PASSWORD` and `APPLE_TEAM_ID`
+  /// or the `APPLE_API_KEY`, `APPLE_API_ISSUER` and `APPLE_API_KEY_PATH` environment variables.
+  /// When set, the bundler fails before bundling the app if the credentials cannot be resolved.
+  pub notarization: Option<NotarizationConfig>,
+}
+
+/// The credentials used to notarize the macOS app with `notarytool`.
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "camelCase", deny_unknown_fields)]
+pub enum NotarizationConfig {
+  /// Uses the credentials stored in the keychain with `xcrun notarytool store-credentials <profile>`.
+  KeychainProfile {
+    /// The name of the keychain profile.
+    profile: String,
+    /// The path to the keychain containing the profile. Defaults to the login keychain.
+    keychain: Option<PathBuf>,
+  },
+  /// Uses an Apple ID and an app-specific password read from the given environment variables.
+  #[serde(rename_all = "camelCase")]
+  AppleId {
+    /// The environment variable holding the Apple ID. Defaults to `APPLE_ID`.
+    #[serde(default = "default_apple_id_env", alias = "apple-id-env")]
+    apple_id_env: String,
+    /// The environment variable holding the app-specific password. Defaults to `APPLE_PASSWORD`.
+    #[serde(default = "default_apple_password_env", alias = "password-env")]
+    password_env: String,
+    /// The environment variable holding the team ID. Defaults to `APPLE_TEAM_ID`.
+    #[serde(default = "default_apple_team_id_env", alias = "team-id-env")]
+    team_id_env: String,
+  },
+}
+
+fn default_apple_id_env() -> String {
+  "APPLE_ID".into()
+}
+
+fn default_apple_password_env() -> String {
+  "APPLE_PASSWORD".into()
+}
+
+fn default_apple_team_id_env() -> String {
+  "APPLE_TEAM_ID".into()
+}
+
+fn de_exclude_from_signing<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let patterns = Vec::<String>::deserialize(deserializer)?;
+  for pattern in &patterns {
+    let path = Path::new(pattern);
+    if path.has_root()
+      || path
+        .components()
+        .any(|c| c == std::path::Component::ParentDir)
+    {
+      return Err(DeError::custom(format!(
+        "invalid signing exclusion `{pattern}`, expected a path relative to the bundle's Contents directory"
+      )));
+    }
+    glob::Pattern::new(pattern)
+      .map_err(|e| DeError::custom(format!("invalid signing exclusion glob `{pattern}`: {e}")))?;
+  }
+  Ok(patterns)
+}
+
+fn de_info_plist<'de, D>(deserializer: D) -> Result<HashMap<String, JsonValue>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  fn contains_null(value: &JsonValue) -> bool {
+    match value {
+      JsonValue::Null => true,
+      JsonValue::Array(values) => values.iter().any(contains_null),
+      JsonValue::Object(values) => values.values().any(contains_null),
+      _ => false,
+    }
+  }
+
+  let info_plist = HashMap::<String, JsonValue>::deserialize(deserializer)?;
+  for (key, value) in &info_plist {
+    let valid_key = !key.is_empty()
+      && key
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_' | '~'));
+    if !valid_key {
+      return Err(DeError::custom(format!(
+        "invalid Info.plist key `{key}`, expected alphanumeric characters, periods, hyphens, underscores or `~`"
+      )));
+    }
+    if contains_null(value) {
+      return Err(DeError::custom(format!(
+        "invalid value for the Info.plist key `{key}`, `null` cannot be represented in a plist"
+      )));
+    }
+  }
+  Ok(info_plist)
 }
 
 impl Default for MacConfig {
@@ -469,6 +744,10 @@ impl Default for MacConfig {
       signing_identity: None,
       provider_short_name: None,
       entitlements: None,
+      info_plist: HashMap::new(),
+      exclude_from_signing: Vec::new(),
+      sign_external_binaries: true,
+      notarization: None,
     }
   }
 }
@@ -508,6 +787,115 @@ impl Default for WixLanguage {
   }
 }
 
+/// A root key of the Windows registry.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub enum RegistryRoot {
+  /// `HKEY_LOCAL_MACHINE`, shared by all users of the machine.
+  ///
+  /// Writing to this root requires a per-machine installation.
+  #[serde(rename = "HKLM")]
+  LocalMachine,
+  /// `HKEY_CURRENT_USER`, the profile of the user running the installer.
+  #[serde(rename = "HKCU")]
+  CurrentUser,
+  /// `HKEY_CLASSES_ROOT`, file associations and COM registrations.
+  #[serde(rename = "HKCR")]
+  ClassesRoot,
+  /// `HKEY_USERS`, the profiles of all users loaded on the machine.
+  #[serde(rename = "HKU")]
+  Users,
+}
+
+impl RegistryRoot {
+  /// The short name of the root key, as used by the installer tools.
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Self::LocalMachine => "HKLM",
+      Self::CurrentUser => "HKCU",
+      Self::ClassesRoot => "HKCR",
+      Self::Users => "HKU",
+    }
+  }
+}
+
+impl Display for RegistryRoot {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+/// The type of a Windows registry value.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub enum RegistryValueType {
+  /// A string value.
+  #[default]
+  #[serde(rename = "REG_SZ")]
+  String,
+  /// A string value containing unexpanded references to environment variables, e.g. `%PATH%`.
+  #[serde(rename = "REG_EXPAND_SZ")]
+  ExpandString,
+  /// A 32-bit number, written in decimal.
+  #[serde(rename = "REG_DWORD")]
+  Dword,
+  /// Binary data, written as a string of hexadecimal digits, e.g. `DEADBEEF`.
+  #[serde(rename = "REG_BINARY")]
+  Binary,
+}
+
+/// A registry value written by the Windows installer.
+///
+/// The value is removed when the app is uninstalled, along with its key if it is left empty.
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RegistryEntry {
+  /// The root key, one of `HKLM`, `HKCU`, `HKCR` or `HKU`.
+  pub root: RegistryRoot,
+  /// The path of the key under the root, e.g. `Software\\MyCompany\\MyApp`.
+  pub key: String,
+  /// The name of the value. When not set, the default value of the key is written.
+  pub name: Option<String>,
+  /// The data to write. It is written verbatim, without expanding installer variables.
+  pub value: String,
+  /// The type of the value. Defaults to `REG_SZ`.
+  #[serde(default, alias = "value-type")]
+  pub value_type: RegistryValueType,
+}
+
+fn de_registry_keys<'de, D>(deserializer: D) -> Result<Vec<RegistryEntry>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let entries = Vec::<RegistryEntry>::deserialize(deserializer)?;
+  for entry in &entries {
+    let key = &entry.key;
+    if key.is_empty() || key.starts_with('\\') || key.ends_with('\\') {
+      return Err(DeError::custom(format!(
+        "invalid registry key `{key}`, expected a path without leading or trailing backslashes"
+      )));
+    }
+    let value = &entry.value;
+    match entry.value_type {
+      RegistryValueType::Dword if value.parse::<u32>().is_err() => {
+        return Err(DeError::custom(format!(
+          "invalid REG_DWORD value `{value}` for registry key `{key}`, expected a 32-bit unsigned decimal number"
+        )));
+      }
+      RegistryValueType::Binary
+        if value.len() % 2 != 0 || !value.chars().all(|c| c.is_ascii_hexdigit()) =>
+      {
+        return Err(DeError::custom(format!(
+          "invalid REG_BINARY value `{value}` for registry key `{key}`, expected an even number of hexadecimal digits"
+        )));
+      }
+      _ => {}
+    }
+  }
+  Ok(entries)
+}
+
 /// Configuration for the MSI bundle using WiX.
 ///
 /// See more: <https://tauri.app/v1/api/config#wixconfig>
@@ -562,6 +950,25 @@ pub struct WixConfig {
   /// The required dimensions are 493px × 312px.
   #[serde(alias = "dialog-image-path")]
   pub dialog_image_path: Option<PathBuf>,
+  /// Whether to show the checkbox to launch the app on the installer finish dialog or not.
+  ///
+  /// When not set, the installer template default is used, which shows the checkbox checked.
+  /// Set to `false` to hide it.
+  ///
+  /// This does not affect installs triggered by the updater, which relaunches the app itself
+  /// according to the [`WindowsUpdateInstallMode`].
+  #[serde(alias = "launch-on-finish")]
+  pub launch_on_finish: Option<bool>,
+  /// Registry values to write on install.
+  ///
+  /// The values are removed on uninstall, along with their keys if they are left empty.
+  /// The MSI installer is always per-machine, so `HKCU` values are written for the user running it.
+  #[serde(
+    default,
+    alias = "registry-keys",
+    deserialize_with = "de_registry_keys"
+  )]
+  pub registry_keys: Vec<RegistryEntry>,
 }
 
 /// Compression algorithms used in the NSIS installer.
@@ -625,6 +1032,55 @@ pub struct NsisConfig {
   ///
   /// See <https://nsis.sourceforge.io/Reference/SetCompressor>
   pub compression: Option<NsisCompression>,
+  /// Whether to show the checkbox to run the app on the installer finish page or not.
+  ///
+  /// When not set, the installer template default is used, which shows the checkbox checked.
+  /// Set to `false` to hide it.
+  ///
+  /// This does not affect installs triggered by the updater, which skip the finish page
+  /// and relaunch the app according to the [`WindowsUpdateInstallMode`].
+  #[serde(alias = "launch-on-finish")]
+  pub launch_on_finish: Option<bool>,
+  /// Registry values to write on install.
+  ///
+  /// The values are removed on uninstall, along with their keys if they are left empty.
+  ///
+  /// **Note**: `HKLM` values require a per-machine installation,
+  /// see [`NsisConfig::install_mode`]. The bundler warns when they are used with [`NSISInstallerMode::CurrentUser`].
+  #[serde(
+    default,
+    alias = "registry-keys",
+    deserialize_with = "de_registry_keys"
+  )]
+  pub registry_keys: Vec<RegistryEntry>,
+  /// The minimum Windows version the installer runs on, in the `major.minor[.build]` format, e.g. `10.0.17763` for Windows 10 1809.
+  ///
+  /// The installer aborts with an error message on older versions. By default no check is done.
+  ///
+  /// **Note**: this check is advisory and only runs on install,
+  /// the app may still need to check the Windows version at runtime for features it depends on.
+  #[serde(
+    default,
+    alias = "minimum-windows-version",
+    deserialize_with = "de_minimum_windows_version"
+  )]
+  pub minimum_windows_version: Option<String>,
+}
+
+fn de_minimum_windows_version<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let version = Option::<String>::deserialize(deserializer)?;
+  if let Some(version) = &version {
+    let parts = version.split('.').collect::<Vec<_>>();
+    if !(2..=3).contains(&parts.len()) || parts.iter().any(|p| p.parse::<u32>().is_err()) {
+      return Err(DeError::custom(format!(
+        "invalid minimum Windows version `{version}`, expected the `major.minor[.build]` format, e.g. `10.0.17763`"
+      )));
+    }
+  }
+  Ok(version)
 }
 
 /// Install Modes for the NSIS installer.
@@ -729,6 +1185,12 @@ pub struct WindowsConfig {
   /// use a TSP timestamp server, like e.g. SSL.com does. If so, enable TSP by setting to true.
   #[serde(default)]
   pub tsp: bool,
+  /// Whether to sign the external binaries (sidecars) with the app's certificate. Defaults to `true`.
+  ///
+  /// Sidecars that are already signed are not signed again,
+  /// unless the `TAURI_SKIP_SIDECAR_SIGNATURE_CHECK` environment variable is set to `true`.
+  #[serde(default = "default_true", alias = "sign-external-binaries")]
+  pub sign_external_binaries: bool,
   /// The installation mode for the Webview2 runtime.
   #[serde(default, alias = "webview-install-mode")]
   pub webview_install_mode: WebviewInstallMode,
@@ -760,6 +1222,7 @@ impl Default for WindowsConfig {
       certificate_thumbprint: None,
       timestamp_url: None,
       tsp: false,
+      sign_external_binaries: true,
       webview_install_mode: Default::default(),
       webview_fixed_runtime_path: None,
       allow_downgrades: true,
@@ -858,6 +1321,24 @@ pub struct UpdaterConfig {
   /// The Windows configuration for the updater.
   #[serde(default)]
   pub windows: UpdaterWindowsConfig,
+  /// Whether to create the updater artifacts (archives and `.sig` signatures) when bundling or not.
+  ///
+  /// When not set, the artifacts are created if the updater is [`active`](Self::active)
+  /// and the `updater` [bundle target](BundleTarget) is selected, either explicitly or with `"all"`.
+  /// Set to `true` to always create them, even if the `updater` target is not listed,
+  /// or to `false` to skip them even when it is.
+  ///
+  /// This allows shipping an updater-enabled app in every build while only creating
+  /// the artifacts on release builds.
+  #[serde(alias = "create-artifacts")]
+  pub create_artifacts: Option<bool>,
+  /// How the update check requests the endpoints when multiple endpoints are configured.
+  /// Defaults to [`EndpointStrategy::Sequential`].
+  ///
+  /// Every endpoint must serve the same update manifest, the strategy only changes which endpoints are requested
+  /// and in what order.
+  #[serde(default, alias = "endpoint-strategy")]
+  pub endpoint_strategy: EndpointStrategy,
 }
 
 impl<'de> Deserialize<'de> for UpdaterConfig {
@@ -872,11 +1353,16 @@ impl<'de> Deserialize<'de> for UpdaterConfig {
       pubkey: Option<String>,
       #[serde(default)]
       windows: UpdaterWindowsConfig,
+      #[serde(rename = "createArtifacts", alias = "create-artifacts")]
+      create_artifacts: Option<bool>,
+      #[serde(default, rename = "endpointStrategy", alias = "endpoint-strategy")]
+      endpoint_strategy: EndpointStrategy,
     }
 
     let config = InnerUpdaterConfig::deserialize(deserializer)?;
 
-    if config.active && config.pubkey.is_none() {
+    // the artifacts are signed, so creating them also requires the public key
+    if (config.active || config.create_artifacts == Some(true)) && config.pubkey.is_none() {
       return Err(DeError::custom(
         "The updater `pubkey` configuration is required.",
       ));
@@ -886,6 +1372,8 @@ impl<'de> Deserialize<'de> for UpdaterConfig {
       active: config.active,
       pubkey: config.pubkey.unwrap_or_default(),
       windows: config.windows,
+      create_artifacts: config.create_artifacts,
+      endpoint_strategy: config.endpoint_strategy,
     })
   }
 }
@@ -896,10 +1384,32 @@ impl Default for UpdaterConfig {
       active: false,
       pubkey: "".into(),
       windows: Default::default(),
+      create_artifacts: None,
+      endpoint_strategy: Default::default(),
     }
   }
 }
 
+/// How the updater picks an endpoint when multiple endpoints are configured.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub enum EndpointStrategy {
+  /// Requests the endpoints one after the other in the configured order,
+  /// moving on to the next one when a request fails.
+  #[default]
+  Sequential,
+  /// Requests a single endpoint picked at random for each update check, spreading the load across all of them.
+  ///
+  /// The update check fails when the picked endpoint fails, it does not try the other endpoints.
+  Random,
+  /// Requests all the endpoints at once and uses the first successful response, cancelling the other requests.
+  ///
+  /// The requests can only run concurrently on the async updater path, where the update check is awaited.
+  /// A blocking update check requests the endpoints sequentially instead.
+  Parallel,
+}
+
 /// Definition for bundle resources.
 /// Can be either a list of paths to include or a map of source to target paths.
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
@@ -951,6 +1461,15 @@ pub struct BundleConfig {
   /// The app's icons
   #[serde(default)]
   pub icon: Vec<String>,
+  /// The app's icons for specific platforms, e.g. a squared icon on Windows and a rounded one on macOS.
+  ///
+  /// The entry of the target platform replaces [`icon`](#BundleConfig.icon) entirely;
+  /// platforms without an entry fall back to [`icon`](#BundleConfig.icon).
+  ///
+  /// The icon formats are checked per platform: `.ico` and `.png` on Windows, `.icns` and `.png` on macOS
+  /// and `.png` on Linux, Android and iOS. The files of the target platform must exist at build time.
+  #[serde(default, alias = "platform-icons")]
+  pub platform_icons: HashMap<Target, Vec<String>>,
   /// App resources to bundle.
   /// Each resource is a path to a file or directory.
   /// Glob patterns are supported.
@@ -970,6 +1489,12 @@ pub struct BundleConfig {
   /// A longer, multi-line description of the application.
   #[serde(alias = "long-description")]
   pub long_description: Option<String>,
+  /// The path to the license file shared by all installers that display a license.
+  ///
+  /// Used by the WiX, NSIS and DMG bundles when their own `license` configuration is not set,
+  /// which takes precedence over this value.
+  #[serde(alias = "license-file")]
+  pub license_file: Option<PathBuf>,
   /// Configuration for the AppImage bundle.
   #[serde(default)]
   pub appimage: AppImageConfig,
@@ -1012,6 +1537,43 @@ pub struct BundleConfig {
   pub updater: UpdaterConfig,
 }
 
+impl BundleConfig {
+  /// The bundle identifier for the given target,
+  /// taking the [Android](AndroidConfig::package_name) and [iOS](IosConfig::bundle_identifier) overrides into account.
+  pub fn identifier_for(&self, target: Target) -> &str {
+    match target {
+      Target::Android => self.android.package_name.as_deref(),
+      Target::Ios => self.ios.bundle_identifier.as_deref(),
+      _ => None,
+    }
+    .unwrap_or(&self.identifier)
+  }
+
+  /// The icons for the given target, taking the [`Self::platform_icons`] overrides into account.
+  pub fn icons_for(&self, target: Target) -> &[String] {
+    self.platform_icons.get(&target).unwrap_or(&self.icon)
+  }
+
+  /// The manufacturer the Windows installers register the app under,
+  /// the [publisher](Self::publisher) or the second element of the identifier.
+  pub fn manufacturer(&self) -> &str {
+    self
+      .publisher
+      .as_deref()
+      .or_else(|| self.identifier.split('.').nth(1))
+      .unwrap_or(&self.identifier)
+  }
+}
+
+/// The icon file extensions supported on the given target.
+fn supported_icon_extensions(target: Target) -> &'static [&'static str] {
+  match target {
+    Target::Windows => &["ico", "png"],
+    Target::MacOS => &["icns", "png"],
+    Target::Linux | Target::Android | Target::Ios => &["png"],
+  }
+}
+
 /// a tuple struct of RGBA colors. Each value has minimum of 0 and maximum of 255.
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, Default)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -1032,6 +1594,9 @@ impl From<Color> for (u8, u8, u8, u8) {
 pub struct WindowEffectsConfig {
   /// List of Window effects to apply to the Window.
   /// Conflicting effects will apply the first one and ignore the rest.
+  ///
+  /// On Windows, an effect that is not supported by the running version falls back to the best available one:
+  /// `Tabbed` (Windows 11 build 22523+) → `Mica` (Windows 11) → `Acrylic` (Windows 10 v1809+) → none.
   pub effects: Vec<WindowEffect>,
   /// Window effect state **macOS Only**
   pub state: Option<WindowEffectState>,
@@ -1042,6 +1607,83 @@ pub struct WindowEffectsConfig {
   pub color: Option<Color>,
 }
 
+/// When the splash window of a [`WindowConfig`] is closed.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub enum CloseTrigger {
+  /// Closes the splash window when the window it covers finishes loading its page.
+  #[default]
+  PageLoad,
+  /// Closes the splash window when the given event is emitted, e.g. by the frontend once it is ready.
+  Event(String),
+  /// Closes the splash window once [`SplashConfig::min_display_ms`] has elapsed.
+  Timeout,
+}
+
+/// When a hidden window of a [`WindowConfig`] is shown, see [`WindowConfig::show_when`].
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub enum ShowTrigger {
+  /// Shows the window when it finishes loading its page.
+  PageLoad,
+  /// Shows the window when the given event is emitted, e.g. by the frontend once it is ready.
+  Event(String),
+}
+
+/// The configuration of a splash window shown while a window loads.
+///
+/// The splash window is only created on startup, as a borderless and centered window labeled `<label>-splash`.
+/// The window it covers is created hidden and shown when the splash window closes,
+/// so users never see the page of the window before it is ready.
+/// A window configured with `visible: false` is left hidden for the app to show.
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SplashConfig {
+  /// The URL to load in the splash window.
+  pub url: WebviewUrl,
+  /// The minimum time in milliseconds the splash window is displayed.
+  ///
+  /// Keeps the splash window from flickering when the close trigger fires right after startup.
+  #[serde(default, alias = "min-display-ms")]
+  pub min_display_ms: u64,
+  /// When the splash window is closed. Defaults to [`CloseTrigger::PageLoad`].
+  #[serde(default, alias = "close-on")]
+  pub close_on: CloseTrigger,
+}
+
+/// The monitor a window of a [`WindowConfig`] is opened on, see [`WindowConfig::monitor`].
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub enum MonitorSelector {
+  /// The monitor at the given index of the available monitors, starting at `0`.
+  Index(u32),
+  /// The monitor with the given name.
+  Name(String),
+  /// The primary monitor.
+  Primary,
+  /// The monitor containing the mouse cursor.
+  Cursor,
+}
+
+impl Display for MonitorSelector {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Index(index) => write!(f, "monitor #{index}"),
+      Self::Name(name) => write!(f, "monitor `{name}`"),
+      Self::Primary => write!(f, "primary monitor"),
+      Self::Cursor => write!(f, "monitor under the cursor"),
+    }
+  }
+}
+
+/// A `(width, height)` size constraint, see [`WindowConfig::inner_size_constraints`].
+type SizeConstraint = Option<(f64, f64)>;
+
 /// The window configuration object.
 ///
 /// See more: <https://tauri.app/v1/api/config#windowconfig>
@@ -1056,9 +1698,47 @@ pub struct WindowConfig {
   /// The window webview URL.
   #[serde(default)]
   pub url: WebviewUrl,
-  /// The user agent for the webview
+  /// A query string appended to the [`url`](Self::url), without the leading `?`.
+  ///
+  /// This keeps the asset path of [`WebviewUrl::App`] URLs clean while still passing parameters to the page.
+  /// It applies to [`WebviewUrl::External`] URLs as well, where it is appended to any existing query.
+  /// The value is used as is, so it must already be URL-encoded.
+  #[serde(alias = "url-query")]
+  pub url_query: Option<String>,
+  /// A fragment set on the [`url`](Self::url), without the leading `#`, replacing any existing one.
+  ///
+  /// Applies to both [`WebviewUrl::App`] and [`WebviewUrl::External`] URLs. The value must already be URL-encoded.
+  #[serde(alias = "url-fragment")]
+  pub url_fragment: Option<String>,
+  /// The user agent for the webview.
+  ///
+  /// The value is a template rendered when the webview is created, at runtime, supporting the placeholders:
+  ///
+  /// - `{{app_name}}`: the app name, see [`PackageConfig::product_name`].
+  /// - `{{app_version}}`: the app version, see [`PackageConfig::version`].
+  /// - `{{os}}`: the operating system, e.g. `linux`, `macos` or `windows`.
+  /// - `{{os_version}}`: the operating system version, e.g. `14.2.1` on macOS, `10.0.22631` on Windows
+  ///   and the kernel release on Linux and Android. Empty if it cannot be read.
+  ///
+  /// Unknown placeholders are left as is, with a warning on debug builds.
+  /// For example, `MyApp/{{app_version}}` resolves to `MyApp/1.0.0` for version `1.0.0`.
   #[serde(alias = "user-agent")]
   pub user_agent: Option<String>,
+  /// A JavaScript script executed on this window's webview before any page script,
+  /// on every page load and navigation, including external URLs.
+  ///
+  /// It runs after the scripts injected by Tauri, so it can use `window.__TAURI_INTERNALS__`
+  /// and, when [`withGlobalTauri`](BuildConfig::with_global_tauri) is enabled, `window.__TAURI__`.
+  /// Useful to inject API shims or polyfills in a single window.
+  #[serde(alias = "initialization-script")]
+  pub initialization_script: Option<String>,
+  /// Whether the Tauri API is injected on `window.__TAURI__` in this window's webview,
+  /// overriding [`withGlobalTauri`](BuildConfig::with_global_tauri) for this window. Inherits it when not set.
+  ///
+  /// Set it to `false` on windows loading remote content, so remote origins don't get easy access to the API.
+  /// Note that it only controls the `window.__TAURI__` global, the IPC is still restricted by the capabilities.
+  #[serde(alias = "with-global-tauri")]
+  pub with_global_tauri: Option<bool>,
   /// Whether the file drop is enabled or not on the webview. By default it is enabled.
   ///
   /// Disabling it is required to use drag and drop on the frontend on Windows.
@@ -1071,36 +1751,67 @@ pub struct WindowConfig {
   pub x: Option<f64>,
   /// The vertical position of the window's top left corner
   pub y: Option<f64>,
+  /// The monitor to open the window on. By default the system picks the monitor.
+  ///
+  /// When set, [`x`](Self::x) and [`y`](Self::y) are relative to the top left corner of the selected monitor,
+  /// defaulting to that corner, and [`center`](Self::center) centers the window on the selected monitor.
+  /// The window opens on the primary monitor, with a warning, when the selected monitor is not connected.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Android / iOS**: Unsupported.
+  pub monitor: Option<MonitorSelector>,
   /// The window width.
   #[serde(default = "default_width")]
   pub width: f64,
   /// The window height.
   #[serde(default = "default_height")]
   pub height: f64,
-  /// The min window width.
+  /// The min width of the window content, excluding the window decorations.
+  ///
+  /// The size constraints apply to the inner size of the window, like [`width`](Self::width) and [`height`](Self::height),
+  /// so the title bar and borders are added on top of them on every platform.
+  /// Each constraint applies on its own, e.g. a min width without a min height only constrains the width.
   #[serde(alias = "min-width")]
   pub min_width: Option<f64>,
-  /// The min window height.
+  /// The min height of the window content, excluding the window decorations.
   #[serde(alias = "min-height")]
   pub min_height: Option<f64>,
-  /// The max window width.
+  /// The max width of the window content, excluding the window decorations.
   #[serde(alias = "max-width")]
   pub max_width: Option<f64>,
-  /// The max window height.
+  /// The max height of the window content, excluding the window decorations.
   #[serde(alias = "max-height")]
   pub max_height: Option<f64>,
   /// Whether the window is resizable or not. When resizable is set to false, native window's maximize button is automatically disabled.
+  ///
+  /// Use [`resizable_horizontal`](Self::resizable_horizontal) and [`resizable_vertical`](Self::resizable_vertical)
+  /// to only allow resizing the window along one axis.
   #[serde(default = "default_true")]
   pub resizable: bool,
-  /// Whether the window's native maximize button is enabled or not.
+  /// Whether the window width can be changed by the user. Defaults to the [`resizable`](Self::resizable) value.
+  ///
+  /// When only one axis is resizable, the other one is locked to its initial size with the min and max size constraints.
+  /// Setting the same min and max size on an axis also locks it.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Android / iOS:** Unsupported.
+  #[serde(alias = "resizable-horizontal")]
+  pub resizable_horizontal: Option<bool>,
+  /// Whether the window height can be changed by the user. Defaults to the [`resizable`](Self::resizable) value.
+  ///
+  /// See [`resizable_horizontal`](Self::resizable_horizontal) for more information.
+  #[serde(alias = "resizable-vertical")]
+  pub resizable_vertical: Option<bool>,
+  /// Whether the window's native maximize button is enabled or not. Defaults to `true`.
   /// If resizable is set to false, this setting is ignored.
   ///
   /// ## Platform-specific
   ///
   /// - **macOS:** Disables the "zoom" button in the window titlebar, which is also used to enter fullscreen mode.
   /// - **Linux / iOS / Android:** Unsupported.
-  #[serde(default = "default_true")]
-  pub maximizable: bool,
+  pub maximizable: Option<bool>,
   /// Whether the window's native minimize button is enabled or not.
   ///
   /// ## Platform-specific
@@ -1121,6 +1832,8 @@ pub struct WindowConfig {
   #[serde(default = "default_title")]
   pub title: String,
   /// Whether the window starts as fullscreen or not.
+  ///
+  /// Takes precedence over `maximized`. The window position and size only apply once it leaves fullscreen.
   #[serde(default)]
   pub fullscreen: bool,
   /// Whether the window will be initially focused or not.
@@ -1129,10 +1842,11 @@ pub struct WindowConfig {
   /// Whether the window is transparent or not.
   ///
   /// Note that on `macOS` this requires the `macos-private-api` feature flag, enabled under `tauri > macOSPrivateApi`.
+  /// Configurations targeting macOS that set it without enabling `macOSPrivateApi` fail to validate.
   /// WARNING: Using private APIs on `macOS` prevents your application from being accepted to the `App Store`.
   #[serde(default)]
   pub transparent: bool,
-  /// Whether the window is maximized or not.
+  /// Whether the window is maximized or not. Ignored when the window starts as `fullscreen`.
   #[serde(default)]
   pub maximized: bool,
   /// Whether the window is visible or not.
@@ -1148,15 +1862,22 @@ pub struct WindowConfig {
   #[serde(default, alias = "always-on-top")]
   pub always_on_top: bool,
   /// Whether the window should be visible on all workspaces or virtual desktops.
+  ///
+  /// Accepts a boolean or an object with macOS-specific options, see [`VisibleOnAllWorkspaces`].
   #[serde(default, alias = "all-workspaces")]
-  pub visible_on_all_workspaces: bool,
+  pub visible_on_all_workspaces: VisibleOnAllWorkspaces,
   /// Prevents the window contents from being captured by other apps.
   #[serde(default, alias = "content-protected")]
   pub content_protected: bool,
   /// If `true`, hides the window icon from the taskbar on Windows and Linux.
   #[serde(default, alias = "skip-taskbar")]
   pub skip_taskbar: bool,
-  /// The initial window theme. Defaults to the system theme. Only implemented on Windows and macOS 10.14+.
+  /// The initial window theme. Defaults to [`TauriConfig::default_theme`], then the system theme.
+  ///
+  /// `"light"` and `"dark"` pin the window theme, ignoring system theme changes,
+  /// while `"system"` (same as omitting the option) follows the system theme.
+  ///
+  /// Only implemented on Windows and macOS 10.14+.
   pub theme: Option<crate::Theme>,
   /// The style of the macOS title bar.
   #[serde(default, alias = "title-bar-style")]
@@ -1164,7 +1885,22 @@ pub struct WindowConfig {
   /// If `true`, sets the window title to be hidden on macOS.
   #[serde(default, alias = "hidden-title")]
   pub hidden_title: bool,
+  /// The height of the macOS title bar in logical pixels, with the traffic lights vertically centered in it.
+  ///
+  /// Useful to align the window controls with custom title bar content,
+  /// requires the [`title_bar_style`](Self::title_bar_style) to be `Overlay` or `Transparent`.
+  /// Defaults to `None`, which keeps the native title bar height.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Linux / Windows / Android / iOS:** Ignored.
+  #[serde(default, alias = "titlebar-height")]
+  pub titlebar_height: Option<f64>,
   /// Whether clicking an inactive window also clicks through to the webview on macOS.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Windows / Linux / iOS / Android:** Unsupported, the setting is ignored.
   #[serde(default, alias = "accept-first-mouse")]
   pub accept_first_mouse: bool,
   /// Defines the window [tabbing identifier] for macOS.
@@ -1175,6 +1911,19 @@ pub struct WindowConfig {
   /// [tabbing identifier]: <https://developer.apple.com/documentation/appkit/nswindow/1644704-tabbingidentifier>
   #[serde(default, alias = "tabbing-identifier")]
   pub tabbing_identifier: Option<String>,
+  /// Whether the window opens as a tab of the windows with the same [`tabbing_identifier`](Self::tabbing_identifier) on macOS.
+  ///
+  /// - `true`: the window always opens as a new tab, like with the "Prefer tabs: Always" system setting.
+  /// - `false`: the window never opens as a tab and cannot be merged with other windows.
+  /// - not set: the system setting decides.
+  ///
+  /// Ignored when the tabbing identifier is not set, since automatic tabbing is then disabled.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Windows / Linux / iOS / Android:** Unsupported, the setting is ignored.
+  #[serde(alias = "auto-tabbing")]
+  pub auto_tabbing: Option<bool>,
   /// Defines additional browser arguments on Windows. By default wry passes `--disable-features=msWebOOUI,msPdfOOUI,msSmartScreenProtection`
   /// so if you use this method, you also need to disable these components by yourself if you want.
   #[serde(default, alias = "additional-browser-args")]
@@ -1187,9 +1936,22 @@ pub struct WindowConfig {
   ///   - `false` has no effect on decorated window, shadow are always ON.
   ///   - `true` will make ndecorated window have a 1px white border,
   /// and on Windows 11, it will have a rounded corners.
-  /// - **Linux:** Unsupported.
+  /// - **Linux:** Unsupported, see [`client_side_decorations`](Self::client_side_decorations).
   #[serde(default = "default_true")]
   pub shadow: bool,
+  /// Whether the undecorated window reserves a transparent margin around its content
+  /// that can be styled with CSS, e.g. to draw a shadow or rounded corners.
+  ///
+  /// The window and the webview are made transparent and the margin is exposed as the
+  /// `--tauri-client-side-decorations-margin` CSS variable, applied as the document padding.
+  /// The margin is also used as the resize region of the window.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Linux:** Only applies to undecorated windows.
+  /// - **Windows / macOS / Android / iOS:** Unsupported, the native window shadow is used instead.
+  #[serde(default, alias = "client-side-decorations")]
+  pub client_side_decorations: bool,
   /// Window effects.
   ///
   /// Requires the window to be transparent.
@@ -1207,26 +1969,401 @@ pub struct WindowConfig {
   ///  - **Android**: Unsupported.
   #[serde(default)]
   pub incognito: bool,
-}
-
-impl Default for WindowConfig {
-  fn default() -> Self {
-    Self {
-      label: default_window_label(),
-      url: WebviewUrl::default(),
-      user_agent: None,
-      file_drop_enabled: true,
-      center: false,
-      x: None,
-      y: None,
-      width: default_width(),
-      height: default_height(),
-      min_width: None,
-      min_height: None,
-      max_width: None,
-      max_height: None,
-      resizable: true,
-      maximizable: true,
+  /// Whether the default webview context menu is shown on right click. Defaults to `true`.
+  ///
+  /// The menu is disabled by cancelling the `contextmenu` DOM event, which is supported on all platforms.
+  /// Custom context menus implemented by the page keep working.
+  ///
+  /// Disabling the menu does not disable the devtools, which can still be opened with the keyboard shortcut
+  /// or `Webview::open_devtools` when they are enabled.
+  #[serde(default = "default_true", alias = "enable-context-menu")]
+  pub enable_context_menu: bool,
+  /// Whether the devtools (Web Inspector) are opened when the window is created. Defaults to `false`.
+  ///
+  /// This does not enable the devtools, it only opens them when they are enabled.
+  /// They are enabled for every webview when compiled in, which is the case on debug builds
+  /// or with the `devtools` Cargo feature. Otherwise the setting is ignored and a message is printed to stderr.
+  /// A per-window setting disabling the devtools would take precedence over this one.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Android / iOS**: Unsupported.
+  #[serde(default, alias = "open-devtools")]
+  pub open_devtools: bool,
+  /// Whether the window size, position and maximized state are saved when it is moved, resized or closed and restored on the next launch.
+  ///
+  /// The state is stored in the `.window-state.json` file of the app config directory, keyed by the window label.
+  /// The window is centered when the saved position is off-screen, e.g. when its monitor was disconnected,
+  /// and [`center`](Self::center) takes precedence over the saved position.
+  ///
+  /// Only applies to the windows created from the configuration.
+  ///
+  /// Regardless of this setting, `tauri dev` keeps the size and position of these windows when it restarts the app
+  /// after a Rust code change, unless its `--no-restore-geometry` flag is set.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Android / iOS**: Unsupported.
+  #[serde(default, alias = "remember-state")]
+  pub remember_state: bool,
+  /// Whether the zoom level of the webview is saved when it changes and restored on the next launch.
+  ///
+  /// The zoom level is stored in the `.webview-zoom.json` file of the app config directory, keyed by the window label.
+  /// Only the changes made with `Webview::set_zoom` are saved, and the restored zoom level is clamped between `0.25` and `5`.
+  ///
+  /// Only applies to the windows created from the configuration.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Android**: Unsupported.
+  #[serde(default, alias = "remember-zoom")]
+  pub remember_zoom: bool,
+  /// A splash window shown on startup until this window is ready.
+  ///
+  /// Only applies to the windows created from the configuration.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Android / iOS**: Unsupported.
+  pub splash: Option<SplashConfig>,
+  /// Shows the window automatically when the trigger fires, avoiding a flash of unstyled content.
+  ///
+  /// Ignored when the window is [`visible`](Self::visible) or has a [`splash`](Self::splash) window.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Android / iOS**: Unsupported.
+  #[serde(alias = "show-when")]
+  pub show_when: Option<ShowTrigger>,
+  /// The URLs the webview is allowed to navigate to, as glob patterns.
+  ///
+  /// A pattern without a path, e.g. `https://*.tauri.app`, is matched against the origin of the URL.
+  /// A pattern with a path, e.g. `https://tauri.app/docs/*`, must also match the path of the URL; the query and fragment are ignored.
+  /// `*` never matches a `/`, use `**` to match any number of path segments.
+  /// Navigations to URLs matching no pattern are blocked and logged.
+  /// The app URL, i.e. the dev server or the bundled assets, is always allowed.
+  /// An empty or missing list allows every navigation.
+  ///
+  /// The `on_navigation` handlers of the webview builder and of the plugins still run for the allowed URLs,
+  /// so they can restrict the navigation further.
+  #[serde(alias = "navigation-allowlist")]
+  pub navigation_allowlist: Option<Vec<String>>,
+}
+
+impl WindowConfig {
+  /// The fields that can be changed at runtime after the window is created, using the [`WindowConfig`] field names.
+  ///
+  /// The remaining fields are only applied when creating the window.
+  pub const RUNTIME_MUTABLE_FIELDS: &'static [&'static str] = &[
+    "center",
+    "x",
+    "y",
+    "width",
+    "height",
+    "min_width",
+    "min_height",
+    "max_width",
+    "max_height",
+    "resizable",
+    "maximizable",
+    "minimizable",
+    "closable",
+    "title",
+    "fullscreen",
+    "focus",
+    "maximized",
+    "visible",
+    "decorations",
+    "always_on_bottom",
+    "always_on_top",
+    "visible_on_all_workspaces",
+    "content_protected",
+    "skip_taskbar",
+    "shadow",
+    "window_effects",
+  ];
+
+  /// Returns the fields that can be changed at runtime after the window is created.
+  ///
+  /// See [`Self::RUNTIME_MUTABLE_FIELDS`].
+  pub fn runtime_mutable_fields() -> &'static [&'static str] {
+    Self::RUNTIME_MUTABLE_FIELDS
+  }
+
+  /// Whether the window can be resized horizontally and vertically, taking the per-axis overrides into account.
+  pub fn resizable_axes(&self) -> (bool, bool) {
+    (
+      self.resizable_horizontal.unwrap_or(self.resizable),
+      self.resizable_vertical.unwrap_or(self.resizable),
+    )
+  }
+
+  /// The min and max inner size constraints of the window, as `(width, height)` tuples.
+  ///
+  /// Axes that are not [resizable](Self::resizable_axes) while the other one is
+  /// are locked to the initial [`width`](Self::width) or [`height`](Self::height).
+  ///
+  /// The runtime only sets the min and max sizes on both axes at once, so missing values on a constrained axis
+  /// are filled with `0` for the min size and [`f64::MAX`] for the max size, which leave that axis unconstrained.
+  pub fn inner_size_constraints(&self) -> (SizeConstraint, SizeConstraint) {
+    let (horizontal, vertical) = self.resizable_axes();
+    let lock_width = vertical && !horizontal;
+    let lock_height = horizontal && !vertical;
+
+    let (min_width, max_width) = if lock_width {
+      (Some(self.width), Some(self.width))
+    } else {
+      (self.min_width, self.max_width)
+    };
+    let (min_height, max_height) = if lock_height {
+      (Some(self.height), Some(self.height))
+    } else {
+      (self.min_height, self.max_height)
+    };
+
+    let min = (min_width.is_some() || min_height.is_some())
+      .then(|| (min_width.unwrap_or(0.), min_height.unwrap_or(0.)));
+    let max = (max_width.is_some() || max_height.is_some()).then(|| {
+      (
+        max_width.unwrap_or(f64::MAX),
+        max_height.unwrap_or(f64::MAX),
+      )
+    });
+    (min, max)
+  }
+
+  /// Whether the webview of the window may navigate to the URL according to [`Self::navigation_allowlist`].
+  pub fn is_navigation_allowed(&self, url: &Url) -> bool {
+    self
+      .navigation_allowlist
+      .as_deref()
+      .map_or(true, |allowlist| is_navigation_allowed(allowlist, url))
+  }
+
+  /// Validates the window configuration for the given target.
+  ///
+  /// The [`ConfigDiagnostic::field_path`] of the returned diagnostics is relative to the window object.
+  pub fn validate(&self, target: Target) -> Vec<ConfigDiagnostic> {
+    let mut diagnostics = Vec::new();
+    let desktop = matches!(target, Target::MacOS | Target::Windows | Target::Linux);
+
+    if desktop && self.maximizable == Some(true) && !self.resizable {
+      diagnostics.push(ConfigDiagnostic::warning(
+        "maximizable",
+        format!(
+          "window `{}` sets `maximizable` to `true` but it is not resizable, so its maximize button is disabled; remove `maximizable` or set it to `false`",
+          self.label
+        ),
+      ));
+    }
+
+    if !desktop && !self.closable {
+      diagnostics.push(ConfigDiagnostic::warning(
+        "closable",
+        format!(
+          "window `{}` sets `closable` to `false` but this is not supported on {target}, so the setting is ignored",
+          self.label
+        ),
+      ));
+    }
+
+    if self.accept_first_mouse && target != Target::MacOS {
+      diagnostics.push(ConfigDiagnostic::info(
+        "acceptFirstMouse",
+        format!(
+          "window `{}` sets `acceptFirstMouse` but it is only supported on macOS, so the setting is ignored on {target}",
+          self.label
+        ),
+      ));
+    }
+
+    if let Some(titlebar_height) = self.titlebar_height {
+      if !titlebar_height.is_finite() || titlebar_height <= 0. {
+        diagnostics.push(ConfigDiagnostic::error(
+          "titlebarHeight",
+          format!(
+            "window `{}` sets `titlebarHeight` to {titlebar_height} but it must be a positive number",
+            self.label
+          ),
+        ));
+      } else if target != Target::MacOS {
+        diagnostics.push(ConfigDiagnostic::info(
+          "titlebarHeight",
+          format!(
+            "window `{}` sets `titlebarHeight` but it is only supported on macOS, so the setting is ignored on {target}",
+            self.label
+          ),
+        ));
+      } else if self.title_bar_style == TitleBarStyle::Visible {
+        diagnostics.push(ConfigDiagnostic::warning(
+          "titlebarHeight",
+          format!(
+            "window `{}` sets `titlebarHeight` but its `titleBarStyle` is `Visible`, so the setting is ignored; use the `Overlay` or `Transparent` style",
+            self.label
+          ),
+        ));
+      }
+    }
+
+    if self.fullscreen {
+      if self.maximized {
+        diagnostics.push(ConfigDiagnostic::warning(
+          "maximized",
+          format!(
+            "window `{}` sets both `maximized` and `fullscreen` to `true`, so it starts in fullscreen and is not maximized",
+            self.label
+          ),
+        ));
+      }
+
+      let geometry = [
+        ("x", self.x.is_some()),
+        ("y", self.y.is_some()),
+        ("width", self.width != default_width()),
+        ("height", self.height != default_height()),
+      ]
+      .into_iter()
+      .filter(|(_, set)| *set)
+      .map(|(field, _)| format!("`{field}`"))
+      .collect::<Vec<_>>();
+      if !geometry.is_empty() {
+        diagnostics.push(ConfigDiagnostic::warning(
+          "fullscreen",
+          format!(
+            "window `{}` starts in fullscreen, so the geometry set by {} is ignored until it leaves fullscreen",
+            self.label,
+            geometry.join(", ")
+          ),
+        ));
+      }
+    }
+
+    if let Some(window_effects) = &self.window_effects {
+      // the effects not supported even by the latest version of the target
+      let latest = (u32::MAX, u32::MAX, u32::MAX);
+      for effect in &window_effects.effects {
+        if !effect.supported_on(target, latest) {
+          diagnostics.push(ConfigDiagnostic::info(
+            "windowEffects",
+            format!(
+              "window `{}` uses the `{effect:?}` effect but it is not supported on {target}, so it is ignored",
+              self.label
+            ),
+          ));
+        }
+      }
+    }
+
+    for pattern in self.navigation_allowlist.iter().flatten() {
+      let (origin_pattern, path_pattern) = split_navigation_pattern(pattern);
+      if let Err(e) = glob::Pattern::new(origin_pattern)
+        .and_then(|_| path_pattern.map_or(Ok(()), |p| glob::Pattern::new(p).map(|_| ())))
+      {
+        diagnostics.push(ConfigDiagnostic::error(
+          "navigationAllowlist",
+          format!(
+            "window `{}` has the invalid navigation allowlist pattern `{pattern}`: {e}",
+            self.label
+          ),
+        ));
+      }
+    }
+
+    if let Some(ShowTrigger::Event(event)) = &self.show_when {
+      if !event
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '-' || c == '/' || c == ':' || c == '_')
+      {
+        diagnostics.push(ConfigDiagnostic::error(
+          "showWhen",
+          format!(
+            "window `{}` is shown on the event `{event}` but event names must include only alphanumeric characters, `-`, `/`, `:` and `_`",
+            self.label
+          ),
+        ));
+      }
+    }
+
+    if self.window_effects.is_some() && !self.transparent {
+      diagnostics.push(ConfigDiagnostic::warning(
+        "windowEffects",
+        format!(
+          "window `{}` sets `windowEffects` but it is not transparent, so the effects are not visible",
+          self.label
+        ),
+      ));
+    }
+
+    diagnostics
+  }
+}
+
+/// Whether the URL matches one of the glob patterns of a [`WindowConfig::navigation_allowlist`].
+///
+/// An empty allowlist allows every URL. Patterns that do not compile never match.
+pub fn is_navigation_allowed(allowlist: &[String], url: &Url) -> bool {
+  if allowlist.is_empty() {
+    return true;
+  }
+  let origin = url.origin().ascii_serialization();
+  allowlist.iter().any(|pattern| {
+    let (origin_pattern, path_pattern) = split_navigation_pattern(pattern);
+    let origin_matches = glob::Pattern::new(origin_pattern)
+      .map(|p| p.matches_with(&origin, NAVIGATION_MATCH_OPTIONS))
+      .unwrap_or(false);
+    origin_matches
+      && path_pattern.map_or(true, |path_pattern| {
+        glob::Pattern::new(path_pattern)
+          .map(|p| p.matches_with(url.path(), NAVIGATION_MATCH_OPTIONS))
+          .unwrap_or(false)
+      })
+  })
+}
+
+/// `*` must not match a `/` so a wildcard cannot reach from the host into the path.
+const NAVIGATION_MATCH_OPTIONS: glob::MatchOptions = glob::MatchOptions {
+  case_sensitive: true,
+  require_literal_separator: true,
+  require_literal_leading_dot: false,
+};
+
+/// Splits a navigation allowlist pattern into its origin pattern and its optional path pattern.
+fn split_navigation_pattern(pattern: &str) -> (&str, Option<&str>) {
+  let authority_start = pattern.find("://").map_or(0, |i| i + 3);
+  match pattern[authority_start..].find('/') {
+    Some(i) => {
+      let (origin, path) = pattern.split_at(authority_start + i);
+      (origin, Some(path))
+    }
+    None => (pattern, None),
+  }
+}
+
+impl Default for WindowConfig {
+  fn default() -> Self {
+    Self {
+      label: default_window_label(),
+      url: WebviewUrl::default(),
+      url_query: None,
+      url_fragment: None,
+      user_agent: None,
+      initialization_script: None,
+      with_global_tauri: None,
+      file_drop_enabled: true,
+      center: false,
+      x: None,
+      y: None,
+      monitor: None,
+      width: default_width(),
+      height: default_height(),
+      min_width: None,
+      min_height: None,
+      max_width: None,
+      max_height: None,
+      resizable: true,
+      resizable_horizontal: None,
+      resizable_vertical: None,
+      maximizable: None,
       minimizable: true,
       closable: true,
       title: default_title(),
@@ -1238,18 +2375,28 @@ impl Default for WindowConfig {
       decorations: true,
       always_on_bottom: false,
       always_on_top: false,
-      visible_on_all_workspaces: false,
+      visible_on_all_workspaces: Default::default(),
       content_protected: false,
       skip_taskbar: false,
       theme: None,
       title_bar_style: Default::default(),
       hidden_title: false,
+      titlebar_height: None,
       accept_first_mouse: false,
       tabbing_identifier: None,
+      auto_tabbing: None,
       additional_browser_args: None,
       shadow: true,
+      client_side_decorations: false,
       window_effects: None,
       incognito: false,
+      enable_context_menu: true,
+      open_devtools: false,
+      remember_state: false,
+      remember_zoom: false,
+      splash: None,
+      show_when: None,
+      navigation_allowlist: None,
     }
   }
 }
@@ -1290,18 +2437,31 @@ impl Default for CspDirectiveSources {
 
 impl From<CspDirectiveSources> for Vec<String> {
   fn from(sources: CspDirectiveSources) -> Self {
-    match sources {
-      CspDirectiveSources::Inline(source) => source.split(' ').map(|s| s.to_string()).collect(),
-      CspDirectiveSources::List(l) => l,
-    }
+    sources.normalized()
   }
 }
 
 impl CspDirectiveSources {
+  /// Returns the list of sources of this directive,
+  /// split on whitespace, without empty entries and duplicates, in their original order.
+  pub fn normalized(&self) -> Vec<String> {
+    let tokens: Box<dyn Iterator<Item = &str>> = match self {
+      Self::Inline(s) => Box::new(s.split_whitespace()),
+      Self::List(l) => Box::new(l.iter().flat_map(|s| s.split_whitespace())),
+    };
+    let mut sources: Vec<String> = Vec::new();
+    for token in tokens {
+      if !sources.iter().any(|s| s == token) {
+        sources.push(token.to_string());
+      }
+    }
+    sources
+  }
+
   /// Whether the given source is configured on this directive or not.
   pub fn contains(&self, source: &str) -> bool {
     match self {
-      Self::Inline(s) => s.contains(&format!("{source} ")) || s.contains(&format!(" {source}")),
+      Self::Inline(s) => s.split_whitespace().any(|s| s == source),
       Self::List(l) => l.contains(&source.into()),
     }
   }
@@ -1325,6 +2485,75 @@ impl CspDirectiveSources {
       self.push(s);
     }
   }
+
+  /// Removes all occurrences of the given source from this directive.
+  ///
+  /// Returns `true` if the source was found.
+  pub fn remove(&mut self, source: &str) -> bool {
+    match self {
+      Self::Inline(s) => {
+        let tokens = s.split_whitespace().collect::<Vec<_>>();
+        let removed = tokens.contains(&source);
+        if removed {
+          *s = tokens
+            .into_iter()
+            .filter(|token| *token != source)
+            .collect::<Vec<_>>()
+            .join(" ");
+        }
+        removed
+      }
+      Self::List(l) => {
+        let len = l.len();
+        l.retain(|s| s != source);
+        l.len() != len
+      }
+    }
+  }
+
+  /// Replaces the sources of this directive with the given list.
+  pub fn replace(&mut self, sources: Vec<String>) {
+    match self {
+      Self::Inline(s) => *s = sources.join(" "),
+      Self::List(l) => *l = sources,
+    }
+  }
+
+  /// Whether this directive lists specific origins that are already allowed by a broad wildcard
+  /// source of the same directive, either `*` or `https:`.
+  ///
+  /// Such origins are redundant, and usually a sign that the wildcard was added by mistake.
+  pub fn is_subsumed_by_wildcard(&self) -> bool {
+    !self.subsumed_sources().is_empty()
+  }
+
+  /// The host sources of this directive that are allowed by its `*` or `https:` source.
+  fn subsumed_sources(&self) -> Vec<String> {
+    let sources = self.normalized();
+    let any_host = sources.iter().any(|s| s == "*");
+    let any_https = sources.iter().any(|s| s.eq_ignore_ascii_case("https:"));
+    if !(any_host || any_https) {
+      return Vec::new();
+    }
+    sources
+      .into_iter()
+      .filter(|source| {
+        // keywords such as 'self' or nonces and scheme sources such as `data:` are not origins
+        if source == "*" || source.starts_with('\'') || source.ends_with(':') {
+          return false;
+        }
+        match source.split_once("://") {
+          // `*` only matches the network schemes
+          Some((scheme, _)) => {
+            let scheme = scheme.to_ascii_lowercase();
+            (any_https && scheme == "https")
+              || (any_host && matches!(scheme.as_str(), "http" | "https" | "ws" | "wss"))
+          }
+          None => any_host,
+        }
+      })
+      .collect()
+  }
 }
 
 /// A Content-Security-Policy definition.
@@ -1364,18 +2593,29 @@ impl From<Csp> for HashMap<String, CspDirectiveSources> {
   }
 }
 
+/// The directives of the map in their canonical order: `default-src` first, then alphabetically.
+fn sorted_directives(
+  map: &HashMap<String, CspDirectiveSources>,
+) -> Vec<(&String, &CspDirectiveSources)> {
+  let mut directives = map.iter().collect::<Vec<_>>();
+  directives.sort_by_key(|(directive, _)| (directive.as_str() != "default-src", *directive));
+  directives
+}
+
 impl Display for Csp {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     match self {
       Self::Policy(s) => write!(f, "{s}"),
       Self::DirectiveMap(m) => {
         let len = m.len();
-        let mut i = 0;
-        for (directive, sources) in m {
-          let sources: Vec<String> = sources.clone().into();
-          write!(f, "{} {}", directive, sources.join(" "))?;
-          i += 1;
-          if i != len {
+        for (i, (directive, sources)) in sorted_directives(m).into_iter().enumerate() {
+          let sources = sources.normalized();
+          if sources.is_empty() {
+            write!(f, "{directive}")?;
+          } else {
+            write!(f, "{} {}", directive, sources.join(" "))?;
+          }
+          if i + 1 != len {
             write!(f, "; ")?;
           }
         }
@@ -1385,6 +2625,70 @@ impl Display for Csp {
   }
 }
 
+/// Whether a window is visible on all workspaces or virtual desktops.
+///
+/// - `false` or `{ "visibleOnAll": false }`: the window is only visible on its own workspace.
+/// - `true` or `{ "visibleOnAll": true, "skipTransformProcessType": true }`: the window is visible on all workspaces.
+/// - `{ "visibleOnAll": true }`: the window is visible on all workspaces and, on macOS, also on top of full screen apps.
+///
+/// ## Platform-specific
+///
+/// - **macOS**: being visible on all workspaces sets the `NSWindowCollectionBehaviorCanJoinAllSpaces` collection behavior,
+///   and being visible on top of full screen apps also sets `NSWindowCollectionBehaviorFullScreenAuxiliary`.
+/// - **Linux**: the object form behaves like the boolean.
+/// - **Windows / iOS / Android:** Unsupported.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize, Serialize)]
+#[serde(untagged)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub enum VisibleOnAllWorkspaces {
+  /// Whether the window is visible on all workspaces or not.
+  Flag(bool),
+  /// Visibility on all workspaces with macOS-specific options.
+  #[serde(rename_all = "camelCase")]
+  Options {
+    /// Whether the window is visible on all workspaces or not.
+    #[serde(alias = "visible-on-all")]
+    visible_on_all: bool,
+    /// Whether the window is kept off full screen apps on macOS, so the object behaves like the `true` boolean.
+    /// Defaults to `false`.
+    #[serde(default, alias = "skip-transform-process-type")]
+    skip_transform_process_type: bool,
+  },
+}
+
+impl Default for VisibleOnAllWorkspaces {
+  fn default() -> Self {
+    Self::Flag(false)
+  }
+}
+
+impl From<bool> for VisibleOnAllWorkspaces {
+  fn from(visible_on_all: bool) -> Self {
+    Self::Flag(visible_on_all)
+  }
+}
+
+impl VisibleOnAllWorkspaces {
+  /// Whether the window is visible on all workspaces or not.
+  pub fn visible_on_all(&self) -> bool {
+    match self {
+      Self::Flag(visible_on_all) => *visible_on_all,
+      Self::Options { visible_on_all, .. } => *visible_on_all,
+    }
+  }
+
+  /// Whether the window must also be shown on top of full screen apps on macOS.
+  pub fn full_screen_auxiliary(&self) -> bool {
+    matches!(
+      self,
+      Self::Options {
+        visible_on_all: true,
+        skip_transform_process_type: false,
+      }
+    )
+  }
+}
+
 /// The possible values for the `dangerous_disable_asset_csp_modification` config option.
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
 #[serde(untagged)]
@@ -1464,6 +2768,18 @@ pub enum FsScope {
     // dotfiles are not supposed to be exposed by default on unix
     #[serde(alias = "require-literal-leading-dot")]
     require_literal_leading_dot: Option<bool>,
+    /// Whether the paths that do not exist yet are resolved before being matched against the scope.
+    ///
+    /// Existing paths are always canonicalized, resolving symlinks and `..` components.
+    /// A path that does not exist, e.g. a file about to be written, is matched as is by default,
+    /// so a new file in a symlinked directory pointing outside of the scope is allowed.
+    /// When enabled, the longest existing ancestor of the path is canonicalized instead,
+    /// and paths with `..` components after that ancestor are denied.
+    /// Enabling it is recommended when the allowed directories may contain user or third-party controlled files.
+    ///
+    /// Defaults to `false`.
+    #[serde(alias = "resolve-symlinks")]
+    resolve_symlinks: Option<bool>,
   },
 }
 
@@ -1489,6 +2805,16 @@ impl FsScope {
       Self::Scope { deny, .. } => Some(deny),
     }
   }
+
+  /// Whether the paths that do not exist yet are resolved before being matched against the scope.
+  pub fn resolve_symlinks(&self) -> bool {
+    match self {
+      Self::AllowedPaths(_) => false,
+      Self::Scope {
+        resolve_symlinks, ..
+      } => resolve_symlinks.unwrap_or(false),
+    }
+  }
 }
 
 /// Config for the asset custom protocol.
@@ -1504,6 +2830,55 @@ pub struct AssetProtocolConfig {
   /// Enables the asset protocol.
   #[serde(default)]
   pub enable: bool,
+  /// Maps a file extension to the `Content-Type` returned for those files.
+  ///
+  /// The MIME type is otherwise guessed from the file contents and extension,
+  /// which can be wrong for uncommon extensions. A common need is `{ "wasm": "application/wasm" }`.
+  ///
+  /// Extensions are case-insensitive and may start with a dot, they are stored lowercased and without the dot.
+  /// Each value must be a MIME type in the `type/subtype` format.
+  #[serde(
+    default,
+    alias = "mime-overrides",
+    deserialize_with = "de_mime_overrides"
+  )]
+  pub mime_overrides: HashMap<String, String>,
+}
+
+fn de_mime_overrides<'de, D>(deserializer: D) -> Result<HashMap<String, String>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let overrides = HashMap::<String, String>::deserialize(deserializer)?;
+  let mut normalized = HashMap::with_capacity(overrides.len());
+  for (extension, mime_type) in overrides {
+    let key = extension
+      .strip_prefix('.')
+      .unwrap_or(&extension)
+      .to_ascii_lowercase();
+    if key.is_empty() || key.contains(['.', '/', '\\']) {
+      return Err(DeError::custom(format!(
+        "invalid extension `{extension}` for the `{mime_type}` MIME type, expected e.g. `wasm`"
+      )));
+    }
+    let essence = mime_type.split(';').next().unwrap_or_default().trim();
+    let valid = essence.split_once('/').is_some_and(|(type_, subtype)| {
+      [type_, subtype]
+        .iter()
+        .all(|part| !part.is_empty() && !part.contains(|c: char| c.is_whitespace() || c == '/'))
+    });
+    if !valid {
+      return Err(DeError::custom(format!(
+        "invalid MIME type `{mime_type}` for the `{extension}` extension, expected `type/subtype`"
+      )));
+    }
+    if normalized.insert(key, mime_type).is_some() {
+      return Err(DeError::custom(format!(
+        "the `{extension}` extension is mapped to more than one MIME type"
+      )));
+    }
+  }
+  Ok(normalized)
 }
 
 /// Security configuration.
@@ -1522,6 +2897,9 @@ pub struct SecurityConfig {
   pub csp: Option<Csp>,
   /// The Content Security Policy that will be injected on all HTML files on development.
   ///
+  /// `tauri dev --relax-csp` adds the dev server origin to its `connect-src` and `script-src` directives,
+  /// see [`SecurityConfig::relaxed_dev_csp`].
+  ///
   /// This is a really important part of the configuration since it helps you ensure your WebView is secured.
   /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/CSP>.
   #[serde(alias = "dev-csp")]
@@ -1546,6 +2924,141 @@ pub struct SecurityConfig {
   /// Custom protocol config.
   #[serde(default, alias = "asset-protocol")]
   pub asset_protocol: AssetProtocolConfig,
+  /// An endpoint that receives the Content Security Policy violation reports.
+  ///
+  /// When set, a `report-uri` directive pointing to this URL and a `report-to` directive
+  /// referencing the [`CSP_REPORT_GROUP`] reporting group are appended to the [`csp`](#SecurityConfig.csp)
+  /// and [`dev_csp`](#SecurityConfig.devCsp) values, replacing any reporting directive already set there.
+  /// The group is declared on the `Reporting-Endpoints` response header.
+  ///
+  /// Must be an `http` or `https` URL. Ignored if no CSP is configured.
+  #[serde(
+    default,
+    alias = "csp-report-uri",
+    deserialize_with = "de_csp_report_uri"
+  )]
+  pub csp_report_uri: Option<Url>,
+  /// Additional schemes the external URLs of the configuration can use, besides `http` and `https`.
+  ///
+  /// The window URLs, the splash window URLs and the `devPath` and `distDir` URLs are rejected when parsing the configuration
+  /// if they use another scheme. This keeps a value derived from untrusted input from loading
+  /// a local file with `file`, or running a script with `javascript` or `data`, in the webview.
+  ///
+  /// List a custom scheme registered by the app here to load it, e.g. `["myapp"]`.
+  ///
+  /// **WARNING:** Only allow the `file`, `javascript` or `data` schemes if you know what you are doing,
+  /// the webview then loads whatever these URLs contain with access to the IPC.
+  #[serde(default, alias = "dangerous-allowed-url-schemes")]
+  pub dangerous_allowed_url_schemes: Vec<String>,
+}
+
+/// The name of the reporting group used by the `report-to` directive added by [`SecurityConfig::csp_report_uri`].
+pub const CSP_REPORT_GROUP: &str = "tauri-csp";
+
+/// The schemes the external URLs of the configuration can always use, see [`SecurityConfig::dangerous_allowed_url_schemes`].
+pub const ALLOWED_URL_SCHEMES: &[&str] = &["http", "https"];
+
+impl SecurityConfig {
+  /// Whether the configuration can load the given URL, see [`Self::dangerous_allowed_url_schemes`].
+  ///
+  /// [`WebviewUrl::App`] URLs are always allowed.
+  pub fn is_url_allowed(&self, url: &WebviewUrl) -> bool {
+    match url {
+      WebviewUrl::External(url) => {
+        ALLOWED_URL_SCHEMES.contains(&url.scheme())
+          || self
+            .dangerous_allowed_url_schemes
+            .iter()
+            .any(|scheme| scheme.eq_ignore_ascii_case(url.scheme()))
+      }
+      WebviewUrl::App(_) => true,
+    }
+  }
+
+  /// Appends the reporting directives for [`Self::csp_report_uri`] to the given CSP.
+  ///
+  /// Returns the CSP unchanged if no report URI is configured.
+  pub fn with_csp_reporting(&self, csp: Csp) -> Csp {
+    match &self.csp_report_uri {
+      Some(uri) => {
+        let mut directives: HashMap<String, CspDirectiveSources> = csp.into();
+        directives.insert(
+          "report-uri".into(),
+          CspDirectiveSources::List(vec![uri.to_string()]),
+        );
+        directives.insert(
+          "report-to".into(),
+          CspDirectiveSources::List(vec![CSP_REPORT_GROUP.into()]),
+        );
+        Csp::DirectiveMap(directives)
+      }
+      None => csp,
+    }
+  }
+
+  /// The `Reporting-Endpoints` header value declaring the [`CSP_REPORT_GROUP`] group, if [`Self::csp_report_uri`] is set.
+  pub fn csp_reporting_endpoints(&self) -> Option<String> {
+    self
+      .csp_report_uri
+      .as_ref()
+      .map(|uri| format!("{CSP_REPORT_GROUP}=\"{uri}\""))
+  }
+
+  /// The development CSP with the given dev server origin allowed, used by `tauri dev --relax-csp`.
+  ///
+  /// The origin is added to the [`RELAXED_DEV_CSP_DIRECTIVES`] of [`Self::dev_csp`],
+  /// or of [`Self::csp`] if no development CSP is set. The `connect-src` directive also gets
+  /// the matching WebSocket origin (`ws://` or `wss://`) so hot module replacement keeps working.
+  /// A directive that is not set is created from the `default-src` sources;
+  /// if `default-src` is not set either, the directive is unrestricted and left as is.
+  ///
+  /// [`Self::csp`] itself is never modified, so release builds are not affected.
+  /// Returns `None` if no CSP is configured.
+  pub fn relaxed_dev_csp(&self, origin: &Url) -> Option<Csp> {
+    let csp = self.dev_csp.as_ref().or(self.csp.as_ref())?.clone();
+    let origin = origin.origin().ascii_serialization();
+    let mut directives: HashMap<String, CspDirectiveSources> = csp.into();
+    for directive in RELAXED_DEV_CSP_DIRECTIVES {
+      let mut sources = match directives.get(*directive) {
+        Some(sources) => sources.clone(),
+        None => match directives.get("default-src") {
+          Some(default_src) => CspDirectiveSources::List(default_src.normalized()),
+          None => continue,
+        },
+      };
+      if !sources.contains(&origin) {
+        sources.push(&origin);
+      }
+      if *directive == "connect-src" {
+        let ws_origin = origin
+          .replacen("https://", "wss://", 1)
+          .replacen("http://", "ws://", 1);
+        if ws_origin != origin && !sources.contains(&ws_origin) {
+          sources.push(ws_origin);
+        }
+      }
+      directives.insert(directive.to_string(), sources);
+    }
+    Some(Csp::DirectiveMap(directives))
+  }
+}
+
+/// The directives that get the dev server origin with [`SecurityConfig::relaxed_dev_csp`].
+pub const RELAXED_DEV_CSP_DIRECTIVES: &[&str] = &["connect-src", "script-src"];
+
+fn de_csp_report_uri<'de, D>(deserializer: D) -> Result<Option<Url>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let uri = Option::<Url>::deserialize(deserializer)?;
+  if let Some(uri) = &uri {
+    if !matches!(uri.scheme(), "http" | "https") {
+      return Err(DeError::custom(format!(
+        "invalid CSP report URI `{uri}`, expected an `http` or `https` URL"
+      )));
+    }
+  }
+  Ok(uri)
 }
 
 /// The application pattern.
@@ -1560,9 +3073,48 @@ pub enum PatternKind {
   Isolation {
     /// The dir containing the index.html file that contains the secure isolation application.
     dir: PathBuf,
+    /// Headers added to the response serving the isolation iframe document, e.g. a stricter `X-Frame-Options`.
+    ///
+    /// They only apply to the isolation iframe, not to the app documents,
+    /// and replace the header of the same name set by Tauri, e.g. `Content-Type`.
+    /// The names must be valid HTTP header names and the values must not contain control characters.
+    #[serde(
+      default,
+      rename = "responseHeaders",
+      alias = "response-headers",
+      deserialize_with = "de_isolation_response_headers"
+    )]
+    response_headers: HashMap<String, String>,
   },
 }
 
+fn de_isolation_response_headers<'de, D>(
+  deserializer: D,
+) -> Result<HashMap<String, String>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let headers = HashMap::<String, String>::deserialize(deserializer)?;
+  for (name, value) in &headers {
+    // the token characters of RFC 9110
+    let valid_name = !name.is_empty()
+      && name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(c));
+    if !valid_name {
+      return Err(DeError::custom(format!(
+        "invalid isolation response header name `{name}`"
+      )));
+    }
+    if value.chars().any(|c| c.is_control() && c != '\t') {
+      return Err(DeError::custom(format!(
+        "invalid value for the isolation response header `{name}`, it must not contain control characters"
+      )));
+    }
+  }
+  Ok(headers)
+}
+
 impl Default for PatternKind {
   fn default() -> Self {
     Self::Brownfield
@@ -1595,25 +3147,187 @@ pub struct TauriConfig {
   /// MacOS private API configuration. Enables the transparent background API and sets the `fullScreenEnabled` preference to `true`.
   #[serde(rename = "macOSPrivateApi", alias = "macos-private-api", default)]
   pub macos_private_api: bool,
+  /// The user agent used by every window that does not set its own [`WindowConfig::user_agent`].
+  ///
+  /// A window's user agent is resolved from its own `userAgent`, then this value,
+  /// then the platform webview default.
+  /// Supports the same placeholders as [`WindowConfig::user_agent`].
+  #[serde(alias = "default-user-agent")]
+  pub default_user_agent: Option<String>,
+  /// The theme used by every window that does not set its own [`WindowConfig::theme`].
+  ///
+  /// A window's theme is resolved from its own `theme`, then this value, then the system theme.
+  /// Windows setting their `theme` to `"system"` follow the system theme regardless of this value.
+  ///
+  /// Only implemented on Windows and macOS 10.14+.
+  #[serde(alias = "default-theme")]
+  pub default_theme: Option<crate::Theme>,
+  /// The minimum webview version the app supports, e.g. `110.0.1587.41`, as one to four period-separated integers.
+  ///
+  /// The installed version is checked when the app starts. If it is older, the app shows a dialog
+  /// telling the user to update the webview and exits instead of opening its windows,
+  /// so the app does not show a blank window on webviews lacking the web APIs it uses.
+  /// Missing components are compared as `0`. No check is done when unset.
+  ///
+  /// The webview versions of the platforms are not comparable, so set this in the platform-specific
+  /// configuration files, e.g. `tauri.windows.conf.json`, instead of `tauri.conf.json`.
+  /// Otherwise a version meant for one platform also blocks or allows the others by accident.
+  ///
+  /// ## Platform-specific
+  ///
+  /// The installed version is the one returned by `tauri::webview_version`:
+  ///
+  /// - **Windows**: the WebView2 runtime version, e.g. `119.0.2151.58`. The dialog links to the WebView2 download page.
+  /// - **macOS**: the WebKit framework bundle version, e.g. `19617.1.17.11.12`, which is updated with the OS.
+  /// - **Linux**: the webkit2gtk version, e.g. `2.42.1`.
+  /// - **Android / iOS**: Unsupported.
+  #[serde(
+    default,
+    alias = "minimum-webview-version",
+    deserialize_with = "de_minimum_webview_version"
+  )]
+  pub minimum_webview_version: Option<String>,
+  /// Global shortcuts registered when the app starts, emitting a global event when triggered,
+  /// even when the app is not focused.
+  ///
+  /// Requires the `global-shortcut` Cargo feature. The event is emitted to every target without a payload
+  /// each time the shortcut is pressed. A shortcut already registered by another application is skipped,
+  /// and an accelerator used by more than one entry is reported by the config validation.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **macOS**: Media key shortcuts require the user to grant the app the accessibility permission,
+  /// which the OS prompts for the first time one of them is registered.
+  /// - **Linux**: Only supported on X11.
+  /// - **Android / iOS**: Unsupported.
+  #[serde(default, alias = "global-shortcuts")]
+  pub global_shortcuts: Vec<GlobalShortcutConfig>,
+}
+
+fn de_minimum_webview_version<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let version = Option::<String>::deserialize(deserializer)?;
+  if let Some(version) = &version {
+    if parse_webview_version(version).is_none() {
+      return Err(DeError::custom(format!(
+        "invalid minimum webview version `{version}`, expected one to four period-separated integers"
+      )));
+    }
+  }
+  Ok(version)
+}
+
+/// Parses a `major[.minor[.patch[.build]]]` webview version.
+fn parse_webview_version(version: &str) -> Option<Vec<u64>> {
+  let components = version.split('.').collect::<Vec<_>>();
+  if components.len() > 4 {
+    return None;
+  }
+  components
+    .into_iter()
+    .map(|c| {
+      if !c.is_empty() && c.chars().all(|c| c.is_ascii_digit()) {
+        c.parse().ok()
+      } else {
+        None
+      }
+    })
+    .collect()
 }
 
 impl TauriConfig {
-  /// Returns all Cargo features.
+  /// Whether the installed webview version satisfies [`Self::minimum_webview_version`].
+  ///
+  /// Only the leading period-separated integers of the installed version are compared,
+  /// so suffixes like `-beta` are ignored. Returns `true` if no minimum is set
+  /// or the installed version cannot be parsed.
+  pub fn is_webview_version_supported(&self, installed: &str) -> bool {
+    let Some(minimum) = self
+      .minimum_webview_version
+      .as_deref()
+      .and_then(parse_webview_version)
+    else {
+      return true;
+    };
+    let installed = installed
+      .trim()
+      .split('.')
+      .map_while(|c| {
+        let digits = c
+          .chars()
+          .take_while(char::is_ascii_digit)
+          .collect::<String>();
+        digits.parse::<u64>().ok()
+      })
+      .collect::<Vec<_>>();
+    if installed.is_empty() {
+      return true;
+    }
+    let len = installed.len().max(minimum.len());
+    let pad = |v: &[u64]| {
+      let mut v = v.to_vec();
+      v.resize(len, 0);
+      v
+    };
+    pad(&installed) >= pad(&minimum)
+  }
+
+  /// Returns the user agent for the given window: its own [`WindowConfig::user_agent`],
+  /// falling back to [`Self::default_user_agent`].
+  ///
+  /// `None` means the platform webview default is used.
+  pub fn user_agent_for<'a>(&'a self, window: &'a WindowConfig) -> Option<&'a str> {
+    window
+      .user_agent
+      .as_deref()
+      .or(self.default_user_agent.as_deref())
+  }
+
+  /// Returns the theme for the given window: its own [`WindowConfig::theme`],
+  /// falling back to [`Self::default_theme`].
+  ///
+  /// `None` means the window follows the system theme.
+  pub fn theme_for(&self, window: &WindowConfig) -> Option<crate::Theme> {
+    window.theme.or(self.default_theme)
+  }
+
+  /// Returns all Cargo features, for every target. See [`Self::all_features_for`].
   pub fn all_features() -> Vec<&'static str> {
     vec![
       "tray-icon",
+      "global-shortcut",
       "macos-private-api",
       "isolation",
       "protocol-asset",
     ]
   }
 
+  /// Returns all Cargo features that are relevant for the given target.
+  ///
+  /// - `tray-icon` and `global-shortcut` are desktop-only, so they are excluded for Android and iOS.
+  /// - `macos-private-api` is only included for macOS.
+  pub fn all_features_for(target: Target) -> Vec<&'static str> {
+    Self::all_features()
+      .into_iter()
+      .filter(|feature| match *feature {
+        "tray-icon" | "global-shortcut" => !matches!(target, Target::Android | Target::Ios),
+        "macos-private-api" => target == Target::MacOS,
+        _ => true,
+      })
+      .collect()
+  }
+
   /// Returns the enabled Cargo features.
   pub fn features(&self) -> Vec<&str> {
     let mut features = Vec::new();
     if self.tray_icon.is_some() {
       features.push("tray-icon");
     }
+    if !self.global_shortcuts.is_empty() {
+      features.push("global-shortcut");
+    }
     if self.macos_private_api {
       features.push("macos-private-api");
     }
@@ -1714,13 +3428,53 @@ impl<'de> Deserialize<'de> for WindowsUpdateInstallMode {
 ///
 /// See more: <https://tauri.app/v1/api/config#updaterwindowsconfig>
 #[skip_serializing_none]
-#[derive(Debug, Default, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct UpdaterWindowsConfig {
   /// The installation mode for the update on Windows. Defaults to `passive`.
   #[serde(default, alias = "install-mode")]
   pub install_mode: WindowsUpdateInstallMode,
+  /// Whether the update should be installed in the scope of the original installation. Defaults to `true`.
+  ///
+  /// When enabled and the app was installed per-machine, `tauri::process::run_update_installer` launches
+  /// the update installer elevated if the app runs without administrator privileges.
+  /// Windows then shows the User Account Control prompt, even with the `quiet` [install mode](Self::install_mode).
+  /// When disabled, the update installer always runs with the privileges of the app,
+  /// so a per-machine installation can only be updated if the app runs as administrator.
+  #[serde(default = "default_true", alias = "respect-install-scope")]
+  pub respect_install_scope: bool,
+}
+
+impl Default for UpdaterWindowsConfig {
+  fn default() -> Self {
+    Self {
+      install_mode: Default::default(),
+      respect_install_scope: true,
+    }
+  }
+}
+
+impl UpdaterWindowsConfig {
+  /// Whether the update installer must be relaunched elevated for an app installed in the given scope,
+  /// when the updater runs with or without administrator privileges.
+  pub fn requires_elevation(&self, install_scope: WindowsInstallScope, elevated: bool) -> bool {
+    self.respect_install_scope && install_scope == WindowsInstallScope::PerMachine && !elevated
+  }
+}
+
+/// The scope of an app installation on Windows, as recorded by the installer in the
+/// `InstallScope` value of the `Software\{manufacturer}\{productName}` registry key.
+///
+/// The key is under `HKEY_LOCAL_MACHINE` for per-machine installations and under
+/// `HKEY_CURRENT_USER` for per-user installations.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WindowsInstallScope {
+  /// Installed for the current user only.
+  CurrentUser,
+  /// Installed for all users, which requires administrator privileges.
+  PerMachine,
 }
 
 /// Configuration for application tray icon.
@@ -1748,6 +3502,179 @@ pub struct TrayIconConfig {
   pub tooltip: Option<String>,
 }
 
+/// A global shortcut declared in the configuration, see [`TauriConfig::global_shortcuts`].
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct GlobalShortcutConfig {
+  /// The accelerator of the shortcut, e.g. `CommandOrControl+Shift+K`.
+  ///
+  /// It is made of any number of modifiers (`Shift`, `Control` or `Ctrl`, `Alt` or `Option`,
+  /// `Super`, `Command` or `Cmd`, and `CommandOrControl` or `CmdOrCtrl`) followed by a single key,
+  /// all separated by `+` and case insensitive.
+  #[serde(deserialize_with = "de_accelerator")]
+  pub accelerator: String,
+  /// The name of the global event emitted when the shortcut is triggered.
+  ///
+  /// Must include only alphanumeric characters, `-`, `/`, `:` and `_`.
+  pub event: String,
+}
+
+impl GlobalShortcutConfig {
+  const MODIFIERS: &'static [&'static str] = &[
+    "SHIFT",
+    "CONTROL",
+    "CTRL",
+    "ALT",
+    "OPTION",
+    "SUPER",
+    "COMMAND",
+    "CMD",
+    "COMMANDORCONTROL",
+    "COMMANDORCTRL",
+    "CMDORCONTROL",
+    "CMDORCTRL",
+  ];
+
+  const NAMED_KEYS: &'static [&'static str] = &[
+    "BACKQUOTE",
+    "BACKSLASH",
+    "BRACKETLEFT",
+    "BRACKETRIGHT",
+    "COMMA",
+    "EQUAL",
+    "MINUS",
+    "PERIOD",
+    "QUOTE",
+    "SEMICOLON",
+    "SLASH",
+    "BACKSPACE",
+    "CAPSLOCK",
+    "ENTER",
+    "SPACE",
+    "TAB",
+    "DELETE",
+    "END",
+    "HOME",
+    "INSERT",
+    "PAGEDOWN",
+    "PAGEUP",
+    "PRINTSCREEN",
+    "SCROLLLOCK",
+    "ARROWDOWN",
+    "DOWN",
+    "ARROWLEFT",
+    "LEFT",
+    "ARROWRIGHT",
+    "RIGHT",
+    "ARROWUP",
+    "UP",
+    "NUMLOCK",
+    "NUMPADADD",
+    "NUMADD",
+    "NUMPADPLUS",
+    "NUMPLUS",
+    "NUMPADDECIMAL",
+    "NUMDECIMAL",
+    "NUMPADDIVIDE",
+    "NUMDIVIDE",
+    "NUMPADENTER",
+    "NUMENTER",
+    "NUMPADEQUAL",
+    "NUMEQUAL",
+    "NUMPADMULTIPLY",
+    "NUMMULTIPLY",
+    "NUMPADSUBTRACT",
+    "NUMSUBTRACT",
+    "ESCAPE",
+    "ESC",
+    "AUDIOVOLUMEDOWN",
+    "VOLUMEDOWN",
+    "AUDIOVOLUMEUP",
+    "VOLUMEUP",
+    "AUDIOVOLUMEMUTE",
+    "VOLUMEMUTE",
+    "MEDIAPLAY",
+    "MEDIAPAUSE",
+    "MEDIAPLAYPAUSE",
+    "MEDIASTOP",
+    "MEDIATRACKNEXT",
+    "MEDIATRACKPREV",
+    "MEDIATRACKPREVIOUS",
+  ];
+
+  /// Checks that the accelerator is made of known modifiers followed by a single known key,
+  /// accepting the same accelerators as the parser used to register the shortcut at runtime.
+  pub fn validate_accelerator(accelerator: &str) -> Result<(), String> {
+    let tokens = accelerator
+      .split('+')
+      .map(|token| token.trim().to_uppercase())
+      .collect::<Vec<_>>();
+    let (key, modifiers) = tokens.split_last().expect("split always yields a token");
+
+    if tokens.len() > 1 && tokens.iter().any(String::is_empty) {
+      return Err(format!(
+        "invalid accelerator `{accelerator}`, it has an empty token"
+      ));
+    }
+
+    for modifier in modifiers {
+      if !Self::MODIFIERS.contains(&modifier.as_str()) {
+        return Err(if Self::is_key(modifier) {
+          format!(
+            "invalid accelerator `{accelerator}`, it must have a single key after the modifiers"
+          )
+        } else {
+          format!("invalid accelerator `{accelerator}`, unknown modifier `{modifier}`")
+        });
+      }
+    }
+
+    if key.is_empty() || Self::MODIFIERS.contains(&key.as_str()) {
+      Err(format!(
+        "invalid accelerator `{accelerator}`, it must end with a key"
+      ))
+    } else if Self::is_key(key) {
+      Ok(())
+    } else {
+      Err(format!(
+        "invalid accelerator `{accelerator}`, unknown key `{key}`"
+      ))
+    }
+  }
+
+  fn is_key(key: &str) -> bool {
+    let mut chars = key.chars();
+    match (chars.next(), chars.next()) {
+      (Some(c), None) => c.is_ascii_alphanumeric() || "`\\[],=-.';/".contains(c),
+      _ => {
+        Self::NAMED_KEYS.contains(&key)
+          || key
+            .strip_prefix('F')
+            .and_then(|n| n.parse::<u8>().ok())
+            .is_some_and(|n| (1..=24).contains(&n) && !key.starts_with("F0"))
+          || key
+            .strip_prefix("KEY")
+            .is_some_and(|k| k.len() == 1 && k.chars().all(|c| c.is_ascii_uppercase()))
+          || key
+            .strip_prefix("DIGIT")
+            .or_else(|| key.strip_prefix("NUMPAD"))
+            .or_else(|| key.strip_prefix("NUM"))
+            .is_some_and(|k| k.len() == 1 && k.chars().all(|c| c.is_ascii_digit()))
+      }
+    }
+  }
+}
+
+fn de_accelerator<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let accelerator = String::deserialize(deserializer)?;
+  GlobalShortcutConfig::validate_accelerator(&accelerator).map_err(DeError::custom)?;
+  Ok(accelerator)
+}
+
 /// General configuration for the iOS target.
 #[skip_serializing_none]
 #[derive(Debug, Default, PartialEq, Eq, Clone, Deserialize, Serialize)]
@@ -1758,6 +3685,38 @@ pub struct IosConfig {
   /// The `APPLE_DEVELOPMENT_TEAM` environment variable can be set to overwrite it.
   #[serde(alias = "development-team")]
   pub development_team: Option<String>,
+  /// The build number (`CFBundleVersion`), which must be one to three period-separated integers (e.g. `"42"` or `"1.2.3"`).
+  ///
+  /// The App Store requires it to increase with each upload of the same version. Defaults to the app version.
+  #[serde(
+    default,
+    alias = "build-number",
+    deserialize_with = "de_ios_build_number"
+  )]
+  pub build_number: Option<String>,
+  /// The bundle identifier to use on iOS instead of the [`identifier`](BundleConfig::identifier).
+  #[serde(alias = "bundle-identifier")]
+  pub bundle_identifier: Option<String>,
+}
+
+fn de_ios_build_number<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let build_number = Option::<String>::deserialize(deserializer)?;
+  if let Some(build_number) = &build_number {
+    let components = build_number.split('.').collect::<Vec<_>>();
+    let valid = components.len() <= 3
+      && components
+        .iter()
+        .all(|c| !c.is_empty() && c.chars().all(|c| c.is_ascii_digit()));
+    if !valid {
+      return Err(DeError::custom(format!(
+        "invalid iOS build number `{build_number}`, expected one to three period-separated integers"
+      )));
+    }
+  }
+  Ok(build_number)
 }
 
 /// General configuration for the iOS target.
@@ -1770,16 +3729,65 @@ pub struct AndroidConfig {
   /// The Android system will prevent the user from installing the application if the system's API level is lower than the value specified.
   #[serde(alias = "min-sdk-version", default = "default_min_sdk_version")]
   pub min_sdk_version: u32,
+  /// The `versionCode` used by the Android system and Google Play to order releases.
+  /// Google Play requires it to increase with each upload and to be at most 2100000000.
+  ///
+  /// Defaults to `major * 1000000 + minor * 1000 + patch`, computed from the app version.
+  #[serde(
+    default,
+    alias = "version-code",
+    deserialize_with = "de_android_version_code"
+  )]
+  pub version_code: Option<u32>,
+  /// The identifier to use on Android instead of the [`identifier`](BundleConfig::identifier),
+  /// from which the application ID and package name are derived.
+  #[serde(alias = "package-name")]
+  pub package_name: Option<String>,
 }
 
 impl Default for AndroidConfig {
   fn default() -> Self {
     Self {
       min_sdk_version: default_min_sdk_version(),
+      version_code: None,
+      package_name: None,
     }
   }
 }
 
+/// The maximum `versionCode` accepted by Google Play.
+const MAX_ANDROID_VERSION_CODE: u32 = 2_100_000_000;
+
+impl AndroidConfig {
+  /// The configured [`version_code`](Self::version_code), or the one derived from the given app version.
+  ///
+  /// Returns `1` if the version is not set or is not a valid semver version.
+  pub fn version_code_for(&self, version: Option<&str>) -> u32 {
+    self.version_code.unwrap_or_else(|| {
+      version
+        .and_then(|v| Version::parse(v).ok())
+        .map(|v| {
+          let code = v.major * 1_000_000 + v.minor * 1_000 + v.patch;
+          code.clamp(1, MAX_ANDROID_VERSION_CODE as u64) as u32
+        })
+        .unwrap_or(1)
+    })
+  }
+}
+
+fn de_android_version_code<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let version_code = Option::<u32>::deserialize(deserializer)?;
+  match version_code {
+    Some(code) if code == 0 || code > MAX_ANDROID_VERSION_CODE => Err(DeError::custom(format!(
+      "invalid Android version code {code}, expected a value between 1 and {MAX_ANDROID_VERSION_CODE}"
+    ))),
+    code => Ok(code),
+  }
+}
+
 fn default_min_sdk_version() -> u32 {
   24
 }
@@ -1837,7 +3845,16 @@ pub enum HookCommand {
     script: String,
     /// The current working directory.
     cwd: Option<String>,
+    /// Whether the hook keeps going when the script fails, only logging a warning. Defaults to `false`,
+    /// aborting the CLI command.
+    #[serde(default, rename = "continueOnError", alias = "continue-on-error")]
+    continue_on_error: bool,
   },
+  /// Run the given commands one after another, in the order they are listed.
+  ///
+  /// A failing command stops the hook, and the commands after it are not run,
+  /// unless it sets `continueOnError`.
+  List(Vec<HookCommand>),
 }
 
 /// The Build configuration object.
@@ -1877,12 +3894,15 @@ pub struct BuildConfig {
   /// The TAURI_ENV_PLATFORM, TAURI_ENV_ARCH, TAURI_ENV_FAMILY, TAURI_ENV_PLATFORM_VERSION, TAURI_ENV_PLATFORM_TYPE and TAURI_ENV_DEBUG environment variables are set if you perform conditional compilation.
   #[serde(alias = "before-dev-command")]
   pub before_dev_command: Option<BeforeDevCommand>,
-  /// A shell command to run before `tauri build` kicks in.
+  /// A shell command, or a list of shell commands run in order, to run before `tauri build` kicks in.
   ///
   /// The TAURI_ENV_PLATFORM, TAURI_ENV_ARCH, TAURI_ENV_FAMILY, TAURI_ENV_PLATFORM_VERSION, TAURI_ENV_PLATFORM_TYPE and TAURI_ENV_DEBUG environment variables are set if you perform conditional compilation.
   #[serde(alias = "before-build-command")]
   pub before_build_command: Option<HookCommand>,
-  /// A shell command to run before the bundling phase in `tauri build` kicks in.
+  /// A shell command, or a list of shell commands run in order, to run before the bundling phase in `tauri build` kicks in.
+  ///
+  /// Use a list for several bundle preparation steps, and set `continueOnError` on the optional ones
+  /// so their failure only logs a warning instead of aborting the build.
   ///
   /// The TAURI_ENV_PLATFORM, TAURI_ENV_ARCH, TAURI_ENV_FAMILY, TAURI_ENV_PLATFORM_VERSION, TAURI_ENV_PLATFORM_TYPE and TAURI_ENV_DEBUG environment variables are set if you perform conditional compilation.
   #[serde(alias = "before-bundle-command")]
@@ -1892,6 +3912,12 @@ pub struct BuildConfig {
   /// Whether we should inject the Tauri API on `window.__TAURI__` or not.
   #[serde(default, alias = "with-global-tauri")]
   pub with_global_tauri: bool,
+  /// Whether the built-in dev server, used when `devPath` is a path, injects its live reload script or not.
+  ///
+  /// Disabling it serves the files verbatim, which is useful when debugging CSP issues,
+  /// but the app will no longer reload automatically when the static assets change.
+  #[serde(default = "default_true", alias = "dev-server-live-reload")]
+  pub dev_server_live_reload: bool,
 }
 
 impl Default for BuildConfig {
@@ -1905,6 +3931,7 @@ impl Default for BuildConfig {
       before_bundle_command: None,
       features: None,
       with_global_tauri: false,
+      dev_server_live_reload: true,
     }
   }
 }
@@ -1989,6 +4016,40 @@ pub struct PackageConfig {
   /// App version. It is a semver version number or a path to a `package.json` file containing the `version` field. If removed the version number from `Cargo.toml` is used.
   #[serde(deserialize_with = "version_deserializer", default)]
   pub version: Option<String>,
+  /// The name of the app executable, e.g. `mycoolapp`, leaving [`Self::product_name`] purely for display.
+  ///
+  /// Must only contain ASCII letters, digits, `_`, `-` and `.`, and must not start with `-` or `.`.
+  /// When not set, the binary name is derived from the product name (kebab-cased on Linux).
+  #[serde(
+    default,
+    alias = "binary-name-override",
+    deserialize_with = "de_binary_name_override"
+  )]
+  #[cfg_attr(
+    feature = "schema",
+    validate(regex(pattern = "^[A-Za-z0-9_][A-Za-z0-9_.-]*$"))
+  )]
+  pub binary_name_override: Option<String>,
+}
+
+fn de_binary_name_override<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let name = Option::<String>::deserialize(deserializer)?;
+  if let Some(name) = &name {
+    let valid = !name.is_empty()
+      && name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+      && !name.starts_with(['-', '.']);
+    if !valid {
+      return Err(DeError::custom(format!(
+        "invalid binary name `{name}`, expected ASCII letters, digits, `_`, `-` and `.`, not starting with `-` or `.`"
+      )));
+    }
+  }
+  Ok(name)
 }
 
 fn version_deserializer<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
@@ -2000,8 +4061,13 @@ where
 
 impl PackageConfig {
   /// The binary name.
+  ///
+  /// Returns [`Self::binary_name_override`] when set, otherwise derives it from the product name.
   #[allow(dead_code)]
   pub fn binary_name(&self) -> Option<String> {
+    if let Some(name) = &self.binary_name_override {
+      return Some(name.clone());
+    }
     #[cfg(target_os = "linux")]
     {
       self.product_name.as_ref().map(|n| n.to_kebab_case())
@@ -2099,6 +4165,221 @@ pub struct Config {
   pub plugins: PluginConfig,
 }
 
+impl Config {
+  /// Validates the whole configuration for the given target, collecting every problem found
+  /// instead of stopping at the first one.
+  ///
+  /// [`DiagnosticSeverity::Error`] diagnostics make [`parse()`] fail,
+  /// while [`DiagnosticSeverity::Warning`] and [`DiagnosticSeverity::Info`] diagnostics are only logged.
+  pub fn validate(&self, target: Target) -> Vec<ConfigDiagnostic> {
+    let mut diagnostics = Vec::new();
+
+    let identifier = &self.tauri.bundle.identifier;
+    if !identifier.is_empty()
+      && !identifier.split('.').all(|segment| {
+        !segment.is_empty()
+          && segment
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-')
+      })
+    {
+      diagnostics.push(ConfigDiagnostic::error(
+        "tauri > bundle > identifier",
+        format!(
+          "bundle identifier `{identifier}` must only contain alphanumeric characters, hyphens and periods, and must not have empty segments"
+        ),
+      ));
+    }
+
+    for (platform, icons) in &self.tauri.bundle.platform_icons {
+      let extensions = supported_icon_extensions(*platform);
+      for (index, icon) in icons.iter().enumerate() {
+        let supported = Path::new(icon)
+          .extension()
+          .and_then(|e| e.to_str())
+          .is_some_and(|e| extensions.contains(&e.to_lowercase().as_str()));
+        if !supported {
+          diagnostics.push(ConfigDiagnostic::error(
+            format!("tauri > bundle > platformIcons > {platform} > {index}"),
+            format!(
+              "icon `{icon}` is not supported on {platform}, expected one of: {}",
+              extensions.join(", ")
+            ),
+          ));
+        }
+      }
+    }
+
+    for (field, csp) in [
+      ("csp", &self.tauri.security.csp),
+      ("devCsp", &self.tauri.security.dev_csp),
+    ] {
+      let Some(csp) = csp else { continue };
+      let directives: HashMap<String, CspDirectiveSources> = csp.clone().into();
+      for (directive, sources) in sorted_directives(&directives) {
+        let subsumed = sources.subsumed_sources();
+        if !subsumed.is_empty() {
+          diagnostics.push(ConfigDiagnostic::warning(
+            format!("tauri > security > {field} > {directive}"),
+            format!(
+              "`{directive}` allows {} with a wildcard source, so these sources are redundant: {}",
+              if sources.contains("*") {
+                "any origin"
+              } else {
+                "any HTTPS origin"
+              },
+              subsumed.join(", ")
+            ),
+          ));
+        }
+      }
+    }
+
+    let security = &self.tauri.security;
+    let mut urls = vec![
+      ("build > devPath".to_string(), &self.build.dev_path),
+      ("build > distDir".to_string(), &self.build.dist_dir),
+    ]
+    .into_iter()
+    .filter_map(|(field, url)| match url {
+      AppUrl::Url(url) => Some((field, url)),
+      AppUrl::Files(_) => None,
+    })
+    .collect::<Vec<_>>();
+    for (index, window) in self.tauri.windows.iter().enumerate() {
+      urls.push((format!("tauri > windows > {index} > url"), &window.url));
+      if let Some(splash) = &window.splash {
+        urls.push((
+          format!("tauri > windows > {index} > splash > url"),
+          &splash.url,
+        ));
+      }
+    }
+    for (field, url) in urls {
+      if !security.is_url_allowed(url) {
+        diagnostics.push(ConfigDiagnostic::error(
+          field,
+          format!(
+            "URL `{url}` uses a scheme that is not allowed, only `http` and `https` URLs can be loaded unless the scheme is listed in `tauri > security > dangerousAllowedUrlSchemes`"
+          ),
+        ));
+      }
+    }
+
+    let mut accelerators = HashSet::new();
+    for (index, shortcut) in self.tauri.global_shortcuts.iter().enumerate() {
+      if !accelerators.insert(shortcut.accelerator.to_uppercase().replace(' ', "")) {
+        diagnostics.push(ConfigDiagnostic::warning(
+          format!("tauri > globalShortcuts > {index} > accelerator"),
+          format!(
+            "accelerator `{}` is used by more than one global shortcut",
+            shortcut.accelerator
+          ),
+        ));
+      }
+      if shortcut.event.is_empty()
+        || !shortcut
+          .event
+          .chars()
+          .all(|c| c.is_alphanumeric() || matches!(c, '-' | '/' | ':' | '_'))
+      {
+        diagnostics.push(ConfigDiagnostic::error(
+          format!("tauri > globalShortcuts > {index} > event"),
+          format!(
+            "event name `{}` must include only alphanumeric characters, `-`, `/`, `:` and `_`",
+            shortcut.event
+          ),
+        ));
+      }
+    }
+
+    let mut labels = HashSet::new();
+    for (index, window) in self.tauri.windows.iter().enumerate() {
+      let prefix = format!("tauri > windows > {index}");
+      if !labels.insert(&window.label) {
+        diagnostics.push(ConfigDiagnostic::error(
+          format!("{prefix} > label"),
+          format!("window label `{}` is used more than once", window.label),
+        ));
+      }
+      if target == Target::MacOS && window.transparent && !self.tauri.macos_private_api {
+        diagnostics.push(ConfigDiagnostic::error(
+          format!("{prefix} > transparent"),
+          format!(
+            "window `{}` sets `transparent` to `true` but it requires `tauri > macOSPrivateApi` on macOS, otherwise the window is not transparent",
+            window.label
+          ),
+        ));
+      }
+      diagnostics.extend(
+        window
+          .validate(target)
+          .into_iter()
+          .map(|diagnostic| ConfigDiagnostic {
+            field_path: format!("{prefix} > {}", diagnostic.field_path),
+            ..diagnostic
+          }),
+      );
+    }
+
+    diagnostics
+  }
+}
+
+/// The severity of a [`ConfigDiagnostic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticSeverity {
+  /// The setting has no effect on the target platform, e.g. a macOS-only option on Windows.
+  Info,
+  /// The setting might not behave as expected, but the app can still run.
+  Warning,
+  /// The configuration is invalid.
+  Error,
+}
+
+/// A problem found by [`Config::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigDiagnostic {
+  /// The severity of the problem.
+  pub severity: DiagnosticSeverity,
+  /// The path to the offending field, e.g. `tauri > windows > 0 > maximizable`.
+  pub field_path: String,
+  /// A human-readable description of the problem.
+  pub message: String,
+}
+
+impl ConfigDiagnostic {
+  fn info(field_path: impl Into<String>, message: impl Into<String>) -> Self {
+    Self {
+      severity: DiagnosticSeverity::Info,
+      field_path: field_path.into(),
+      message: message.into(),
+    }
+  }
+
+  fn warning(field_path: impl Into<String>, message: impl Into<String>) -> Self {
+    Self {
+      severity: DiagnosticSeverity::Warning,
+      field_path: field_path.into(),
+      message: message.into(),
+    }
+  }
+
+  fn error(field_path: impl Into<String>, message: impl Into<String>) -> Self {
+    Self {
+      severity: DiagnosticSeverity::Error,
+      field_path: field_path.into(),
+      message: message.into(),
+    }
+  }
+}
+
+impl Display for ConfigDiagnostic {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "`{}`: {}", self.field_path, self.message)
+  }
+}
+
 /// The plugin configs holds a HashMap mapping a plugin name to its configuration object.
 ///
 /// See more: <https://tauri.app/v1/api/config#pluginconfig>
@@ -2106,6 +4387,37 @@ pub struct Config {
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct PluginConfig(pub HashMap<String, JsonValue>);
 
+impl PluginConfig {
+  /// Deserializes the configuration of the given plugin into its configuration type.
+  ///
+  /// Returns `Ok(None)` when the plugin has no configuration.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use tauri_utils::config::PluginConfig;
+  ///
+  /// #[derive(serde::Deserialize)]
+  /// struct UpdaterConfig {
+  ///   endpoints: Vec<String>,
+  /// }
+  ///
+  /// let config: PluginConfig = serde_json::from_value(serde_json::json!({
+  ///   "updater": { "endpoints": ["https://tauri.app/update"] }
+  /// }))
+  /// .unwrap();
+  /// let updater = config.get_as::<UpdaterConfig>("updater").unwrap().unwrap();
+  /// assert_eq!(updater.endpoints, ["https://tauri.app/update"]);
+  /// ```
+  pub fn get_as<T: DeserializeOwned>(&self, plugin: &str) -> serde_json::Result<Option<T>> {
+    self
+      .0
+      .get(plugin)
+      .map(|config| T::deserialize(config))
+      .transpose()
+  }
+}
+
 fn default_build() -> BuildConfig {
   BuildConfig {
     runner: None,
@@ -2116,6 +4428,7 @@ fn default_build() -> BuildConfig {
     before_bundle_command: None,
     features: None,
     with_global_tauri: false,
+    dev_server_live_reload: true,
   }
 }
 
@@ -2169,6 +4482,7 @@ mod build {
       tokens.append_all(match self {
         Self::Light => quote! { #prefix::Light },
         Self::Dark => quote! { #prefix::Dark },
+        Self::System => quote! { #prefix::System },
       })
     }
   }
@@ -2256,11 +4570,16 @@ mod build {
     fn to_tokens(&self, tokens: &mut TokenStream) {
       let label = str_lit(&self.label);
       let url = &self.url;
+      let url_query = opt_str_lit(self.url_query.as_ref());
+      let url_fragment = opt_str_lit(self.url_fragment.as_ref());
       let user_agent = opt_str_lit(self.user_agent.as_ref());
+      let initialization_script = opt_str_lit(self.initialization_script.as_ref());
+      let with_global_tauri = opt_lit(self.with_global_tauri.as_ref());
       let file_drop_enabled = self.file_drop_enabled;
       let center = self.center;
       let x = opt_lit(self.x.as_ref());
       let y = opt_lit(self.y.as_ref());
+      let monitor = opt_lit(self.monitor.as_ref());
       let width = self.width;
       let height = self.height;
       let min_width = opt_lit(self.min_width.as_ref());
@@ -2268,7 +4587,9 @@ mod build {
       let max_width = opt_lit(self.max_width.as_ref());
       let max_height = opt_lit(self.max_height.as_ref());
       let resizable = self.resizable;
-      let maximizable = self.maximizable;
+      let resizable_horizontal = opt_lit(self.resizable_horizontal.as_ref());
+      let resizable_vertical = opt_lit(self.resizable_vertical.as_ref());
+      let maximizable = opt_lit(self.maximizable.as_ref());
       let minimizable = self.minimizable;
       let closable = self.closable;
       let title = str_lit(&self.title);
@@ -2280,29 +4601,44 @@ mod build {
       let decorations = self.decorations;
       let always_on_bottom = self.always_on_bottom;
       let always_on_top = self.always_on_top;
-      let visible_on_all_workspaces = self.visible_on_all_workspaces;
+      let visible_on_all_workspaces = &self.visible_on_all_workspaces;
       let content_protected = self.content_protected;
       let skip_taskbar = self.skip_taskbar;
       let theme = opt_lit(self.theme.as_ref());
       let title_bar_style = &self.title_bar_style;
       let hidden_title = self.hidden_title;
+      let titlebar_height = opt_lit(self.titlebar_height.as_ref());
       let accept_first_mouse = self.accept_first_mouse;
       let tabbing_identifier = opt_str_lit(self.tabbing_identifier.as_ref());
+      let auto_tabbing = opt_lit(self.auto_tabbing.as_ref());
       let additional_browser_args = opt_str_lit(self.additional_browser_args.as_ref());
       let shadow = self.shadow;
+      let client_side_decorations = self.client_side_decorations;
       let window_effects = opt_lit(self.window_effects.as_ref());
       let incognito = self.incognito;
+      let enable_context_menu = self.enable_context_menu;
+      let open_devtools = self.open_devtools;
+      let remember_state = self.remember_state;
+      let remember_zoom = self.remember_zoom;
+      let splash = opt_lit(self.splash.as_ref());
+      let show_when = opt_lit(self.show_when.as_ref());
+      let navigation_allowlist = opt_vec_str_lit(self.navigation_allowlist.as_ref());
 
       literal_struct!(
         tokens,
         WindowConfig,
         label,
         url,
+        url_query,
+        url_fragment,
         user_agent,
+        initialization_script,
+        with_global_tauri,
         file_drop_enabled,
         center,
         x,
         y,
+        monitor,
         width,
         height,
         min_width,
@@ -2310,6 +4646,8 @@ mod build {
         max_width,
         max_height,
         resizable,
+        resizable_horizontal,
+        resizable_vertical,
         maximizable,
         minimizable,
         closable,
@@ -2328,16 +4666,81 @@ mod build {
         theme,
         title_bar_style,
         hidden_title,
+        titlebar_height,
         accept_first_mouse,
         tabbing_identifier,
+        auto_tabbing,
         additional_browser_args,
         shadow,
+        client_side_decorations,
         window_effects,
-        incognito
+        incognito,
+        enable_context_menu,
+        open_devtools,
+        remember_state,
+        remember_zoom,
+        splash,
+        show_when,
+        navigation_allowlist
       );
     }
   }
 
+  impl ToTokens for MonitorSelector {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+      let prefix = quote! { ::tauri::utils::config::MonitorSelector };
+
+      tokens.append_all(match self {
+        Self::Index(index) => quote! { #prefix::Index(#index) },
+        Self::Name(name) => {
+          let name = str_lit(name);
+          quote! { #prefix::Name(#name) }
+        }
+        Self::Primary => quote! { #prefix::Primary },
+        Self::Cursor => quote! { #prefix::Cursor },
+      })
+    }
+  }
+
+  impl ToTokens for CloseTrigger {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+      let prefix = quote! { ::tauri::utils::config::CloseTrigger };
+
+      tokens.append_all(match self {
+        Self::PageLoad => quote! { #prefix::PageLoad },
+        Self::Event(event) => {
+          let event = str_lit(event);
+          quote! { #prefix::Event(#event) }
+        }
+        Self::Timeout => quote! { #prefix::Timeout },
+      })
+    }
+  }
+
+  impl ToTokens for ShowTrigger {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+      let prefix = quote! { ::tauri::utils::config::ShowTrigger };
+
+      tokens.append_all(match self {
+        Self::PageLoad => quote! { #prefix::PageLoad },
+        Self::Event(event) => {
+          let event = str_lit(event);
+          quote! { #prefix::Event(#event) }
+        }
+      })
+    }
+  }
+
+  impl ToTokens for SplashConfig {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+      let url = &self.url;
+      let min_display_ms = self.min_display_ms;
+      let close_on = &self.close_on;
+
+      literal_struct!(tokens, SplashConfig, url, min_display_ms, close_on)
+    }
+  }
+
   impl ToTokens for PatternKind {
     fn to_tokens(&self, tokens: &mut TokenStream) {
       let prefix = quote! { ::tauri::utils::config::PatternKind };
@@ -2345,11 +4748,20 @@ mod build {
       tokens.append_all(match self {
         Self::Brownfield => quote! { #prefix::Brownfield },
         #[cfg(not(feature = "isolation"))]
-        Self::Isolation { dir: _ } => quote! { #prefix::Brownfield },
+        Self::Isolation { .. } => quote! { #prefix::Brownfield },
         #[cfg(feature = "isolation")]
-        Self::Isolation { dir } => {
+        Self::Isolation {
+          dir,
+          response_headers,
+        } => {
           let dir = path_buf_lit(dir);
-          quote! { #prefix::Isolation { dir: #dir } }
+          let response_headers = map_lit(
+            quote! { ::std::collections::HashMap },
+            response_headers,
+            str_lit,
+            str_lit,
+          );
+          quote! { #prefix::Isolation { dir: #dir, response_headers: #response_headers } }
         }
       })
     }
@@ -2400,16 +4812,60 @@ mod build {
       let active = self.active;
       let pubkey = str_lit(&self.pubkey);
       let windows = &self.windows;
+      let create_artifacts = opt_lit(self.create_artifacts.as_ref());
+      let endpoint_strategy = &self.endpoint_strategy;
+
+      literal_struct!(
+        tokens,
+        UpdaterConfig,
+        active,
+        pubkey,
+        windows,
+        create_artifacts,
+        endpoint_strategy
+      );
+    }
+  }
+
+  impl ToTokens for Target {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+      let prefix = quote! { ::tauri::utils::platform::Target };
+
+      tokens.append_all(match self {
+        Self::MacOS => quote! { #prefix::MacOS },
+        Self::Windows => quote! { #prefix::Windows },
+        Self::Linux => quote! { #prefix::Linux },
+        Self::Android => quote! { #prefix::Android },
+        Self::Ios => quote! { #prefix::Ios },
+      })
+    }
+  }
+
+  impl ToTokens for EndpointStrategy {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+      let prefix = quote! { ::tauri::utils::config::EndpointStrategy };
 
-      literal_struct!(tokens, UpdaterConfig, active, pubkey, windows);
+      tokens.append_all(match self {
+        Self::Sequential => quote! { #prefix::Sequential },
+        Self::Random => quote! { #prefix::Random },
+        Self::Parallel => quote! { #prefix::Parallel },
+      })
     }
   }
 
   impl ToTokens for BundleConfig {
     fn to_tokens(&self, tokens: &mut TokenStream) {
       let identifier = str_lit(&self.identifier);
-      let publisher = quote!(None);
+      let publisher = opt_str_lit(self.publisher.as_ref());
       let icon = vec_lit(&self.icon, str_lit);
+      let mut platform_icons = self.platform_icons.iter().collect::<Vec<_>>();
+      platform_icons.sort_by_key(|(target, _)| target.to_string());
+      let platform_icons = map_lit(
+        quote! { ::std::collections::HashMap },
+        platform_icons,
+        identity,
+        |icons| vec_lit(icons, str_lit),
+      );
       let active = self.active;
       let targets = quote!(Default::default());
       let resources = quote!(None);
@@ -2418,6 +4874,7 @@ mod build {
       let file_associations = quote!(None);
       let short_description = quote!(None);
       let long_description = quote!(None);
+      let license_file = quote!(None);
       let appimage = quote!(Default::default());
       let deb = quote!(Default::default());
       let rpm = quote!(Default::default());
@@ -2436,6 +4893,7 @@ mod build {
         identifier,
         publisher,
         icon,
+        platform_icons,
         targets,
         resources,
         copyright,
@@ -2443,6 +4901,7 @@ mod build {
         file_associations,
         short_description,
         long_description,
+        license_file,
         appimage,
         deb,
         rpm,
@@ -2478,6 +4937,7 @@ mod build {
       let dev_path = &self.dev_path;
       let dist_dir = &self.dist_dir;
       let with_global_tauri = self.with_global_tauri;
+      let dev_server_live_reload = self.dev_server_live_reload;
       let runner = quote!(None);
       let before_dev_command = quote!(None);
       let before_build_command = quote!(None);
@@ -2491,6 +4951,7 @@ mod build {
         dev_path,
         dist_dir,
         with_global_tauri,
+        dev_server_live_reload,
         before_dev_command,
         before_build_command,
         before_bundle_command,
@@ -2514,7 +4975,13 @@ mod build {
   impl ToTokens for UpdaterWindowsConfig {
     fn to_tokens(&self, tokens: &mut TokenStream) {
       let install_mode = &self.install_mode;
-      literal_struct!(tokens, UpdaterWindowsConfig, install_mode);
+      let respect_install_scope = self.respect_install_scope;
+      literal_struct!(
+        tokens,
+        UpdaterWindowsConfig,
+        install_mode,
+        respect_install_scope
+      );
     }
   }
 
@@ -2547,7 +5014,7 @@ mod build {
         Self::DirectiveMap(list) => {
           let map = map_lit(
             quote! { ::std::collections::HashMap },
-            list,
+            sorted_directives(list),
             str_lit,
             identity,
           );
@@ -2598,6 +5065,8 @@ mod build {
       let freeze_prototype = self.freeze_prototype;
       let dangerous_disable_asset_csp_modification = &self.dangerous_disable_asset_csp_modification;
       let asset_protocol = &self.asset_protocol;
+      let csp_report_uri = opt_lit(self.csp_report_uri.as_ref().map(url_lit).as_ref());
+      let dangerous_allowed_url_schemes = vec_lit(&self.dangerous_allowed_url_schemes, str_lit);
 
       literal_struct!(
         tokens,
@@ -2606,7 +5075,9 @@ mod build {
         dev_csp,
         freeze_prototype,
         dangerous_disable_asset_csp_modification,
-        asset_protocol
+        asset_protocol,
+        csp_report_uri,
+        dangerous_allowed_url_schemes
       );
     }
   }
@@ -2632,6 +5103,22 @@ mod build {
     }
   }
 
+  impl ToTokens for VisibleOnAllWorkspaces {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+      let prefix = quote! { ::tauri::utils::config::VisibleOnAllWorkspaces };
+
+      tokens.append_all(match self {
+        Self::Flag(flag) => quote! { #prefix::Flag(#flag) },
+        Self::Options {
+          visible_on_all,
+          skip_transform_process_type,
+        } => {
+          quote! { #prefix::Options { visible_on_all: #visible_on_all, skip_transform_process_type: #skip_transform_process_type } }
+        }
+      });
+    }
+  }
+
   impl ToTokens for FsScope {
     fn to_tokens(&self, tokens: &mut TokenStream) {
       let prefix = quote! { ::tauri::utils::config::FsScope };
@@ -2641,11 +5128,12 @@ mod build {
           let allowed_paths = vec_lit(allow, path_buf_lit);
           quote! { #prefix::AllowedPaths(#allowed_paths) }
         }
-        Self::Scope { allow, deny , require_literal_leading_dot} => {
+        Self::Scope { allow, deny , require_literal_leading_dot, resolve_symlinks } => {
           let allow = vec_lit(allow, path_buf_lit);
           let deny = vec_lit(deny, path_buf_lit);
           let  require_literal_leading_dot = opt_lit(require_literal_leading_dot.as_ref());
-          quote! { #prefix::Scope { allow: #allow, deny: #deny, require_literal_leading_dot: #require_literal_leading_dot } }
+          let resolve_symlinks = opt_lit(resolve_symlinks.as_ref());
+          quote! { #prefix::Scope { allow: #allow, deny: #deny, require_literal_leading_dot: #require_literal_leading_dot, resolve_symlinks: #resolve_symlinks } }
         }
       });
     }
@@ -2654,7 +5142,13 @@ mod build {
   impl ToTokens for AssetProtocolConfig {
     fn to_tokens(&self, tokens: &mut TokenStream) {
       let scope = &self.scope;
-      tokens.append_all(quote! { ::tauri::utils::config::AssetProtocolConfig { scope: #scope, ..Default::default() } })
+      let mime_overrides = map_lit(
+        quote! { ::std::collections::HashMap },
+        &self.mime_overrides,
+        str_lit,
+        str_lit,
+      );
+      tokens.append_all(quote! { ::tauri::utils::config::AssetProtocolConfig { scope: #scope, mime_overrides: #mime_overrides, ..Default::default() } })
     }
   }
 
@@ -2666,6 +5160,10 @@ mod build {
       let security = &self.security;
       let tray_icon = opt_lit(self.tray_icon.as_ref());
       let macos_private_api = self.macos_private_api;
+      let default_user_agent = opt_str_lit(self.default_user_agent.as_ref());
+      let default_theme = opt_lit(self.default_theme.as_ref());
+      let minimum_webview_version = opt_str_lit(self.minimum_webview_version.as_ref());
+      let global_shortcuts = vec_lit(&self.global_shortcuts, identity);
 
       literal_struct!(
         tokens,
@@ -2675,14 +5173,27 @@ mod build {
         bundle,
         security,
         tray_icon,
-        macos_private_api
+        macos_private_api,
+        default_user_agent,
+        default_theme,
+        minimum_webview_version,
+        global_shortcuts
       );
     }
   }
 
-  impl ToTokens for PluginConfig {
+  impl ToTokens for GlobalShortcutConfig {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-      let config = map_lit(
+      let accelerator = str_lit(&self.accelerator);
+      let event = str_lit(&self.event);
+
+      literal_struct!(tokens, GlobalShortcutConfig, accelerator, event);
+    }
+  }
+
+  impl ToTokens for PluginConfig {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+      let config = map_lit(
         quote! { ::std::collections::HashMap },
         &self.0,
         str_lit,
@@ -2696,8 +5207,15 @@ mod build {
     fn to_tokens(&self, tokens: &mut TokenStream) {
       let product_name = opt_str_lit(self.product_name.as_ref());
       let version = opt_str_lit(self.version.as_ref());
+      let binary_name_override = opt_str_lit(self.binary_name_override.as_ref());
 
-      literal_struct!(tokens, PackageConfig, product_name, version);
+      literal_struct!(
+        tokens,
+        PackageConfig,
+        product_name,
+        version,
+        binary_name_override
+      );
     }
   }
 
@@ -2744,12 +5262,14 @@ mod test {
         identifier: String::from(""),
         publisher: None,
         icon: Vec::new(),
+        platform_icons: HashMap::new(),
         resources: None,
         copyright: None,
         category: None,
         file_associations: None,
         short_description: None,
         long_description: None,
+        license_file: None,
         appimage: Default::default(),
         deb: Default::default(),
         rpm: Default::default(),
@@ -2767,9 +5287,15 @@ mod test {
         freeze_prototype: false,
         dangerous_disable_asset_csp_modification: DisabledCspModificationKind::Flag(false),
         asset_protocol: AssetProtocolConfig::default(),
+        csp_report_uri: None,
+        dangerous_allowed_url_schemes: Vec::new(),
       },
       tray_icon: None,
       macos_private_api: false,
+      default_user_agent: None,
+      default_theme: None,
+      minimum_webview_version: None,
+      global_shortcuts: Vec::new(),
     };
 
     // create a build config
@@ -2784,6 +5310,7 @@ mod test {
       before_bundle_command: None,
       features: None,
       with_global_tauri: false,
+      dev_server_live_reload: true,
     };
 
     // test the configs
@@ -2798,4 +5325,1447 @@ mod test {
     );
     assert_eq!(d_windows, tauri.windows);
   }
+
+  #[test]
+  fn bundle_target_to_vec() {
+    let target = BundleTarget::List(vec![
+      BundleType::Updater,
+      BundleType::Msi,
+      BundleType::Nsis,
+      BundleType::Msi,
+      BundleType::Updater,
+    ]);
+    assert_eq!(
+      target.to_vec(),
+      vec![BundleType::Updater, BundleType::Msi, BundleType::Nsis]
+    );
+
+    assert_eq!(
+      BundleTarget::One(BundleType::Dmg).to_vec(),
+      vec![BundleType::Dmg]
+    );
+    assert!(BundleTarget::All.to_vec().is_empty());
+  }
+
+  #[test]
+  fn csp_directive_sources_remove() {
+    let mut inline =
+      CspDirectiveSources::Inline("'unsafe-inline' 'self' tauri: 'unsafe-inline'".into());
+    assert!(inline.remove("'unsafe-inline'"));
+    assert_eq!(inline, CspDirectiveSources::Inline("'self' tauri:".into()));
+    assert!(inline.remove("tauri:"));
+    assert_eq!(inline, CspDirectiveSources::Inline("'self'".into()));
+    assert!(!inline.remove("'unsafe-eval'"));
+    assert!(inline.remove("'self'"));
+    assert_eq!(inline, CspDirectiveSources::Inline("".into()));
+
+    let mut list = CspDirectiveSources::List(vec![
+      "'unsafe-inline'".into(),
+      "'self'".into(),
+      "tauri:".into(),
+    ]);
+    assert!(list.remove("'unsafe-inline'"));
+    assert!(list.remove("tauri:"));
+    assert!(!list.remove("tauri:"));
+    assert_eq!(list, CspDirectiveSources::List(vec!["'self'".into()]));
+
+    let sources = vec!["'self'".to_string(), "asset:".to_string()];
+    inline.replace(sources.clone());
+    assert_eq!(inline, CspDirectiveSources::Inline("'self' asset:".into()));
+    list.replace(sources.clone());
+    assert_eq!(list, CspDirectiveSources::List(sources));
+  }
+
+  #[test]
+  fn visible_on_all_workspaces() {
+    let parse = |value| serde_json::from_value::<VisibleOnAllWorkspaces>(value).unwrap();
+
+    assert_eq!(
+      parse(serde_json::json!(true)),
+      VisibleOnAllWorkspaces::Flag(true)
+    );
+    assert!(!parse(serde_json::json!(true)).full_screen_auxiliary());
+
+    let options = parse(serde_json::json!({ "visibleOnAll": true }));
+    assert!(options.visible_on_all());
+    assert!(options.full_screen_auxiliary());
+
+    let options = parse(serde_json::json!({
+      "visibleOnAll": true,
+      "skipTransformProcessType": true
+    }));
+    assert!(options.visible_on_all());
+    assert!(!options.full_screen_auxiliary());
+
+    let options = parse(serde_json::json!({ "visibleOnAll": false }));
+    assert!(!options.visible_on_all());
+    assert!(!options.full_screen_auxiliary());
+  }
+
+  #[test]
+  fn mime_overrides() {
+    let parse = |value| serde_json::from_value::<AssetProtocolConfig>(value);
+
+    let config = parse(serde_json::json!({
+      "mimeOverrides": { "wasm": "application/wasm", "txt": "text/plain; charset=utf-8" }
+    }))
+    .unwrap();
+    assert_eq!(config.mime_overrides["wasm"], "application/wasm");
+
+    assert!(parse(serde_json::json!({ "mimeOverrides": { "bin": "octet-stream" } })).is_err());
+    assert!(parse(serde_json::json!({ "mimeOverrides": { "bin": "application/" } })).is_err());
+
+    // extensions are normalized to the lowercased extension without its dot
+    let config = parse(serde_json::json!({
+      "mimeOverrides": { ".PNG": "image/png", "Glb": "model/gltf-binary" }
+    }))
+    .unwrap();
+    assert_eq!(config.mime_overrides["png"], "image/png");
+    assert_eq!(config.mime_overrides["glb"], "model/gltf-binary");
+
+    assert!(parse(serde_json::json!({ "mimeOverrides": { ".": "text/plain" } })).is_err());
+    assert!(
+      parse(serde_json::json!({ "mimeOverrides": { "tar.gz": "application/gzip" } })).is_err()
+    );
+    assert!(parse(serde_json::json!({
+      "mimeOverrides": { "wasm": "application/wasm", ".WASM": "application/octet-stream" }
+    }))
+    .is_err());
+  }
+
+  #[test]
+  fn all_features_for_target() {
+    for target in [Target::Android, Target::Ios] {
+      let features = TauriConfig::all_features_for(target);
+      assert!(!features.contains(&"tray-icon"));
+      assert!(!features.contains(&"global-shortcut"));
+      assert!(features.contains(&"isolation"));
+    }
+    assert_eq!(
+      TauriConfig::all_features_for(Target::MacOS),
+      TauriConfig::all_features()
+    );
+    assert!(TauriConfig::all_features_for(Target::Linux).contains(&"tray-icon"));
+  }
+
+  #[test]
+  fn window_warnings() {
+    let window = WindowConfig {
+      label: "main".into(),
+      resizable: false,
+      maximizable: Some(true),
+      ..Default::default()
+    };
+    let warnings = window.validate(Target::MacOS);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].severity, DiagnosticSeverity::Warning);
+    assert_eq!(warnings[0].field_path, "maximizable");
+    assert!(warnings[0].message.contains("`main`"));
+    // `maximizable` is not relevant on mobile
+    assert!(window.validate(Target::Ios).is_empty());
+
+    let window = WindowConfig {
+      label: "main".into(),
+      resizable: false,
+      maximizable: Some(false),
+      ..Default::default()
+    };
+    assert!(window.validate(Target::Linux).is_empty());
+
+    // the default `maximizable` value is not a contradiction
+    let window = WindowConfig {
+      label: "main".into(),
+      resizable: false,
+      ..Default::default()
+    };
+    assert!(window.validate(Target::MacOS).is_empty());
+
+    let window = WindowConfig {
+      label: "popup".into(),
+      closable: false,
+      ..Default::default()
+    };
+    assert!(window.validate(Target::Windows).is_empty());
+    for target in [Target::Android, Target::Ios] {
+      let warnings = window.validate(target);
+      assert_eq!(warnings.len(), 1);
+      assert_eq!(warnings[0].field_path, "closable");
+      assert!(warnings[0].message.contains("`popup`"));
+    }
+
+    let config = Config {
+      tauri: TauriConfig {
+        windows: vec![
+          WindowConfig {
+            label: "a".into(),
+            resizable: false,
+            maximizable: Some(true),
+            ..Default::default()
+          },
+          WindowConfig {
+            label: "b".into(),
+            resizable: false,
+            maximizable: Some(true),
+            ..Default::default()
+          },
+        ],
+        ..Default::default()
+      },
+      ..Default::default()
+    };
+    assert_eq!(config.validate(Target::Linux).len(), 2);
+    assert!(Config::default().validate(Target::Linux).is_empty());
+  }
+
+  #[test]
+  fn validate_reports_all_issues() {
+    let config = Config {
+      tauri: TauriConfig {
+        bundle: BundleConfig {
+          identifier: "com.tauri..my app".into(),
+          ..Default::default()
+        },
+        windows: vec![
+          WindowConfig {
+            label: "main".into(),
+            resizable: false,
+            maximizable: Some(true),
+            ..Default::default()
+          },
+          WindowConfig {
+            label: "main".into(),
+            window_effects: Some(WindowEffectsConfig {
+              effects: vec![WindowEffect::Mica],
+              state: None,
+              radius: None,
+              color: None,
+            }),
+            ..Default::default()
+          },
+        ],
+        ..Default::default()
+      },
+      ..Default::default()
+    };
+
+    let diagnostics = config.validate(Target::Windows);
+    let fields = diagnostics
+      .iter()
+      .map(|d| (d.severity, d.field_path.as_str()))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      fields,
+      vec![
+        (DiagnosticSeverity::Error, "tauri > bundle > identifier"),
+        (
+          DiagnosticSeverity::Warning,
+          "tauri > windows > 0 > maximizable"
+        ),
+        (DiagnosticSeverity::Error, "tauri > windows > 1 > label"),
+        (
+          DiagnosticSeverity::Warning,
+          "tauri > windows > 1 > windowEffects"
+        ),
+      ]
+    );
+  }
+
+  #[test]
+  fn window_theme() {
+    let theme = |value: &str| {
+      serde_json::from_value::<WindowConfig>(serde_json::json!({ "theme": value }))
+        .unwrap()
+        .theme
+    };
+    assert_eq!(theme("dark"), Some(crate::Theme::Dark));
+    assert_eq!(theme("light"), Some(crate::Theme::Light));
+    assert_eq!(theme("system"), Some(crate::Theme::System));
+    assert_eq!(crate::Theme::System.to_string(), "system");
+  }
+
+  #[test]
+  fn bundle_target_from_str() {
+    assert_eq!("all".parse::<BundleTarget>().unwrap(), BundleTarget::All);
+    assert_eq!(" ALL ".parse::<BundleTarget>().unwrap(), BundleTarget::All);
+    assert_eq!(
+      "deb, AppImage,".parse::<BundleTarget>().unwrap(),
+      BundleTarget::List(vec![BundleType::Deb, BundleType::AppImage])
+    );
+    assert_eq!(
+      "msi,exe".parse::<BundleTarget>().unwrap_err().to_string(),
+      "unknown bundle target 'exe'"
+    );
+    assert!(" , ".parse::<BundleTarget>().is_err());
+  }
+
+  #[test]
+  fn csp_report_uri() {
+    let security: SecurityConfig = serde_json::from_value(serde_json::json!({
+      "csp": "default-src 'self'; report-uri https://old.example.com",
+      "cspReportUri": "https://example.com/csp"
+    }))
+    .unwrap();
+
+    let csp: HashMap<String, CspDirectiveSources> = security
+      .with_csp_reporting(security.csp.clone().unwrap())
+      .into();
+    assert_eq!(
+      csp.get("report-uri"),
+      Some(&CspDirectiveSources::List(vec![
+        "https://example.com/csp".into()
+      ]))
+    );
+    assert_eq!(
+      csp.get("report-to"),
+      Some(&CspDirectiveSources::List(vec![CSP_REPORT_GROUP.into()]))
+    );
+    assert!(csp.contains_key("default-src"));
+    assert_eq!(
+      security.csp_reporting_endpoints().as_deref(),
+      Some("tauri-csp=\"https://example.com/csp\"")
+    );
+
+    let csp = Csp::Policy("default-src 'self'".into());
+    assert_eq!(
+      SecurityConfig::default().with_csp_reporting(csp.clone()),
+      csp
+    );
+
+    assert!(serde_json::from_value::<SecurityConfig>(
+      serde_json::json!({ "cspReportUri": "file:///tmp/csp" })
+    )
+    .is_err());
+  }
+
+  #[test]
+  fn mac_info_plist() {
+    let config: MacConfig = serde_json::from_value(serde_json::json!({
+      "infoPlist": {
+        "NSCameraUsageDescription": "Used to take your profile picture",
+        "CFBundleURLTypes": [{ "CFBundleURLSchemes": ["myapp"] }]
+      }
+    }))
+    .unwrap();
+    assert_eq!(
+      config.info_plist.get("NSCameraUsageDescription"),
+      Some(&serde_json::json!("Used to take your profile picture"))
+    );
+    assert_eq!(config.info_plist.len(), 2);
+    assert!(MacConfig::default().info_plist.is_empty());
+
+    for invalid in [
+      serde_json::json!({ "infoPlist": { "Invalid Key": true } }),
+      serde_json::json!({ "infoPlist": { "NSSomething": [null] } }),
+    ] {
+      assert!(serde_json::from_value::<MacConfig>(invalid).is_err());
+    }
+  }
+
+  #[test]
+  fn mobile_overrides() {
+    let bundle: BundleConfig = serde_json::from_value(serde_json::json!({
+      "identifier": "com.tauri.app",
+      "android": { "versionCode": 42, "packageName": "com.tauri.android" },
+      "iOS": { "buildNumber": "1.2.3" }
+    }))
+    .unwrap();
+    assert_eq!(bundle.identifier_for(Target::Android), "com.tauri.android");
+    assert_eq!(bundle.identifier_for(Target::Ios), "com.tauri.app");
+    assert_eq!(bundle.identifier_for(Target::Linux), "com.tauri.app");
+    assert_eq!(bundle.android.version_code_for(Some("1.0.0")), 42);
+    assert_eq!(bundle.ios.build_number.as_deref(), Some("1.2.3"));
+
+    let android = AndroidConfig::default();
+    assert_eq!(android.version_code_for(Some("1.2.3")), 1_002_003);
+    assert_eq!(android.version_code_for(Some("0.0.0")), 1);
+    assert_eq!(android.version_code_for(None), 1);
+
+    assert!(
+      serde_json::from_value::<AndroidConfig>(serde_json::json!({ "versionCode": 0 })).is_err()
+    );
+    for build_number in ["", "1.2.3.4", "1.a", "1..2"] {
+      assert!(serde_json::from_value::<IosConfig>(
+        serde_json::json!({ "buildNumber": build_number })
+      )
+      .is_err());
+    }
+  }
+
+  #[test]
+  fn resizable_axes() {
+    let window = |value: serde_json::Value| serde_json::from_value::<WindowConfig>(value).unwrap();
+
+    let config = window(serde_json::json!({}));
+    assert_eq!(config.resizable_axes(), (true, true));
+    assert_eq!(config.inner_size_constraints(), (None, None));
+
+    let config = window(serde_json::json!({ "resizable": false }));
+    assert_eq!(config.resizable_axes(), (false, false));
+    assert_eq!(config.inner_size_constraints(), (None, None));
+
+    let config = window(serde_json::json!({
+      "width": 800,
+      "height": 40,
+      "minWidth": 200,
+      "resizableVertical": false
+    }));
+    assert_eq!(config.resizable_axes(), (true, false));
+    assert_eq!(
+      config.inner_size_constraints(),
+      (Some((200., 40.)), Some((f64::MAX, 40.)))
+    );
+
+    let config = window(serde_json::json!({
+      "width": 300,
+      "resizable": false,
+      "resizableVertical": true
+    }));
+    assert_eq!(config.resizable_axes(), (false, true));
+    assert_eq!(
+      config.inner_size_constraints(),
+      (Some((300., 0.)), Some((300., f64::MAX)))
+    );
+
+    let config = window(serde_json::json!({ "minHeight": 100, "maxHeight": 100 }));
+    assert_eq!(
+      config.inner_size_constraints(),
+      (Some((0., 100.)), Some((f64::MAX, 100.)))
+    );
+  }
+
+  #[test]
+  fn default_user_agent() {
+    let config: TauriConfig = serde_json::from_value(serde_json::json!({
+      "defaultUserAgent": "MyApp/1.0",
+      "windows": [
+        { "label": "main" },
+        { "label": "custom", "userAgent": "Custom/2.0" }
+      ]
+    }))
+    .unwrap();
+
+    assert_eq!(config.user_agent_for(&config.windows[0]), Some("MyApp/1.0"));
+    assert_eq!(
+      config.user_agent_for(&config.windows[1]),
+      Some("Custom/2.0")
+    );
+    assert_eq!(
+      TauriConfig::default().user_agent_for(&WindowConfig::default()),
+      None
+    );
+  }
+
+  #[test]
+  fn mac_exclude_from_signing() {
+    let config: MacConfig = serde_json::from_value(serde_json::json!({
+      "excludeFromSigning": ["Resources/helpers/*.app", "Frameworks/Prebuilt.framework"]
+    }))
+    .unwrap();
+    assert_eq!(config.exclude_from_signing.len(), 2);
+    assert!(MacConfig::default().exclude_from_signing.is_empty());
+
+    for pattern in [
+      "/Applications/Helper.app",
+      "../Helper.app",
+      "Resources/[helper",
+    ] {
+      assert!(
+        serde_json::from_value::<MacConfig>(serde_json::json!({
+          "excludeFromSigning": [pattern]
+        }))
+        .is_err(),
+        "{pattern} should be rejected"
+      );
+    }
+  }
+
+  #[test]
+  fn csp_directive_sources_normalized() {
+    let mut inline = CspDirectiveSources::Inline("  'self'   tauri: ".into());
+    inline.push("'self'");
+    inline.extend(vec!["".into(), " asset: ".into()]);
+    assert_eq!(
+      inline.normalized(),
+      vec!["'self'".to_string(), "tauri:".into(), "asset:".into()]
+    );
+    assert!(inline.contains("'self'"));
+    assert!(!inline.contains("self"));
+
+    let list = CspDirectiveSources::List(vec!["".into(), "'self'  blob:".into(), "blob:".into()]);
+    assert_eq!(
+      Vec::<String>::from(list),
+      vec!["'self'".to_string(), "blob:".into()]
+    );
+
+    let mut empty = CspDirectiveSources::Inline("'self'".into());
+    empty.remove("'self'");
+    empty.push("https:");
+    assert_eq!(empty.normalized(), vec!["https:".to_string()]);
+
+    let csp = Csp::DirectiveMap(HashMap::from([(
+      "default-src".to_string(),
+      CspDirectiveSources::Inline(" 'self'  'self' ".into()),
+    )]));
+    assert_eq!(csp.to_string(), "default-src 'self'");
+    let csp = Csp::DirectiveMap(HashMap::from([(
+      "upgrade-insecure-requests".to_string(),
+      CspDirectiveSources::Inline(" ".into()),
+    )]));
+    assert_eq!(csp.to_string(), "upgrade-insecure-requests");
+  }
+
+  #[test]
+  fn accept_first_mouse_diagnostic() {
+    let window = WindowConfig {
+      accept_first_mouse: true,
+      ..Default::default()
+    };
+    assert!(window.validate(Target::MacOS).is_empty());
+
+    let diagnostics = window.validate(Target::Windows);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Info);
+    assert_eq!(diagnostics[0].field_path, "acceptFirstMouse");
+    assert!(WindowConfig::default().validate(Target::Linux).is_empty());
+  }
+
+  #[test]
+  fn registry_keys() {
+    let config: NsisConfig = serde_json::from_value(serde_json::json!({
+      "registryKeys": [
+        { "root": "HKCU", "key": "Software\\Tauri\\App", "name": "Theme", "value": "dark" },
+        { "root": "HKLM", "key": "Software\\Tauri\\App", "value": "1", "valueType": "REG_DWORD" }
+      ]
+    }))
+    .unwrap();
+    assert_eq!(config.registry_keys.len(), 2);
+    assert_eq!(config.registry_keys[0].root, RegistryRoot::CurrentUser);
+    assert_eq!(
+      config.registry_keys[0].value_type,
+      RegistryValueType::String
+    );
+    assert_eq!(config.registry_keys[1].name, None);
+    assert_eq!(config.registry_keys[1].value_type, RegistryValueType::Dword);
+    assert!(WixConfig::default().registry_keys.is_empty());
+
+    for entry in [
+      serde_json::json!({ "root": "HKEY_LOCAL_MACHINE", "key": "Software\\App", "value": "" }),
+      serde_json::json!({ "root": "HKCU", "key": "Software\\App", "value": "", "valueType": "REG_NONE" }),
+      serde_json::json!({ "root": "HKCU", "key": "\\Software\\App", "value": "" }),
+      serde_json::json!({ "root": "HKCU", "key": "Software\\App", "value": "-1", "valueType": "REG_DWORD" }),
+      serde_json::json!({ "root": "HKCU", "key": "Software\\App", "value": "ABC", "valueType": "REG_BINARY" }),
+    ] {
+      assert!(
+        serde_json::from_value::<WixConfig>(serde_json::json!({ "registryKeys": [entry] }))
+          .is_err(),
+        "{entry} should be rejected"
+      );
+    }
+  }
+
+  #[test]
+  fn updater_endpoint_strategy() {
+    assert_eq!(
+      UpdaterConfig::default().endpoint_strategy,
+      EndpointStrategy::Sequential
+    );
+    let config: UpdaterConfig = serde_json::from_value(serde_json::json!({})).unwrap();
+    assert_eq!(config.endpoint_strategy, EndpointStrategy::Sequential);
+
+    for (strategy, name) in [
+      (EndpointStrategy::Sequential, "sequential"),
+      (EndpointStrategy::Random, "random"),
+      (EndpointStrategy::Parallel, "parallel"),
+    ] {
+      assert_eq!(serde_json::to_value(strategy).unwrap(), name);
+      let config: UpdaterConfig = serde_json::from_value(serde_json::json!({
+        "endpointStrategy": name
+      }))
+      .unwrap();
+      assert_eq!(config.endpoint_strategy, strategy);
+      assert_eq!(
+        serde_json::from_value::<UpdaterConfig>(serde_json::to_value(&config).unwrap()).unwrap(),
+        config
+      );
+    }
+
+    assert!(serde_json::from_value::<UpdaterConfig>(serde_json::json!({
+      "endpointStrategy": "roundRobin"
+    }))
+    .is_err());
+  }
+
+  #[test]
+  fn package_binary_name() {
+    let package: PackageConfig = serde_json::from_value(serde_json::json!({
+      "productName": "My Cool App"
+    }))
+    .unwrap();
+    #[cfg(target_os = "linux")]
+    assert_eq!(package.binary_name().as_deref(), Some("my-cool-app"));
+    #[cfg(not(target_os = "linux"))]
+    assert_eq!(package.binary_name().as_deref(), Some("My Cool App"));
+
+    let package: PackageConfig = serde_json::from_value(serde_json::json!({
+      "productName": "My Cool App",
+      "binaryNameOverride": "mycoolapp"
+    }))
+    .unwrap();
+    assert_eq!(package.product_name.as_deref(), Some("My Cool App"));
+    assert_eq!(package.binary_name().as_deref(), Some("mycoolapp"));
+
+    for name in ["my cool app", "bin/app", "-app", ".app", ""] {
+      assert!(
+        serde_json::from_value::<PackageConfig>(serde_json::json!({
+          "binaryNameOverride": name
+        }))
+        .is_err(),
+        "{name} should be rejected"
+      );
+    }
+  }
+
+  #[test]
+  fn transparent_requires_macos_private_api() {
+    let mut config = Config {
+      tauri: TauriConfig {
+        windows: vec![WindowConfig {
+          transparent: true,
+          ..Default::default()
+        }],
+        ..Default::default()
+      },
+      ..Default::default()
+    };
+
+    let diagnostics = config.validate(Target::MacOS);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Error);
+    assert_eq!(
+      diagnostics[0].field_path,
+      "tauri > windows > 0 > transparent"
+    );
+    assert!(config.validate(Target::Windows).is_empty());
+
+    config.tauri.macos_private_api = true;
+    assert!(config.validate(Target::MacOS).is_empty());
+  }
+
+  #[test]
+  fn window_splash() {
+    let window: WindowConfig = serde_json::from_value(serde_json::json!({
+      "splash": { "url": "splash.html" }
+    }))
+    .unwrap();
+    let splash = window.splash.unwrap();
+    assert_eq!(splash.url, WebviewUrl::App("splash.html".into()));
+    assert_eq!(splash.min_display_ms, 0);
+    assert_eq!(splash.close_on, CloseTrigger::PageLoad);
+
+    let splash: SplashConfig = serde_json::from_value(serde_json::json!({
+      "url": "splash.html",
+      "minDisplayMs": 500,
+      "closeOn": { "event": "app-ready" }
+    }))
+    .unwrap();
+    assert_eq!(splash.min_display_ms, 500);
+    assert_eq!(splash.close_on, CloseTrigger::Event("app-ready".into()));
+
+    let close_on: CloseTrigger = serde_json::from_value(serde_json::json!("timeout")).unwrap();
+    assert_eq!(close_on, CloseTrigger::Timeout);
+  }
+
+  #[test]
+  fn nsis_minimum_windows_version() {
+    let nsis: NsisConfig = serde_json::from_value(serde_json::json!({
+      "minimumWindowsVersion": "10.0.17763"
+    }))
+    .unwrap();
+    assert_eq!(nsis.minimum_windows_version.as_deref(), Some("10.0.17763"));
+    assert_eq!(NsisConfig::default().minimum_windows_version, None);
+
+    for version in ["10", "10.0.17763.1", "10.x", "10..0", ""] {
+      let error = serde_json::from_value::<NsisConfig>(serde_json::json!({
+        "minimumWindowsVersion": version
+      }))
+      .unwrap_err();
+      assert!(error
+        .to_string()
+        .contains("invalid minimum Windows version"));
+    }
+  }
+
+  #[test]
+  fn mac_notarization() {
+    let config: MacConfig = serde_json::from_value(serde_json::json!({
+      "notarization": { "type": "keychainProfile", "profile": "tauri-notarize" }
+    }))
+    .unwrap();
+    assert_eq!(
+      config.notarization,
+      Some(NotarizationConfig::KeychainProfile {
+        profile: "tauri-notarize".into(),
+        keychain: None
+      })
+    );
+
+    let config: MacConfig = serde_json::from_value(serde_json::json!({
+      "notarization": { "type": "appleId", "passwordEnv": "CI_APPLE_PASSWORD" }
+    }))
+    .unwrap();
+    assert_eq!(
+      config.notarization,
+      Some(NotarizationConfig::AppleId {
+        apple_id_env: "APPLE_ID".into(),
+        password_env: "CI_APPLE_PASSWORD".into(),
+        team_id_env: "APPLE_TEAM_ID".into()
+      })
+    );
+    assert_eq!(MacConfig::default().notarization, None);
+  }
+
+  #[test]
+  fn csp_directive_order() {
+    let directives = [
+      ("script-src", "'self' 'unsafe-eval'"),
+      ("img-src", "'self' asset:"),
+      ("default-src", "'self'"),
+      ("connect-src", "ipc:"),
+    ];
+    let forward = Csp::DirectiveMap(
+      directives
+        .iter()
+        .map(|(d, s)| (d.to_string(), CspDirectiveSources::Inline(s.to_string())))
+        .collect(),
+    );
+    let mut reverse = HashMap::new();
+    for (directive, sources) in directives.iter().rev() {
+      reverse.insert(
+        directive.to_string(),
+        CspDirectiveSources::List(sources.split(' ').map(Into::into).collect()),
+      );
+    }
+    let reverse = Csp::DirectiveMap(reverse);
+
+    let expected = "default-src 'self'; connect-src ipc:; img-src 'self' asset:; script-src 'self' 'unsafe-eval'";
+    assert_eq!(forward.to_string(), expected);
+    assert_eq!(reverse.to_string(), expected);
+    assert_eq!(
+      Csp::DirectiveMap(Csp::Policy(expected.into()).into()).to_string(),
+      expected
+    );
+  }
+
+  #[test]
+  fn titlebar_height_diagnostic() {
+    let window = WindowConfig {
+      title_bar_style: TitleBarStyle::Overlay,
+      titlebar_height: Some(52.),
+      ..Default::default()
+    };
+    assert!(window.validate(Target::MacOS).is_empty());
+
+    let diagnostics = window.validate(Target::Windows);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Info);
+
+    let diagnostics = WindowConfig {
+      titlebar_height: Some(52.),
+      ..Default::default()
+    }
+    .validate(Target::MacOS);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Warning);
+    assert_eq!(diagnostics[0].field_path, "titlebarHeight");
+
+    for titlebar_height in [0., f64::NAN, f64::INFINITY] {
+      let diagnostics = WindowConfig {
+        titlebar_height: Some(titlebar_height),
+        ..window.clone()
+      }
+      .validate(Target::MacOS);
+      assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Error);
+    }
+  }
+
+  #[test]
+  fn fullscreen_diagnostics() {
+    let window = WindowConfig {
+      fullscreen: true,
+      maximized: true,
+      ..Default::default()
+    };
+    let diagnostics = window.validate(Target::Linux);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Warning);
+    assert_eq!(diagnostics[0].field_path, "maximized");
+
+    let diagnostics = WindowConfig {
+      fullscreen: true,
+      x: Some(10.),
+      width: 1024.,
+      ..Default::default()
+    }
+    .validate(Target::Linux);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Warning);
+    assert_eq!(diagnostics[0].field_path, "fullscreen");
+    assert!(diagnostics[0].message.contains("`x`, `width`"));
+
+    assert!(WindowConfig {
+      x: Some(10.),
+      maximized: true,
+      ..Default::default()
+    }
+    .validate(Target::Linux)
+    .is_empty());
+  }
+
+  #[test]
+  fn sign_external_binaries_defaults() {
+    let mac: MacConfig = serde_json::from_value(serde_json::json!({})).unwrap();
+    assert!(mac.sign_external_binaries);
+    let windows: WindowsConfig = serde_json::from_value(serde_json::json!({})).unwrap();
+    assert!(windows.sign_external_binaries);
+
+    let mac: MacConfig =
+      serde_json::from_value(serde_json::json!({ "signExternalBinaries": false })).unwrap();
+    assert!(!mac.sign_external_binaries);
+    let windows: WindowsConfig =
+      serde_json::from_value(serde_json::json!({ "sign-external-binaries": false })).unwrap();
+    assert!(!windows.sign_external_binaries);
+  }
+
+  #[test]
+  fn webview_url_join() {
+    let app = WebviewUrl::App("settings".into());
+    assert_eq!(
+      app.join("users/john").unwrap(),
+      WebviewUrl::App(["settings", "users", "john"].iter().collect())
+    );
+    assert_eq!(
+      app.join("/users//john/").unwrap(),
+      WebviewUrl::App(["settings", "users", "john"].iter().collect())
+    );
+    assert_eq!(
+      app.join("./users\\john").unwrap(),
+      WebviewUrl::App(["settings", "users", "john"].iter().collect())
+    );
+    assert_eq!(
+      app.join("users?tab=profile").unwrap(),
+      WebviewUrl::App(["settings", "users?tab=profile"].iter().collect())
+    );
+    assert!(matches!(
+      app.join("users/../../secret"),
+      Err(crate::Error::WebviewUrlEscape(_))
+    ));
+    #[cfg(windows)]
+    for path in ["C:", "C:secret", "users/D:/secret"] {
+      assert!(matches!(
+        app.join(path),
+        Err(crate::Error::WebviewUrlEscape(_))
+      ));
+    }
+
+    let external = |url: &str| WebviewUrl::External(url.parse().unwrap());
+    assert_eq!(
+      external("https://tauri.app/docs/").join("guides").unwrap(),
+      external("https://tauri.app/docs/guides")
+    );
+    assert_eq!(
+      external("https://tauri.app/docs").join("guides").unwrap(),
+      external("https://tauri.app/guides")
+    );
+    assert_eq!(
+      external("https://tauri.app/docs/?lang=en")
+        .join("guides?tab=1")
+        .unwrap(),
+      external("https://tauri.app/docs/guides?tab=1")
+    );
+    assert_eq!(
+      external("https://tauri.app/docs/")
+        .join("?lang=en")
+        .unwrap(),
+      external("https://tauri.app/docs/?lang=en")
+    );
+    assert_eq!(
+      external("https://tauri.app/docs/guides/")
+        .join("../blog")
+        .unwrap(),
+      external("https://tauri.app/docs/blog")
+    );
+    assert_eq!(
+      external("file:///app/index.html")
+        .join("about.html")
+        .unwrap(),
+      external("file:///app/about.html")
+    );
+    assert!(matches!(
+      external("https://tauri.app/docs/").join("https://example.com"),
+      Err(crate::Error::WebviewUrlEscape(_))
+    ));
+    assert!(matches!(
+      external("https://tauri.app/docs/").join("//example.com/docs"),
+      Err(crate::Error::WebviewUrlEscape(_))
+    ));
+  }
+
+  #[test]
+  fn window_effects_target_diagnostic() {
+    let window = WindowConfig {
+      transparent: true,
+      window_effects: Some(WindowEffectsConfig {
+        effects: vec![WindowEffect::Mica, WindowEffect::Sidebar],
+        ..Default::default()
+      }),
+      ..Default::default()
+    };
+
+    for (target, ignored) in [(Target::Windows, "`Sidebar`"), (Target::MacOS, "`Mica`")] {
+      let diagnostics = window.validate(target);
+      assert_eq!(diagnostics.len(), 1);
+      assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Info);
+      assert!(diagnostics[0].message.contains(ignored));
+    }
+
+    assert_eq!(window.validate(Target::Linux).len(), 2);
+  }
+
+  #[test]
+  fn plugin_config_get_as() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Endpoint {
+      url: String,
+      timeout_secs: u64,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct UpdaterConfig {
+      active: bool,
+      endpoints: Vec<Endpoint>,
+    }
+
+    let config: PluginConfig = serde_json::from_value(serde_json::json!({
+      "updater": {
+        "active": true,
+        "endpoints": [{ "url": "https://tauri.app/update", "timeoutSecs": 30 }]
+      },
+      "shell": { "open": true }
+    }))
+    .unwrap();
+
+    assert_eq!(
+      config.get_as::<UpdaterConfig>("updater").unwrap(),
+      Some(UpdaterConfig {
+        active: true,
+        endpoints: vec![Endpoint {
+          url: "https://tauri.app/update".into(),
+          timeout_secs: 30,
+        }],
+      })
+    );
+    assert!(config.get_as::<UpdaterConfig>("shell").is_err());
+    assert_eq!(config.get_as::<UpdaterConfig>("fs").unwrap(), None);
+  }
+
+  #[test]
+  fn window_show_when() {
+    let window: WindowConfig = serde_json::from_value(serde_json::json!({
+      "visible": false,
+      "showWhen": "pageLoad"
+    }))
+    .unwrap();
+    assert_eq!(window.show_when, Some(ShowTrigger::PageLoad));
+
+    let window: WindowConfig = serde_json::from_value(serde_json::json!({
+      "visible": false,
+      "show-when": { "event": "app-ready" }
+    }))
+    .unwrap();
+    assert_eq!(
+      window.show_when,
+      Some(ShowTrigger::Event("app-ready".into()))
+    );
+
+    assert_eq!(WindowConfig::default().show_when, None);
+    assert!(window.validate(Target::Linux).is_empty());
+
+    let window = WindowConfig {
+      show_when: Some(ShowTrigger::Event("app ready".into())),
+      ..Default::default()
+    };
+    let diagnostics = window.validate(Target::Linux);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Error);
+    assert_eq!(diagnostics[0].field_path, "showWhen");
+  }
+
+  #[test]
+  fn bundle_type_extension() {
+    for bundle_type in BundleType::ALL {
+      assert_eq!(
+        BundleType::from_extension(bundle_type.extension()).as_ref(),
+        Some(bundle_type)
+      );
+    }
+
+    assert_eq!(BundleType::Nsis.extension(), "exe");
+    assert_eq!(BundleType::App.extension(), "app");
+    assert_eq!(BundleType::from_extension("DEB"), Some(BundleType::Deb));
+    assert_eq!(
+      BundleType::from_extension("appimage"),
+      Some(BundleType::AppImage)
+    );
+    assert_eq!(BundleType::from_extension(".Msi"), Some(BundleType::Msi));
+    assert_eq!(BundleType::from_extension("zip"), None);
+    assert_eq!(BundleType::from_extension(""), None);
+  }
+
+  #[test]
+  fn relaxed_dev_csp() {
+    let origin: Url = "http://localhost:1430/index.html".parse().unwrap();
+    let csp = Csp::Policy("default-src 'self'; script-src 'self'".into());
+    let security = SecurityConfig {
+      csp: Some(csp.clone()),
+      ..Default::default()
+    };
+
+    let relaxed: HashMap<String, CspDirectiveSources> =
+      security.relaxed_dev_csp(&origin).unwrap().into();
+    assert_eq!(
+      relaxed["script-src"].normalized(),
+      vec!["'self'", "http://localhost:1430"]
+    );
+    assert_eq!(
+      relaxed["connect-src"].normalized(),
+      vec!["'self'", "http://localhost:1430", "ws://localhost:1430"]
+    );
+    assert_eq!(relaxed["default-src"].normalized(), vec!["'self'"]);
+    assert_eq!(security.csp, Some(csp.clone()));
+
+    let security = SecurityConfig {
+      csp: Some(csp.clone()),
+      dev_csp: Some(Csp::Policy("connect-src https://api.example.com".into())),
+      ..Default::default()
+    };
+    let relaxed: HashMap<String, CspDirectiveSources> =
+      security.relaxed_dev_csp(&origin).unwrap().into();
+    assert_eq!(
+      relaxed["connect-src"].normalized(),
+      vec![
+        "https://api.example.com",
+        "http://localhost:1430",
+        "ws://localhost:1430"
+      ]
+    );
+    assert!(!relaxed.contains_key("script-src"));
+    assert_eq!(security.csp, Some(csp));
+
+    assert!(SecurityConfig::default().relaxed_dev_csp(&origin).is_none());
+  }
+
+  #[test]
+  fn platform_icons() {
+    let bundle: BundleConfig = serde_json::from_value(serde_json::json!({
+      "identifier": "com.tauri.app",
+      "icon": ["icons/icon.png", "icons/icon.ico", "icons/icon.icns"],
+      "platformIcons": {
+        "windows": ["icons/squared.ico"],
+        "macOS": ["icons/rounded.icns", "icons/rounded.png"]
+      }
+    }))
+    .unwrap();
+
+    assert_eq!(bundle.icons_for(Target::Windows), ["icons/squared.ico"]);
+    assert_eq!(
+      bundle.icons_for(Target::MacOS),
+      ["icons/rounded.icns", "icons/rounded.png"]
+    );
+    assert_eq!(bundle.icons_for(Target::Linux), bundle.icon.as_slice());
+
+    let mut config = Config::default();
+    config.tauri.bundle = bundle;
+    assert!(config.validate(Target::Windows).is_empty());
+
+    config
+      .tauri
+      .bundle
+      .platform_icons
+      .insert(Target::Linux, vec!["icons/icon.ico".into()]);
+    let diagnostics = config.validate(Target::Windows);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+      diagnostics[0].field_path,
+      "tauri > bundle > platformIcons > linux > 0"
+    );
+  }
+
+  #[test]
+  fn minimum_webview_version() {
+    for version in ["", "119.", "119.0.x", "v119", "1.2.3.4.5", "119 .0"] {
+      let error = serde_json::from_value::<TauriConfig>(serde_json::json!({
+        "minimumWebviewVersion": version
+      }))
+      .unwrap_err();
+      assert!(
+        error
+          .to_string()
+          .contains("invalid minimum webview version"),
+        "{version}: {error}"
+      );
+    }
+
+    let config: TauriConfig = serde_json::from_value(serde_json::json!({
+      "minimumWebviewVersion": "110.0.1587"
+    }))
+    .unwrap();
+    assert!(config.is_webview_version_supported("110.0.1587.41"));
+    assert!(config.is_webview_version_supported("111"));
+    assert!(config.is_webview_version_supported("unknown"));
+    assert!(!config.is_webview_version_supported("110.0.1500.12"));
+    assert!(!config.is_webview_version_supported("109.0.1587-beta"));
+    assert!(TauriConfig::default().is_webview_version_supported("1.0"));
+  }
+
+  #[test]
+  fn updater_install_scope() {
+    let config = UpdaterWindowsConfig::default();
+    assert!(config.respect_install_scope);
+    assert_eq!(
+      serde_json::from_value::<UpdaterWindowsConfig>(serde_json::json!({})).unwrap(),
+      config
+    );
+
+    let config: UpdaterWindowsConfig = serde_json::from_value(serde_json::json!({
+      "installMode": "quiet",
+      "respectInstallScope": false
+    }))
+    .unwrap();
+    assert!(!config.respect_install_scope);
+    assert_eq!(
+      serde_json::from_value::<UpdaterWindowsConfig>(serde_json::to_value(&config).unwrap())
+        .unwrap(),
+      config
+    );
+    assert!(!config.requires_elevation(WindowsInstallScope::PerMachine, false));
+
+    let config = UpdaterWindowsConfig::default();
+    assert!(config.requires_elevation(WindowsInstallScope::PerMachine, false));
+    assert!(!config.requires_elevation(WindowsInstallScope::PerMachine, true));
+    assert!(!config.requires_elevation(WindowsInstallScope::CurrentUser, false));
+
+    for (scope, name) in [
+      (WindowsInstallScope::CurrentUser, "currentUser"),
+      (WindowsInstallScope::PerMachine, "perMachine"),
+    ] {
+      assert_eq!(serde_json::to_value(scope).unwrap(), name);
+      assert_eq!(
+        serde_json::from_value::<WindowsInstallScope>(serde_json::json!(name)).unwrap(),
+        scope
+      );
+    }
+
+    let mut bundle = BundleConfig {
+      identifier: "com.tauri.app".into(),
+      ..Default::default()
+    };
+    assert_eq!(bundle.manufacturer(), "tauri");
+    bundle.publisher = Some("Tauri Programme".into());
+    assert_eq!(bundle.manufacturer(), "Tauri Programme");
+  }
+
+  #[test]
+  fn csp_wildcard_subsumption() {
+    let wildcard = CspDirectiveSources::Inline("'self' * https://x.com".into());
+    assert!(wildcard.is_subsumed_by_wildcard());
+    assert_eq!(
+      wildcard.subsumed_sources(),
+      vec!["https://x.com".to_string()]
+    );
+
+    let https = CspDirectiveSources::List(vec![
+      "https:".into(),
+      "https://x.com".into(),
+      "http://y.com".into(),
+    ]);
+    assert_eq!(https.subsumed_sources(), vec!["https://x.com".to_string()]);
+
+    let specific = CspDirectiveSources::List(vec![
+      "'self'".into(),
+      "https://x.com".into(),
+      "y.com".into(),
+    ]);
+    assert!(!specific.is_subsumed_by_wildcard());
+
+    // `*` does not allow `data:`, custom schemes or nonces
+    let schemes = CspDirectiveSources::Inline("* data: asset://localhost 'nonce-1'".into());
+    assert!(!schemes.is_subsumed_by_wildcard());
+
+    let config = Config {
+      tauri: TauriConfig {
+        security: SecurityConfig {
+          csp: Some(Csp::Policy(
+            "default-src 'self'; connect-src * https://x.com".into(),
+          )),
+          ..Default::default()
+        },
+        ..Default::default()
+      },
+      ..Default::default()
+    };
+    let diagnostics = config.validate(Target::Linux);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Warning);
+    assert_eq!(
+      diagnostics[0].field_path,
+      "tauri > security > csp > connect-src"
+    );
+    assert!(diagnostics[0].message.contains("https://x.com"));
+  }
+
+  #[test]
+  fn window_monitor() {
+    let config: WindowConfig = serde_json::from_value(serde_json::json!({
+      "monitor": { "index": 1 },
+      "center": true
+    }))
+    .unwrap();
+    assert_eq!(config.monitor, Some(MonitorSelector::Index(1)));
+
+    let config: WindowConfig =
+      serde_json::from_value(serde_json::json!({ "monitor": { "name": "DP-2" } })).unwrap();
+    assert_eq!(config.monitor, Some(MonitorSelector::Name("DP-2".into())));
+
+    let config: WindowConfig =
+      serde_json::from_value(serde_json::json!({ "monitor": "cursor" })).unwrap();
+    assert_eq!(config.monitor, Some(MonitorSelector::Cursor));
+
+    assert!(
+      serde_json::from_value::<WindowConfig>(serde_json::json!({ "monitor": "secondary" }))
+        .is_err()
+    );
+  }
+
+  #[test]
+  fn external_url_schemes() {
+    let config = |url: &str, allowed: &[&str]| Config {
+      tauri: TauriConfig {
+        windows: vec![WindowConfig {
+          url: serde_json::from_value(serde_json::json!(url)).unwrap(),
+          ..Default::default()
+        }],
+        security: SecurityConfig {
+          dangerous_allowed_url_schemes: allowed.iter().map(|s| s.to_string()).collect(),
+          ..Default::default()
+        },
+        ..Default::default()
+      },
+      ..Default::default()
+    };
+
+    assert!(config("https://tauri.app", &[])
+      .validate(Target::Linux)
+      .is_empty());
+    assert!(config("index.html", &[]).validate(Target::Linux).is_empty());
+
+    let diagnostics = config("javascript:alert(1)", &[]).validate(Target::Linux);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Error);
+    assert_eq!(diagnostics[0].field_path, "tauri > windows > 0 > url");
+    assert!(diagnostics[0].message.contains("javascript:alert(1)"));
+
+    assert!(config("myapp://localhost/page", &["myapp"])
+      .validate(Target::Linux)
+      .is_empty());
+  }
+
+  #[test]
+  fn hook_command_list() {
+    let hook: HookCommand = serde_json::from_value(serde_json::json!([
+      "npm run icons",
+      { "script": "npm run optimize", "continueOnError": true },
+      { "script": "npm run licenses", "cwd": "scripts" }
+    ]))
+    .unwrap();
+    assert_eq!(
+      hook,
+      HookCommand::List(vec![
+        HookCommand::Script("npm run icons".into()),
+        HookCommand::ScriptWithOptions {
+          script: "npm run optimize".into(),
+          cwd: None,
+          continue_on_error: true,
+        },
+        HookCommand::ScriptWithOptions {
+          script: "npm run licenses".into(),
+          cwd: Some("scripts".into()),
+          continue_on_error: false,
+        },
+      ])
+    );
+  }
+
+  #[test]
+  fn navigation_allowlist() {
+    let window: WindowConfig = serde_json::from_value(serde_json::json!({
+      "navigationAllowlist": ["https://*.tauri.app", "https://github.com/tauri-apps/*"]
+    }))
+    .unwrap();
+    let allowed = |url: &str| window.is_navigation_allowed(&url.parse().unwrap());
+    assert!(allowed("https://v2.tauri.app/start/"));
+    assert!(allowed("https://github.com/tauri-apps/tauri"));
+    assert!(!allowed("https://github.com/attacker/repo"));
+    assert!(!allowed("http://v2.tauri.app"));
+    assert!(!allowed("https://tauri.app.attacker.com"));
+    assert!(!allowed("https://attacker.com/x.tauri.app"));
+    assert!(!allowed("https://attacker.com/?.tauri.app"));
+    assert!(!allowed("https://attacker.com/#.tauri.app"));
+    assert!(!allowed("https://github.com.attacker.com/tauri-apps/tauri"));
+    assert!(!allowed("https://github.com/tauri-apps/tauri/issues"));
+
+    let permissive = WindowConfig {
+      navigation_allowlist: Some(Vec::new()),
+      ..Default::default()
+    };
+    assert!(permissive.is_navigation_allowed(&"https://attacker.com".parse().unwrap()));
+    assert!(WindowConfig::default().is_navigation_allowed(&"https://attacker.com".parse().unwrap()));
+
+    let invalid = WindowConfig {
+      navigation_allowlist: Some(vec!["https://[tauri.app".into()]),
+      ..Default::default()
+    };
+    let diagnostics = invalid.validate(Target::Linux);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Error);
+    assert_eq!(diagnostics[0].field_path, "navigationAllowlist");
+  }
+
+  #[test]
+  fn default_theme() {
+    let config: TauriConfig = serde_json::from_value(serde_json::json!({
+      "defaultTheme": "dark",
+      "windows": [
+        { "label": "main" },
+        { "label": "light", "theme": "light" },
+        { "label": "system", "theme": "system" }
+      ]
+    }))
+    .unwrap();
+
+    assert_eq!(
+      config.theme_for(&config.windows[0]),
+      Some(crate::Theme::Dark)
+    );
+    assert_eq!(
+      config.theme_for(&config.windows[1]),
+      Some(crate::Theme::Light)
+    );
+    assert_eq!(
+      config.theme_for(&config.windows[2]),
+      Some(crate::Theme::System)
+    );
+    assert_eq!(
+      TauriConfig::default().theme_for(&WindowConfig::default()),
+      None
+    );
+  }
+
+  #[test]
+  fn isolation_response_headers() {
+    let pattern: PatternKind = serde_json::from_value(serde_json::json!({
+      "use": "isolation",
+      "options": {
+        "dir": "../isolation",
+        "responseHeaders": { "X-Frame-Options": "DENY" }
+      }
+    }))
+    .unwrap();
+    let PatternKind::Isolation {
+      response_headers, ..
+    } = &pattern
+    else {
+      panic!("expected the isolation pattern");
+    };
+    assert_eq!(response_headers.get("X-Frame-Options").unwrap(), "DENY");
+
+    #[cfg(all(feature = "build", feature = "isolation"))]
+    {
+      let tokens = quote::ToTokens::to_token_stream(&pattern).to_string();
+      assert!(tokens.contains("response_headers"));
+      assert!(tokens.contains("\"X-Frame-Options\""));
+      assert!(tokens.contains("\"DENY\""));
+    }
+
+    for headers in [
+      serde_json::json!({ "X Frame Options": "DENY" }),
+      serde_json::json!({ "": "DENY" }),
+      serde_json::json!({ "X-Frame-Options": "DENY\r\nSet-Cookie: a=b" }),
+    ] {
+      assert!(serde_json::from_value::<PatternKind>(serde_json::json!({
+        "use": "isolation",
+        "options": { "dir": "../isolation", "responseHeaders": headers }
+      }))
+      .is_err());
+    }
+  }
+
+  #[test]
+  fn global_shortcuts() {
+    let config: TauriConfig = serde_json::from_value(serde_json::json!({
+      "globalShortcuts": [
+        { "accelerator": "CmdOrCtrl+Shift+K", "event": "toggle" },
+        { "accelerator": "alt + f12", "event": "devtools" },
+        { "accelerator": "Super+Space", "event": "search" },
+        { "accelerator": "Ctrl+Num5", "event": "center" }
+      ]
+    }))
+    .unwrap();
+    assert_eq!(config.global_shortcuts.len(), 4);
+    assert_eq!(config.global_shortcuts[0].event, "toggle");
+
+    for accelerator in [
+      "Ctrl+Hyper+K",
+      "Ctrl+Shift",
+      "Ctrl+K+L",
+      "Ctrl+F25",
+      "Ctrl+F0",
+      "Ctrl+KeyAB",
+      "Ctrl++K",
+      "Ctrl+",
+      "",
+    ] {
+      let error = serde_json::from_value::<GlobalShortcutConfig>(serde_json::json!({
+        "accelerator": accelerator,
+        "event": "event"
+      }))
+      .unwrap_err();
+      assert!(
+        error.to_string().contains("invalid accelerator"),
+        "{accelerator}: {error}"
+      );
+    }
+
+    let mut config = Config::default();
+    config.tauri.global_shortcuts = vec![
+      GlobalShortcutConfig {
+        accelerator: "Ctrl+K".into(),
+        event: "a".into(),
+      },
+      GlobalShortcutConfig {
+        accelerator: "ctrl + k".into(),
+        event: "open settings".into(),
+      },
+    ];
+    let diagnostics = config.validate(Target::Linux);
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(
+      diagnostics[0].field_path,
+      "tauri > globalShortcuts > 1 > accelerator"
+    );
+    assert_eq!(
+      diagnostics[1].field_path,
+      "tauri > globalShortcuts > 1 > event"
+    );
+  }
 }
@@ -0,0 +1,209 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! A [`Deserializer`] over a JSON [`Value`] that skips the unknown struct keys instead of failing,
+//! so the `deny_unknown_fields` attribute of the configuration types does not apply.
+
+use std::cell::RefCell;
+
+use serde::de::{
+  value::StringDeserializer, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor,
+};
+use serde_json::{Map, Value};
+
+use super::parse::UnknownKeyWarning;
+
+type Result<T> = std::result::Result<T, serde_json::Error>;
+
+pub(crate) struct LenientDeserializer<'a> {
+  value: Value,
+  path: Vec<String>,
+  warnings: &'a RefCell<Vec<UnknownKeyWarning>>,
+}
+
+impl<'a> LenientDeserializer<'a> {
+  pub(crate) fn new(value: Value, warnings: &'a RefCell<Vec<UnknownKeyWarning>>) -> Self {
+    Self {
+      value,
+      path: Vec::new(),
+      warnings,
+    }
+  }
+}
+
+impl<'de, 'a> Deserializer<'de> for LenientDeserializer<'a> {
+  type Error = serde_json::Error;
+
+  fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+    match self.value {
+      Value::Object(map) => visitor.visit_map(LenientMap {
+        entries: map.into_iter(),
+        value: None,
+        path: self.path,
+        warnings: self.warnings,
+      }),
+      Value::Array(items) => visitor.visit_seq(LenientSeq {
+        items: items.into_iter().enumerate(),
+        path: self.path,
+        warnings: self.warnings,
+      }),
+      value => value.deserialize_any(visitor),
+    }
+  }
+
+  fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+    match self.value {
+      Value::Null => visitor.visit_none(),
+      _ => visitor.visit_some(self),
+    }
+  }
+
+  fn deserialize_newtype_struct<V: Visitor<'de>>(
+    self,
+    _name: &'static str,
+    visitor: V,
+  ) -> Result<V::Value> {
+    visitor.visit_newtype_struct(self)
+  }
+
+  fn deserialize_struct<V: Visitor<'de>>(
+    self,
+    _name: &'static str,
+    fields: &'static [&'static str],
+    visitor: V,
+  ) -> Result<V::Value> {
+    match self.value {
+      Value::Object(map) => {
+        let mut known = Map::new();
+        for (key, value) in map {
+          if fields.contains(&key.as_str()) {
+            known.insert(key, value);
+          } else {
+            let mut path = self.path.clone();
+            path.push(key.clone());
+            self.warnings.borrow_mut().push(UnknownKeyWarning {
+              field_path: path.join(" > "),
+              suggestion: closest_field(&key, fields),
+            });
+          }
+        }
+        Self {
+          value: Value::Object(known),
+          ..self
+        }
+        .deserialize_any(visitor)
+      }
+      _ => self.deserialize_any(visitor),
+    }
+  }
+
+  fn deserialize_enum<V: Visitor<'de>>(
+    self,
+    name: &'static str,
+    variants: &'static [&'static str],
+    visitor: V,
+  ) -> Result<V::Value> {
+    self.value.deserialize_enum(name, variants, visitor)
+  }
+
+  serde::forward_to_deserialize_any! {
+    bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+    bytes byte_buf unit unit_struct seq tuple tuple_struct map identifier ignored_any
+  }
+}
+
+struct LenientMap<'a> {
+  entries: serde_json::map::IntoIter,
+  value: Option<(String, Value)>,
+  path: Vec<String>,
+  warnings: &'a RefCell<Vec<UnknownKeyWarning>>,
+}
+
+impl<'de, 'a> MapAccess<'de> for LenientMap<'a> {
+  type Error = serde_json::Error;
+
+  fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+    match self.entries.next() {
+      Some((key, value)) => {
+        self.value = Some((key.clone(), value));
+        seed.deserialize(StringDeserializer::new(key)).map(Some)
+      }
+      None => Ok(None),
+    }
+  }
+
+  fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+    let (key, value) = self
+      .value
+      .take()
+      .expect("next_value_seed called before next_key_seed");
+    let mut path = self.path.clone();
+    path.push(key);
+    seed.deserialize(LenientDeserializer {
+      value,
+      path,
+      warnings: self.warnings,
+    })
+  }
+
+  fn size_hint(&self) -> Option<usize> {
+    Some(self.entries.len())
+  }
+}
+
+struct LenientSeq<'a> {
+  items: std::iter::Enumerate<std::vec::IntoIter<Value>>,
+  path: Vec<String>,
+  warnings: &'a RefCell<Vec<UnknownKeyWarning>>,
+}
+
+impl<'de, 'a> SeqAccess<'de> for LenientSeq<'a> {
+  type Error = serde_json::Error;
+
+  fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+    match self.items.next() {
+      Some((index, value)) => {
+        let mut path = self.path.clone();
+        path.push(index.to_string());
+        seed
+          .deserialize(LenientDeserializer {
+            value,
+            path,
+            warnings: self.warnings,
+          })
+          .map(Some)
+      }
+      None => Ok(None),
+    }
+  }
+
+  fn size_hint(&self) -> Option<usize> {
+    Some(self.items.len())
+  }
+}
+
+/// The known field closest to the unknown key, if it looks like a typo of it.
+fn closest_field(key: &str, fields: &[&str]) -> Option<String> {
+  fields
+    .iter()
+    .map(|field| (edit_distance(key, field), *field))
+    .filter(|(distance, _)| *distance <= 2.max(key.len() / 4))
+    .min_by_key(|(distance, _)| *distance)
+    .map(|(_, field)| field.to_string())
+}
+
+/// The Levenshtein distance between the two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+  let b = b.chars().collect::<Vec<_>>();
+  let mut previous = (0..=b.len()).collect::<Vec<_>>();
+  for (i, a) in a.chars().enumerate() {
+    let mut current = vec![i + 1];
+    for (j, b) in b.iter().enumerate() {
+      let substitution = previous[j] + usize::from(a != *b);
+      current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+    }
+    previous = current;
+  }
+  previous[b.len()]
+}
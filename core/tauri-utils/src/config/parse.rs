@@ -2,12 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
-use crate::config::Config;
+use crate::config::{Config, ConfigDiagnostic, DiagnosticSeverity};
 use crate::platform::Target;
 use json_patch::merge;
-use serde::de::DeserializeOwned;
+use serde::{de::DeserializeOwned, Deserialize};
 use serde_json::Value;
+use std::cell::RefCell;
 use std::ffi::OsStr;
+use std::fmt;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
 
@@ -28,7 +30,7 @@ pub const ENABLED_FORMATS: &[ConfigFormat] = &[
 ];
 
 /// The available configuration formats.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum ConfigFormat {
   /// The default JSON (tauri.conf.json) format.
   Json,
@@ -125,6 +127,10 @@ pub enum ConfigError {
     feature: String,
   },
 
+  /// The configuration has invalid values, see [`Config::validate`].
+  #[error("invalid Tauri config: {}", .0.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "))]
+  Invalid(Vec<ConfigDiagnostic>),
+
   /// A generic IO error with context of what caused it.
   #[error("unable to read Tauri config file at {path} because {error}")]
   Io {
@@ -234,8 +240,38 @@ pub fn does_supported_file_name_exist(target: Target, path: impl Into<PathBuf>)
 ///   a. Parse it with `toml`
 ///   b. Return error if all above steps failed
 /// 4. Return error if all above steps failed
+///
+/// The config is then validated with [`Config::validate`]: warnings are logged and errors are returned
+/// as [`ConfigError::Invalid`].
 pub fn parse(target: Target, path: impl Into<PathBuf>) -> Result<(Config, PathBuf), ConfigError> {
-  do_parse(target, path.into())
+  parse_with_format(target, path).map(|(config, _format, path)| (config, path))
+}
+
+/// Same as [`parse`], but also returns the format of the configuration file that was read,
+/// which can be used to write changes back in the same format.
+///
+/// A `tauri.conf.json` file is reported as [`ConfigFormat::Json5`] if it is only valid JSON5.
+pub fn parse_with_format(
+  target: Target,
+  path: impl Into<PathBuf>,
+) -> Result<(Config, ConfigFormat, PathBuf), ConfigError> {
+  let (config, format, path) = do_parse::<Config>(target, path.into())?;
+  let (errors, warnings): (Vec<_>, Vec<_>) = config
+    .validate(target)
+    .into_iter()
+    .partition(|diagnostic| diagnostic.severity == DiagnosticSeverity::Error);
+  for diagnostic in warnings {
+    if diagnostic.severity == DiagnosticSeverity::Info {
+      log::info!("{diagnostic}");
+    } else {
+      log::warn!("{diagnostic}");
+    }
+  }
+  if errors.is_empty() {
+    Ok((config, format, path))
+  } else {
+    Err(ConfigError::Invalid(errors))
+  }
 }
 
 /// See [`parse`] for specifics, returns a JSON [`Value`] instead of [`Config`].
@@ -243,13 +279,13 @@ pub fn parse_value(
   target: Target,
   path: impl Into<PathBuf>,
 ) -> Result<(Value, PathBuf), ConfigError> {
-  do_parse(target, path.into())
+  do_parse(target, path.into()).map(|(value, _format, path)| (value, path))
 }
 
 fn do_parse<D: DeserializeOwned>(
   target: Target,
   path: PathBuf,
-) -> Result<(D, PathBuf), ConfigError> {
+) -> Result<(D, ConfigFormat, PathBuf), ConfigError> {
   let file_name = path
     .file_name()
     .map(OsStr::to_string_lossy)
@@ -288,19 +324,27 @@ fn do_parse<D: DeserializeOwned>(
     #[cfg(feature = "config-json5")]
     let json = {
       match do_parse_json5(&raw, &path) {
-        json5 @ Ok(_) => json5,
+        Ok(config) => {
+          // valid JSON is also valid JSON5, so only report JSON5 if the file is not JSON
+          let format = if serde_json::from_str::<serde::de::IgnoredAny>(&raw).is_ok() {
+            ConfigFormat::Json
+          } else {
+            ConfigFormat::Json5
+          };
+          return Ok((config, format, path));
+        }
 
         // assume any errors from json5 in a .json file is because it's not json5
         Err(_) => json,
       }
     };
 
-    json.map(|j| (j, path))
+    json.map(|j| (j, ConfigFormat::Json, path))
   } else if json5.exists() {
     #[cfg(feature = "config-json5")]
     {
       let raw = read_to_string(&json5)?;
-      do_parse_json5(&raw, &path).map(|config| (config, json5))
+      do_parse_json5(&raw, &path).map(|config| (config, ConfigFormat::Json5, json5))
     }
 
     #[cfg(not(feature = "config-json5"))]
@@ -312,7 +356,7 @@ fn do_parse<D: DeserializeOwned>(
     #[cfg(feature = "config-toml")]
     {
       let raw = read_to_string(&toml)?;
-      do_parse_toml(&raw, &path).map(|config| (config, toml))
+      do_parse_toml(&raw, &path).map(|config| (config, ConfigFormat::Toml, toml))
     }
 
     #[cfg(not(feature = "config-toml"))]
@@ -351,6 +395,50 @@ fn do_parse_json<D: DeserializeOwned>(raw: &str, path: &Path) -> Result<D, Confi
   })
 }
 
+/// A configuration key that is unknown to this version of Tauri, reported by [`parse_lenient`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownKeyWarning {
+  /// The path of the key, e.g. `tauri > bundle > publsher`.
+  pub field_path: String,
+  /// The known key closest to the unknown one, if it looks like a typo of it.
+  pub suggestion: Option<String>,
+}
+
+impl fmt::Display for UnknownKeyWarning {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "unknown configuration key `{}`", self.field_path)?;
+    if let Some(suggestion) = &self.suggestion {
+      write!(f, ", did you mean `{suggestion}`?")?;
+    }
+    Ok(())
+  }
+}
+
+/// "Low-level" helper to parse JSON into a [`Config`] like [`parse_json`],
+/// but reporting the unknown keys as warnings instead of failing.
+///
+/// The configuration types reject unknown keys, so a configuration written for a newer Tauri version
+/// fails to parse on older versions. This function skips those keys instead, which lets teams
+/// on mixed Tauri versions share a configuration. The tradeoff is that a typo in a key name
+/// is only a warning too and the setting is silently ignored, so check the returned warnings,
+/// which suggest the closest known key for likely typos.
+///
+/// Keys of values that accept several shapes, e.g. a string or an object, are still rejected.
+/// The strict [`parse`] remains the default.
+pub fn parse_lenient(
+  raw: &str,
+  path: &Path,
+) -> Result<(Config, Vec<UnknownKeyWarning>), ConfigError> {
+  let value: Value = do_parse_json(raw, path)?;
+  let warnings = RefCell::new(Vec::new());
+  let config = Config::deserialize(super::lenient::LenientDeserializer::new(value, &warnings))
+    .map_err(|error| ConfigError::FormatJson {
+      path: path.into(),
+      error,
+    })?;
+  Ok((config, warnings.into_inner()))
+}
+
 /// "Low-level" helper to parse JSON5 into a [`Config`].
 ///
 /// `raw` should be the contents of the file that is represented by `path`. This function requires
@@ -392,3 +480,97 @@ fn read_to_string(path: &Path) -> Result<String, ConfigError> {
     error,
   })
 }
+
+#[cfg(test)]
+mod tests {
+  use super::{parse_json, parse_lenient, parse_with_format, ConfigFormat, UnknownKeyWarning};
+  use crate::platform::Target;
+  use std::path::{Path, PathBuf};
+
+  fn parse_file(name: &str, file_name: &str, contents: &str) -> (ConfigFormat, PathBuf) {
+    let dir = std::env::temp_dir().join(format!("tauri-parse-{name}-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join(file_name), contents).unwrap();
+    let result = parse_with_format(Target::Linux, dir.join("tauri.conf.json"));
+    std::fs::remove_dir_all(&dir).unwrap();
+    let (config, format, path) = result.unwrap();
+    assert_eq!(config.tauri.bundle.identifier, "com.tauri.test");
+    assert_eq!(path.file_name().unwrap(), file_name);
+    (format, path)
+  }
+
+  #[test]
+  fn detects_format() {
+    let (format, _) = parse_file(
+      "json",
+      "tauri.conf.json",
+      r#"{ "tauri": { "bundle": { "identifier": "com.tauri.test" } } }"#,
+    );
+    assert_eq!(format, ConfigFormat::Json);
+
+    #[cfg(feature = "config-json5")]
+    {
+      let (format, _) = parse_file(
+        "json5",
+        "tauri.conf.json5",
+        "{ tauri: { bundle: { identifier: 'com.tauri.test' } } }",
+      );
+      assert_eq!(format, ConfigFormat::Json5);
+
+      let (format, _) = parse_file(
+        "json5-in-json",
+        "tauri.conf.json",
+        "{ // comment\n tauri: { bundle: { identifier: 'com.tauri.test' } } }",
+      );
+      assert_eq!(format, ConfigFormat::Json5);
+    }
+
+    #[cfg(feature = "config-toml")]
+    {
+      let (format, _) = parse_file(
+        "toml",
+        "Tauri.toml",
+        "[tauri.bundle]\nidentifier = \"com.tauri.test\"\n",
+      );
+      assert_eq!(format, ConfigFormat::Toml);
+    }
+  }
+
+  #[test]
+  fn lenient_unknown_keys() {
+    let raw = r#"{
+      "tauri": {
+        "futureFeature": { "enabled": true },
+        "bundle": { "identifier": "com.tauri.test", "publsher": "Tauri" },
+        "windows": [{ "label": "main", "futureWindowOption": 1 }]
+      }
+    }"#;
+    let path = Path::new("tauri.conf.json");
+
+    assert!(parse_json(raw, path).is_err());
+
+    let (config, warnings) = parse_lenient(raw, path).unwrap();
+    assert_eq!(config.tauri.bundle.identifier, "com.tauri.test");
+    assert_eq!(config.tauri.bundle.publisher, None);
+    assert_eq!(config.tauri.windows[0].label, "main");
+    assert_eq!(warnings.len(), 3);
+    assert!(warnings.contains(&UnknownKeyWarning {
+      field_path: "tauri > futureFeature".into(),
+      suggestion: None,
+    }));
+    assert!(warnings.contains(&UnknownKeyWarning {
+      field_path: "tauri > windows > 0 > futureWindowOption".into(),
+      suggestion: None,
+    }));
+
+    let typo = warnings
+      .iter()
+      .find(|w| w.field_path == "tauri > bundle > publsher")
+      .unwrap();
+    assert_eq!(typo.suggestion.as_deref(), Some("publisher"));
+    assert_eq!(
+      typo.to_string(),
+      "unknown configuration key `tauri > bundle > publsher`, did you mean `publisher`?"
+    );
+  }
+}
@@ -72,6 +72,7 @@ impl PackageInfo {
 #[allow(deprecated)]
 mod window_effects {
   use super::*;
+  use crate::platform::Target;
 
   #[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize, Serialize)]
   #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -150,6 +151,144 @@ mod window_effects {
     Acrylic,
   }
 
+  /// Windows 10 version 1809, the first build supporting [`WindowEffect::Acrylic`].
+  const WINDOWS_10_1809: u32 = 17763;
+  /// Windows 11, the first build supporting [`WindowEffect::Mica`].
+  const WINDOWS_11: u32 = 22000;
+  /// Windows 11 build 22523, the first build supporting [`WindowEffect::Tabbed`].
+  const WINDOWS_11_22523: u32 = 22523;
+
+  impl WindowEffect {
+    /// Whether the effect is supported on the given target and OS version.
+    ///
+    /// The OS version is `(major, minor, patch)` on macOS and `(major, minor, build)` on Windows,
+    /// e.g. `(10, 0, 22000)` for Windows 11 and `(6, 1, 7601)` for Windows 7.
+    pub fn supported_on(&self, target: Target, os_version: (u32, u32, u32)) -> bool {
+      let (major, minor, build) = os_version;
+      let windows_build = |min_build| major > 10 || (major == 10 && build >= min_build);
+      match self {
+        Self::AppearanceBased | Self::Light | Self::Dark | Self::MediumLight | Self::UltraDark => {
+          target == Target::MacOS
+        }
+        Self::Titlebar | Self::Selection => target == Target::MacOS && (major, minor) >= (10, 10),
+        Self::Menu | Self::Popover | Self::Sidebar => {
+          target == Target::MacOS && (major, minor) >= (10, 11)
+        }
+        Self::HeaderView
+        | Self::Sheet
+        | Self::WindowBackground
+        | Self::HudWindow
+        | Self::FullScreenUI
+        | Self::Tooltip
+        | Self::ContentBackground
+        | Self::UnderWindowBackground
+        | Self::UnderPageBackground => target == Target::MacOS && (major, minor) >= (10, 14),
+        Self::Mica | Self::MicaDark | Self::MicaLight => {
+          target == Target::Windows && windows_build(WINDOWS_11)
+        }
+        Self::Tabbed | Self::TabbedDark | Self::TabbedLight => {
+          target == Target::Windows && windows_build(WINDOWS_11_22523)
+        }
+        Self::Blur => {
+          target == Target::Windows && ((major, minor) == (6, 1) || windows_build(WINDOWS_10_1809))
+        }
+        Self::Acrylic => target == Target::Windows && windows_build(WINDOWS_10_1809),
+      }
+    }
+
+    /// The effect used instead of this one when it is not supported.
+    ///
+    /// The fallback chain is `Tabbed` → `Mica` → `Acrylic`, keeping the dark or light variant
+    /// until `Acrylic`, which has no fallback. The other effects have no fallback either.
+    pub fn fallback(&self) -> Option<Self> {
+      match self {
+        Self::Tabbed => Some(Self::Mica),
+        Self::TabbedDark => Some(Self::MicaDark),
+        Self::TabbedLight => Some(Self::MicaLight),
+        Self::Mica | Self::MicaDark | Self::MicaLight => Some(Self::Acrylic),
+        _ => None,
+      }
+    }
+
+    /// The best effect available on the given target and OS version:
+    /// this effect if it is supported, otherwise the first supported effect of its [fallback chain](Self::fallback).
+    ///
+    /// For instance, `Mica` is used as is on Windows 11, mapped to `Acrylic` on Windows 10 and not available on Windows 7.
+    pub fn best_available(&self, target: Target, os_version: (u32, u32, u32)) -> Option<Self> {
+      let mut effect = Some(*self);
+      while let Some(e) = effect {
+        if e.supported_on(target, os_version) {
+          return Some(e);
+        }
+        effect = e.fallback();
+      }
+      None
+    }
+  }
+
+  #[cfg(test)]
+  mod tests {
+    use super::WindowEffect;
+    use crate::platform::Target;
+
+    const WINDOWS_7: (u32, u32, u32) = (6, 1, 7601);
+    const WINDOWS_10_1607: (u32, u32, u32) = (10, 0, 14393);
+    const WINDOWS_10_22H2: (u32, u32, u32) = (10, 0, 19045);
+    const WINDOWS_11_21H2: (u32, u32, u32) = (10, 0, 22000);
+    const WINDOWS_11_23H2: (u32, u32, u32) = (10, 0, 22631);
+
+    #[test]
+    fn supported_on() {
+      assert!(WindowEffect::Mica.supported_on(Target::Windows, WINDOWS_11_21H2));
+      assert!(!WindowEffect::Mica.supported_on(Target::Windows, WINDOWS_10_22H2));
+      assert!(!WindowEffect::Mica.supported_on(Target::MacOS, (14, 0, 0)));
+      assert!(!WindowEffect::Tabbed.supported_on(Target::Windows, WINDOWS_11_21H2));
+      assert!(WindowEffect::Tabbed.supported_on(Target::Windows, WINDOWS_11_23H2));
+      assert!(WindowEffect::Blur.supported_on(Target::Windows, WINDOWS_7));
+      assert!(!WindowEffect::Blur.supported_on(Target::Windows, WINDOWS_10_1607));
+      assert!(!WindowEffect::Acrylic.supported_on(Target::Windows, WINDOWS_7));
+      assert!(WindowEffect::Sidebar.supported_on(Target::MacOS, (10, 11, 0)));
+      assert!(!WindowEffect::HudWindow.supported_on(Target::MacOS, (10, 13, 6)));
+      assert!(!WindowEffect::HudWindow.supported_on(Target::Linux, (6, 6, 0)));
+    }
+
+    #[test]
+    fn best_available() {
+      let best = |effect: WindowEffect| {
+        [
+          WINDOWS_7,
+          WINDOWS_10_1607,
+          WINDOWS_10_22H2,
+          WINDOWS_11_21H2,
+          WINDOWS_11_23H2,
+        ]
+        .map(|version| effect.best_available(Target::Windows, version))
+      };
+
+      use WindowEffect::*;
+      assert_eq!(
+        best(Mica),
+        [None, None, Some(Acrylic), Some(Mica), Some(Mica)]
+      );
+      assert_eq!(
+        best(TabbedDark),
+        [None, None, Some(Acrylic), Some(MicaDark), Some(TabbedDark)]
+      );
+      assert_eq!(
+        best(Acrylic),
+        [None, None, Some(Acrylic), Some(Acrylic), Some(Acrylic)]
+      );
+      assert_eq!(
+        best(Blur),
+        [Some(Blur), None, Some(Blur), Some(Blur), Some(Blur)]
+      );
+      assert_eq!(
+        WindowEffect::Mica.best_available(Target::MacOS, (14, 0, 0)),
+        None
+      );
+    }
+  }
+
   /// Window effect state **macOS only**
   ///
   /// <https://developer.apple.com/documentation/appkit/nsvisualeffectview/state>
@@ -239,6 +378,10 @@ pub enum Theme {
   Light,
   /// Dark theme.
   Dark,
+  /// Follows the system theme, including changes made while the window is open.
+  ///
+  /// Only meaningful when configuring a window: the theme of an existing window is never reported as `System`.
+  System,
 }
 
 impl Serialize for Theme {
@@ -258,6 +401,7 @@ impl<'de> Deserialize<'de> for Theme {
     let s = String::deserialize(deserializer)?;
     Ok(match s.to_lowercase().as_str() {
       "dark" => Self::Dark,
+      "system" => Self::System,
       _ => Self::Light,
     })
   }
@@ -271,6 +415,7 @@ impl Display for Theme {
       match self {
         Self::Light => "light",
         Self::Dark => "dark",
+        Self::System => "system",
       }
     )
   }
@@ -383,6 +528,12 @@ pub enum Error {
   #[cfg(feature = "resources")]
   #[error("could not walk directory `{0}`, try changing `allow_walk` to true on the `ResourcePaths` constructor.")]
   NotAllowedToWalkDir(std::path::PathBuf),
+  /// Invalid URL.
+  #[error("{0}")]
+  InvalidUrl(#[from] url::ParseError),
+  /// A path joined onto a webview URL escapes it.
+  #[error("`{0}` escapes the webview URL it is joined onto")]
+  WebviewUrlEscape(String),
 }
 
 /// Suppresses the unused-variable warnings of the given inputs.
@@ -16,7 +16,7 @@ use crate::{Env, PackageInfo};
 mod starting_binary;
 
 /// Platform target.
-#[derive(PartialEq, Eq, Copy, Debug, Clone, Serialize, Deserialize)]
+#[derive(PartialEq, Eq, Hash, Copy, Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub enum Target {
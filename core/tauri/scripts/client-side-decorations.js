@@ -0,0 +1,27 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+;(function () {
+  // in CSS pixels, also used as the resize region of the window
+  const margin = 10
+
+  Object.defineProperty(window.__TAURI_INTERNALS__, 'clientSideDecorationsMargin', {
+    value: margin
+  })
+
+  window.addEventListener('DOMContentLoaded', () => {
+    const style = document.createElement('style')
+    style.textContent = `:root {
+  --tauri-client-side-decorations-margin: ${margin}px;
+}
+html {
+  box-sizing: border-box;
+  height: 100%;
+  padding: var(--tauri-client-side-decorations-margin);
+  background: transparent;
+}`
+    // prepended so the app styles take precedence
+    document.head.prepend(style)
+  })
+})()
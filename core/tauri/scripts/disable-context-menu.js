@@ -0,0 +1,11 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+window.addEventListener(
+  'contextmenu',
+  function (event) {
+    event.preventDefault()
+  },
+  { capture: true }
+)
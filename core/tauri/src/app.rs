@@ -18,8 +18,11 @@ use crate::{
     RunEvent as RuntimeRunEvent,
   },
   sealed::{ManagerBase, RuntimeOrDispatch},
-  utils::config::Config,
-  utils::{assets::Assets, Env},
+  utils::{acl::resolved::Resolved, config::Config},
+  utils::{
+    assets::{Assets, AssetsIter},
+    Env,
+  },
   webview::PageLoadPayload,
   Context, DeviceEventFilter, EventLoopMessage, Icon, Manager, Monitor, Runtime, Scopes,
   StateManager, Theme, Webview, WebviewWindowBuilder, Window,
@@ -55,7 +58,13 @@ use crate::runtime::RuntimeHandle;
 #[cfg(target_os = "macos")]
 use crate::ActivationPolicy;
 
+#[cfg(all(desktop, feature = "global-shortcut"))]
+mod global_shortcut;
 pub(crate) mod plugin;
+#[cfg(desktop)]
+mod splash;
+#[cfg(all(desktop, feature = "wry"))]
+mod webview_version;
 
 #[cfg(desktop)]
 pub(crate) type GlobalMenuEventListener<T> = Box<dyn Fn(&T, crate::menu::MenuEvent) + Send + Sync>;
@@ -231,7 +240,7 @@ impl<R: Runtime> AssetResolver<R> {
   }
 
   /// Iterate on all assets.
-  pub fn iter(&self) -> Box<dyn Iterator<Item = (&&str, &&[u8])> + '_> {
+  pub fn iter(&self) -> Box<AssetsIter<'_>> {
     self.manager.assets.iter()
   }
 }
@@ -365,6 +374,50 @@ impl<R: Runtime> AppHandle<R> {
     self.manager().plugins.lock().unwrap().unregister(plugin)
   }
 
+  /// Adds capabilities resolved at runtime to the app ACL,
+  /// e.g. to grant IPC access to a plugin that is loaded dynamically or to windows created at runtime.
+  ///
+  /// # Security
+  ///
+  /// The capabilities are as trusted as the ones defined at build time,
+  /// so they must never be built from data coming from a webview.
+  ///
+  /// Capabilities can only be added: the commands denied at build time stay denied,
+  /// even if an added capability allows them. Permissions with scopes cannot be added at runtime,
+  /// and the windows granted access to a command that is already allowed share its existing scope.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use tauri::utils::acl::{capability::Capability, plugin::Manifest, resolved::ResolvedBuilder};
+  ///
+  /// tauri::Builder::default()
+  ///   .setup(move |app| {
+  ///     // usually provided by the dynamically loaded plugin
+  ///     let manifest: Manifest = serde_json::from_value(serde_json::json!({
+  ///       "default_permission": null,
+  ///       "permissions": {
+  ///         "allow-ping": { "identifier": "allow-ping", "commands": { "allow": ["ping"] } }
+  ///       },
+  ///       "permission_sets": {}
+  ///     }))?;
+  ///     let capability: Capability = serde_json::from_value(serde_json::json!({
+  ///       "identifier": "dynamic",
+  ///       "windows": ["main"],
+  ///       "permissions": ["dynamic:allow-ping"]
+  ///     }))?;
+  ///     let acl = ResolvedBuilder::new()
+  ///       .plugin("dynamic", manifest)
+  ///       .capability(capability)
+  ///       .build()?;
+  ///     app.handle().add_capabilities(acl)?;
+  ///     Ok(())
+  ///   });
+  /// ```
+  pub fn add_capabilities(&self, acl: Resolved) -> crate::Result<()> {
+    self.manager().runtime_authority.add_capabilities(acl)
+  }
+
   /// Exits the app. This is the same as [`std::process::exit`], but it performs cleanup on this application.
   pub fn exit(&self, exit_code: i32) {
     self.cleanup_before_exit();
@@ -592,6 +645,15 @@ macro_rules! shared_app_impl {
           _ => unreachable!(),
         })
       }
+
+      /// Returns the position of the mouse cursor, in physical pixels relative to the larger full screen area.
+      pub fn cursor_position(&self) -> crate::Result<PhysicalPosition<f64>> {
+        Ok(match self.runtime() {
+          RuntimeOrDispatch::Runtime(h) => h.cursor_position()?,
+          RuntimeOrDispatch::RuntimeHandle(h) => h.cursor_position()?,
+          _ => unreachable!(),
+        })
+      }
       /// Returns the default window icon.
       pub fn default_window_icon(&self) -> Option<&Icon> {
         self.manager.window.default_icon.as_ref()
@@ -1619,6 +1681,16 @@ tauri::Builder::default()
       }
     }
 
+    // register the global shortcuts defined in the configuration
+    #[cfg(all(desktop, feature = "global-shortcut"))]
+    {
+      let shortcuts = &app.config().tauri.global_shortcuts;
+      if !shortcuts.is_empty() {
+        let shortcuts = global_shortcut::GlobalShortcuts::register(handle, shortcuts)?;
+        app.manage(shortcuts);
+      }
+    }
+
     app.manager.initialize_plugins(handle)?;
 
     Ok(app)
@@ -1674,6 +1746,11 @@ unsafe impl<R: Runtime> HasRawDisplayHandle for App<R> {
 
 #[cfg_attr(feature = "tracing", tracing::instrument(name = "app::setup"))]
 fn setup<R: Runtime>(app: &mut App<R>) -> crate::Result<()> {
+  #[cfg(all(desktop, feature = "wry"))]
+  if !webview_version::check(app.handle()) {
+    app.handle().exit(1);
+  }
+
   let window_labels = app
     .config()
     .tauri
@@ -1690,8 +1767,71 @@ fn setup<R: Runtime>(app: &mut App<R>) -> crate::Result<()> {
     .collect::<Vec<_>>();
 
   for window_config in app.config().tauri.windows.clone() {
-    WebviewWindowBuilder::from_config(app.handle(), window_config)
-      .build_internal(&window_labels, &webview_labels)?;
+    #[cfg(desktop)]
+    let remember_state = window_config.remember_state;
+    #[cfg(all(desktop, dev))]
+    let dev_state_path = crate::window::state::dev_state_path();
+    #[cfg(desktop)]
+    let (window_config, restored_state) = {
+      let mut window_config = window_config;
+      let mut restored_state = None;
+      if remember_state {
+        restored_state = crate::window::state::restore(app.handle(), &mut window_config);
+      }
+      #[cfg(dev)]
+      if let Some(path) = &dev_state_path {
+        restored_state = crate::window::state::restore_dev(app.handle(), path, &mut window_config)
+          .or(restored_state);
+      }
+      (window_config, restored_state)
+    };
+
+    #[cfg(desktop)]
+    let splash = window_config
+      .splash
+      .clone()
+      .map(|splash| {
+        splash::SplashWindow::new(
+          app.handle(),
+          &window_config.label,
+          window_config.visible,
+          splash,
+        )
+      })
+      .transpose()?;
+
+    #[allow(unused_mut)]
+    let mut builder = WebviewWindowBuilder::from_config(app.handle(), window_config);
+    #[cfg(desktop)]
+    if let Some(splash) = &splash {
+      builder = splash.attach(builder);
+    }
+    let window = builder.build_internal(&window_labels, &webview_labels)?;
+
+    #[cfg(desktop)]
+    if let Some(restored_state) = restored_state {
+      let _ = restored_state.apply(&window);
+    }
+
+    #[cfg(desktop)]
+    if remember_state {
+      let _ = crate::window::state::remember(&window);
+    }
+
+    if crate::webview::zoom::is_remembered(window.as_ref()) {
+      let _ = crate::webview::zoom::restore(window.as_ref());
+    }
+
+    #[cfg(all(desktop, dev))]
+    if let Some(path) = dev_state_path {
+      // the app is killed on restart, so the state is saved shortly after every change
+      crate::window::state::watch(&window, path);
+    }
+
+    #[cfg(desktop)]
+    if let Some(splash) = splash {
+      splash.watch();
+    }
   }
 
   if let Some(setup) = app.setup.take() {
@@ -0,0 +1,54 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::{collections::HashMap, str::FromStr};
+
+use global_hotkey::{hotkey::HotKey, GlobalHotKeyEvent, GlobalHotKeyManager, HotKeyState};
+
+use crate::{utils::config::GlobalShortcutConfig, AppHandle, Manager, Runtime};
+
+/// Keeps the global shortcuts of the configuration registered while the app is running.
+pub(crate) struct GlobalShortcuts {
+  _manager: GlobalHotKeyManager,
+}
+
+impl GlobalShortcuts {
+  /// Registers the shortcuts, emitting the event of a shortcut to every target when it is pressed.
+  ///
+  /// A shortcut that fails to register, e.g. because another application already registered it, is skipped.
+  pub(crate) fn register<R: Runtime>(
+    app_handle: &AppHandle<R>,
+    shortcuts: &[GlobalShortcutConfig],
+  ) -> crate::Result<Self> {
+    let manager = GlobalHotKeyManager::new()?;
+
+    let mut events = HashMap::new();
+    for shortcut in shortcuts {
+      let hotkey = HotKey::from_str(&shortcut.accelerator)
+        .map_err(|e| global_hotkey::Error::HotKeyParseError(e.to_string()))?;
+      match manager.register(hotkey) {
+        Ok(()) => {
+          events.insert(hotkey.id(), shortcut.event.clone());
+        }
+        Err(e) => {
+          tauri_utils::debug_eprintln!(
+            "failed to register global shortcut `{}`: {e}",
+            shortcut.accelerator
+          );
+        }
+      }
+    }
+
+    let app_handle = app_handle.clone();
+    GlobalHotKeyEvent::set_event_handler(Some(move |event: GlobalHotKeyEvent| {
+      if event.state() == HotKeyState::Pressed {
+        if let Some(name) = events.get(&event.id()) {
+          let _ = app_handle.emit(name, ());
+        }
+      }
+    }));
+
+    Ok(Self { _manager: manager })
+  }
+}
@@ -0,0 +1,166 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::{
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+  },
+  time::{Duration, Instant},
+};
+
+use crate::{
+  utils::config::{CloseTrigger, SplashConfig},
+  webview::PageLoadEvent,
+  AppHandle, Manager, Runtime, WebviewWindow, WebviewWindowBuilder,
+};
+
+/// The splash window shown on startup until the window it covers is ready.
+pub(crate) struct SplashWindow<R: Runtime> {
+  window: WebviewWindow<R>,
+  app_handle: AppHandle<R>,
+  label: String,
+  /// Whether the covered window is shown when the splash window closes, i.e. it is not configured with `visible: false`.
+  show: bool,
+  close_on: CloseTrigger,
+  min_display: Duration,
+  shown_at: Instant,
+  closed: AtomicBool,
+}
+
+impl<R: Runtime> SplashWindow<R> {
+  /// Creates the borderless splash window of the `label` window, which is shown when the splash window closes if `show` is set.
+  pub(crate) fn new(
+    app_handle: &AppHandle<R>,
+    label: &str,
+    show: bool,
+    config: SplashConfig,
+  ) -> crate::Result<Arc<Self>> {
+    let window = WebviewWindowBuilder::new(app_handle, format!("{label}-splash"), config.url)
+      .decorations(false)
+      .resizable(false)
+      .skip_taskbar(true)
+      .center()
+      .build()?;
+
+    Ok(Arc::new(Self {
+      window,
+      app_handle: app_handle.clone(),
+      label: label.into(),
+      show,
+      close_on: config.close_on,
+      min_display: Duration::from_millis(config.min_display_ms),
+      shown_at: Instant::now(),
+      closed: AtomicBool::new(false),
+    }))
+  }
+
+  /// Hides the covered window until the splash window closes,
+  /// closing it when the window finishes loading if using [`CloseTrigger::PageLoad`].
+  pub(crate) fn attach<'a>(
+    self: &Arc<Self>,
+    builder: WebviewWindowBuilder<'a, R, AppHandle<R>>,
+  ) -> WebviewWindowBuilder<'a, R, AppHandle<R>> {
+    let builder = builder.visible(false);
+    if self.close_on == CloseTrigger::PageLoad {
+      let splash = self.clone();
+      builder.on_page_load(move |_window, payload| {
+        if payload.event() == PageLoadEvent::Finished {
+          splash.close();
+        }
+      })
+    } else {
+      builder
+    }
+  }
+
+  /// Registers the remaining close triggers once the covered window is created.
+  pub(crate) fn watch(self: Arc<Self>) {
+    match self.close_on.clone() {
+      CloseTrigger::PageLoad => (),
+      CloseTrigger::Event(event) => {
+        let app_handle = self.app_handle.clone();
+        app_handle.once_global(event, move |_| self.close());
+      }
+      CloseTrigger::Timeout => self.close(),
+    }
+  }
+
+  /// Closes the splash window and shows the covered window unless it is configured hidden,
+  /// waiting until the splash window has been displayed for the configured minimum time.
+  fn close(self: &Arc<Self>) {
+    if self.closed.swap(true, Ordering::SeqCst) {
+      return;
+    }
+
+    let remaining = self.min_display.saturating_sub(self.shown_at.elapsed());
+    if remaining.is_zero() {
+      self.close_now();
+    } else {
+      let splash = self.clone();
+      std::thread::spawn(move || {
+        std::thread::sleep(remaining);
+        splash.close_now();
+      });
+    }
+  }
+
+  fn close_now(&self) {
+    // show the window first so there is no gap between the two windows
+    if let Some(window) = self
+      .app_handle
+      .get_webview_window(&self.label)
+      .filter(|_| self.show)
+    {
+      let _ = window.show();
+      let _ = window.set_focus();
+    }
+    let _ = self.window.close();
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::SplashWindow;
+  use crate::{
+    test::mock_app,
+    utils::config::{CloseTrigger, SplashConfig, WindowConfig},
+    Manager, WebviewWindowBuilder,
+  };
+
+  #[test]
+  fn shows_window_unless_hidden() {
+    let app = mock_app();
+
+    for visible in [true, false] {
+      let label = if visible { "visible" } else { "hidden" };
+      let config = WindowConfig {
+        label: label.into(),
+        visible,
+        splash: Some(SplashConfig {
+          url: Default::default(),
+          min_display_ms: 0,
+          close_on: CloseTrigger::Event(format!("{label}-ready")),
+        }),
+        ..Default::default()
+      };
+      let splash = SplashWindow::new(
+        app.handle(),
+        label,
+        config.visible,
+        config.splash.clone().unwrap(),
+      )
+      .unwrap();
+      let window = splash
+        .attach(WebviewWindowBuilder::from_config(app.handle(), config))
+        .build()
+        .unwrap();
+      splash.watch();
+      assert!(!window.is_visible().unwrap());
+
+      app.emit(&format!("{label}-ready"), ()).unwrap();
+      assert_eq!(window.is_visible().unwrap(), visible);
+    }
+  }
+}
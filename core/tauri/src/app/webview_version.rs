@@ -0,0 +1,104 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{AppHandle, Manager, Runtime};
+
+const TITLE: &str = "Unsupported WebView version";
+
+/// Checks the installed webview against [`crate::utils::config::TauriConfig::minimum_webview_version`].
+///
+/// Returns `false` after telling the user to update the webview if it is too old.
+pub(crate) fn check<R: Runtime>(app_handle: &AppHandle<R>) -> bool {
+  let config = &app_handle.config().tauri;
+  let Some(minimum) = &config.minimum_webview_version else {
+    return true;
+  };
+  let Ok(installed) = crate::webview_version() else {
+    return true;
+  };
+  if config.is_webview_version_supported(&installed) {
+    return true;
+  }
+
+  let name = &app_handle.package_info().name;
+  let mut message = format!(
+    "{name} requires WebView version {minimum} or newer, but version {installed} is installed."
+  );
+  if cfg!(windows) {
+    message.push_str(
+      "\n\nDownload the latest WebView2 Runtime from https://developer.microsoft.com/microsoft-edge/webview2/",
+    );
+  } else if cfg!(target_os = "macos") {
+    message
+      .push_str("\n\nUpdate macOS to get a newer WebView: https://support.apple.com/macos/upgrade");
+  } else {
+    message
+      .push_str("\n\nUpdate the webkit2gtk package of your distribution to get a newer WebView.");
+  }
+  show_error_dialog(&message);
+
+  false
+}
+
+#[cfg(windows)]
+fn show_error_dialog(message: &str) {
+  use windows::{
+    core::HSTRING,
+    Win32::{
+      Foundation::HWND,
+      UI::WindowsAndMessaging::{MessageBoxW, MB_ICONERROR, MB_OK},
+    },
+  };
+
+  unsafe {
+    MessageBoxW(
+      HWND::default(),
+      &HSTRING::from(message),
+      &HSTRING::from(TITLE),
+      MB_OK | MB_ICONERROR,
+    );
+  }
+}
+
+#[cfg(target_os = "macos")]
+fn show_error_dialog(message: &str) {
+  use cocoa::{
+    base::{id, nil},
+    foundation::NSString,
+  };
+  use objc::{class, msg_send, sel, sel_impl};
+
+  // NSAlertStyleCritical
+  const CRITICAL_ALERT_STYLE: u64 = 2;
+
+  unsafe {
+    let alert: id = msg_send![class!(NSAlert), new];
+    let _: () = msg_send![alert, setMessageText: NSString::alloc(nil).init_str(TITLE)];
+    let _: () = msg_send![alert, setInformativeText: NSString::alloc(nil).init_str(message)];
+    let _: () = msg_send![alert, setAlertStyle: CRITICAL_ALERT_STYLE];
+    let _: isize = msg_send![alert, runModal];
+  }
+}
+
+#[cfg(any(
+  target_os = "linux",
+  target_os = "dragonfly",
+  target_os = "freebsd",
+  target_os = "netbsd",
+  target_os = "openbsd"
+))]
+fn show_error_dialog(message: &str) {
+  use gtk::prelude::*;
+
+  let dialog = gtk::MessageDialog::new(
+    None::<&gtk::Window>,
+    gtk::DialogFlags::MODAL,
+    gtk::MessageType::Error,
+    gtk::ButtonsType::Ok,
+    message,
+  );
+  dialog.set_title(TITLE);
+  dialog.run();
+  dialog.close();
+}
@@ -2,13 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
-use std::collections::BTreeMap;
+use std::collections::{btree_map::Entry, BTreeMap};
 use std::fmt::Debug;
+use std::sync::RwLock;
 
 use serde::de::DeserializeOwned;
 use state::TypeMap;
 
 use tauri_utils::acl::{
+  capability::matches_remote_domain,
   resolved::{CommandKey, Resolved, ResolvedCommand, ResolvedScope, ScopeKey},
   ExecutionContext,
 };
@@ -19,8 +21,8 @@ use super::{CommandArg, CommandItem};
 
 /// The runtime authority used to authorize IPC execution based on the Access Control List.
 pub struct RuntimeAuthority {
-  allowed_commands: BTreeMap<CommandKey, ResolvedCommand>,
-  denied_commands: BTreeMap<CommandKey, ResolvedCommand>,
+  allowed_commands: RwLock<BTreeMap<CommandKey, ResolvedCommand>>,
+  denied_commands: RwLock<BTreeMap<CommandKey, ResolvedCommand>>,
   pub(crate) scope_manager: ScopeManager,
 }
 
@@ -43,8 +45,9 @@ impl Origin {
         Self::Remote { domain },
         ExecutionContext::Remote {
           domain: domain_pattern,
+          subdomain_depth,
         },
-      ) => domain_pattern.matches(domain),
+      ) => matches_remote_domain(domain_pattern.as_str(), *subdomain_depth, domain),
       _ => false,
     }
   }
@@ -58,8 +61,8 @@ impl RuntimeAuthority {
       .map(|key| (*key, <TypeMap![Send + Sync]>::new()))
       .collect();
     Self {
-      allowed_commands: acl.allowed_commands,
-      denied_commands: acl.denied_commands,
+      allowed_commands: RwLock::new(acl.allowed_commands),
+      denied_commands: RwLock::new(acl.denied_commands),
       scope_manager: ScopeManager {
         command_scope: acl.command_scope,
         global_scope: acl.global_scope,
@@ -69,15 +72,40 @@ impl RuntimeAuthority {
     }
   }
 
+  /// Adds the commands allowed and denied by an ACL resolved at runtime,
+  /// e.g. with [`ResolvedBuilder`](tauri_utils::acl::resolved::ResolvedBuilder).
+  ///
+  /// The windows of a command that is already allowed are added to the existing ones, keeping its scope.
+  /// Denied commands are added to the existing denials, which cannot be removed.
+  ///
+  /// Scopes cannot be added at runtime, so the ACL must not have any command or global scope.
+  pub fn add_capabilities(&self, acl: Resolved) -> crate::Result<()> {
+    if !acl.command_scope.is_empty() || !acl.global_scope.is_empty() {
+      return Err(crate::Error::RuntimeCapabilityScope);
+    }
+
+    merge_commands(
+      &mut self.allowed_commands.write().unwrap(),
+      acl.allowed_commands,
+    );
+    merge_commands(
+      &mut self.denied_commands.write().unwrap(),
+      acl.denied_commands,
+    );
+    Ok(())
+  }
+
   /// Checks if the given IPC execution is allowed and returns the [`ResolvedCommand`] if it is.
   pub fn resolve_access(
     &self,
     command: &str,
     window: &str,
     origin: Origin,
-  ) -> Option<&ResolvedCommand> {
+  ) -> Option<ResolvedCommand> {
     if self
       .denied_commands
+      .read()
+      .unwrap()
       .keys()
       .any(|cmd| cmd.name == command && origin.matches(&cmd.context))
     {
@@ -85,10 +113,27 @@ impl RuntimeAuthority {
     } else {
       self
         .allowed_commands
+        .read()
+        .unwrap()
         .iter()
         .find(|(cmd, _)| cmd.name == command && origin.matches(&cmd.context))
         .map(|(_cmd, allowed)| allowed)
         .filter(|allowed| allowed.windows.iter().any(|w| w.matches(window)))
+        .cloned()
+    }
+  }
+}
+
+fn merge_commands(
+  commands: &mut BTreeMap<CommandKey, ResolvedCommand>,
+  added: BTreeMap<CommandKey, ResolvedCommand>,
+) {
+  for (key, command) in added {
+    match commands.entry(key) {
+      Entry::Occupied(mut entry) => entry.get_mut().windows.extend(command.windows),
+      Entry::Vacant(entry) => {
+        entry.insert(command);
+      }
     }
   }
 }
@@ -258,9 +303,14 @@ impl ScopeManager {
 #[cfg(test)]
 mod tests {
   use glob::Pattern;
-  use tauri_utils::acl::{
-    resolved::{CommandKey, Resolved, ResolvedCommand},
-    ExecutionContext,
+  use tauri_utils::{
+    acl::{
+      capability::{Capability, PermissionEntry},
+      plugin::Manifest,
+      resolved::{CommandKey, Resolved, ResolvedBuilder, ResolvedCommand},
+      Commands, ExecutionContext, Identifier, Permission, Scopes, Value,
+    },
+    platform::Target,
   };
 
   use crate::command::Origin;
@@ -296,7 +346,7 @@ mod tests {
         &window.replace('*', "something"),
         Origin::Local
       ),
-      Some(&resolved_cmd)
+      Some(resolved_cmd)
     );
   }
 
@@ -307,6 +357,7 @@ mod tests {
       name: "my-command".into(),
       context: ExecutionContext::Remote {
         domain: Pattern::new(domain).unwrap(),
+        subdomain_depth: None,
       },
     };
     let window = "main";
@@ -334,7 +385,7 @@ mod tests {
           domain: domain.into()
         }
       ),
-      Some(&resolved_cmd)
+      Some(resolved_cmd)
     );
   }
 
@@ -345,6 +396,7 @@ mod tests {
       name: "my-command".into(),
       context: ExecutionContext::Remote {
         domain: Pattern::new(domain).unwrap(),
+        subdomain_depth: None,
       },
     };
     let window = "main";
@@ -372,7 +424,7 @@ mod tests {
           domain: domain.replace('*', "studio")
         }
       ),
-      Some(&resolved_cmd)
+      Some(resolved_cmd)
     );
   }
 
@@ -448,4 +500,106 @@ mod tests {
       .resolve_access(&command.name, window, Origin::Local)
       .is_none());
   }
+
+  fn dynamic_acl(permissions: &[&str], scope: Option<Value>) -> Resolved {
+    let permission = |identifier: &str, allow: &[&str], deny: &[&str]| Permission {
+      version: None,
+      identifier: identifier.into(),
+      description: None,
+      commands: Commands {
+        allow: allow.iter().map(|c| c.to_string()).collect(),
+        deny: deny.iter().map(|c| c.to_string()).collect(),
+      },
+      scope: Scopes {
+        allow: scope.clone().map(|scope| vec![scope]),
+        deny: None,
+      },
+      platforms: None,
+    };
+    let manifest = Manifest {
+      default_permission: None,
+      permissions: [
+        (
+          "allow-ping".to_string(),
+          permission("allow-ping", &["ping"], &[]),
+        ),
+        (
+          "deny-ping".to_string(),
+          permission("deny-ping", &[], &["ping"]),
+        ),
+      ]
+      .into_iter()
+      .collect(),
+      permission_sets: Default::default(),
+    };
+
+    ResolvedBuilder::new()
+      .plugin("dynamic", manifest)
+      .capability(Capability {
+        identifier: "dynamic".into(),
+        description: String::new(),
+        context: Default::default(),
+        windows: vec!["main".into()],
+        permissions: permissions
+          .iter()
+          .map(|p| PermissionEntry::PermissionRef(Identifier::try_from(p.to_string()).unwrap()))
+          .collect(),
+        platforms: vec![Target::current()],
+      })
+      .build()
+      .unwrap()
+  }
+
+  #[test]
+  fn dynamic_capability_allows_command() {
+    let authority = RuntimeAuthority::new(Resolved {
+      allowed_commands: Default::default(),
+      denied_commands: Default::default(),
+      command_scope: Default::default(),
+      global_scope: Default::default(),
+    });
+    let command = "plugin:dynamic|ping";
+    assert!(authority
+      .resolve_access(command, "main", Origin::Local)
+      .is_none());
+
+    authority
+      .add_capabilities(dynamic_acl(&["dynamic:allow-ping"], None))
+      .unwrap();
+    assert!(authority
+      .resolve_access(command, "main", Origin::Local)
+      .is_some());
+    assert!(authority
+      .resolve_access(command, "other", Origin::Local)
+      .is_none());
+
+    // denials can be added but not removed
+    authority
+      .add_capabilities(dynamic_acl(&["dynamic:deny-ping"], None))
+      .unwrap();
+    authority
+      .add_capabilities(dynamic_acl(&["dynamic:allow-ping"], None))
+      .unwrap();
+    assert!(authority
+      .resolve_access(command, "main", Origin::Local)
+      .is_none());
+  }
+
+  #[test]
+  fn dynamic_capability_scope_rejected() {
+    let authority = RuntimeAuthority::new(Resolved {
+      allowed_commands: Default::default(),
+      denied_commands: Default::default(),
+      command_scope: Default::default(),
+      global_scope: Default::default(),
+    });
+    let acl = dynamic_acl(&["dynamic:allow-ping"], Some(Value::String("*".into())));
+    assert!(matches!(
+      authority.add_capabilities(acl),
+      Err(crate::Error::RuntimeCapabilityScope)
+    ));
+    assert!(authority
+      .resolve_access("plugin:dynamic|ping", "main", Origin::Local)
+      .is_none());
+  }
 }
@@ -194,6 +194,21 @@ pub mod private {
   #[cfg(feature = "tracing")]
   pub use tracing;
 
+  /// Prints the warning of a command marked with `#[command(deprecated)]` in debug builds,
+  /// only on the first call with the given `once`. Returns whether this was the first call.
+  pub fn warn_deprecated_command(once: &std::sync::Once, command: &str, note: &str) -> bool {
+    let mut warned = false;
+    once.call_once(|| {
+      warned = true;
+      if note.is_empty() {
+        tauri_utils::debug_eprintln!("the `{command}` command is deprecated");
+      } else {
+        tauri_utils::debug_eprintln!("the `{command}` command is deprecated: {note}");
+      }
+    });
+    warned
+  }
+
   // ===== impl IpcResponse =====
 
   pub struct ResponseTag;
@@ -330,3 +345,33 @@ pub mod private {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Once;
+
+  use super::private::warn_deprecated_command;
+
+  #[test]
+  fn deprecated_command_warns_once() {
+    static GET_USER: Once = Once::new();
+    static GET_SETTINGS: Once = Once::new();
+
+    assert!(warn_deprecated_command(
+      &GET_USER,
+      "get_user",
+      "use getUserV2"
+    ));
+    assert!(!warn_deprecated_command(
+      &GET_USER,
+      "get_user",
+      "use getUserV2"
+    ));
+    assert!(warn_deprecated_command(&GET_SETTINGS, "get_settings", ""));
+    assert!(!warn_deprecated_command(
+      &GET_USER,
+      "get_user",
+      "use getUserV2"
+    ));
+  }
+}
@@ -110,6 +110,11 @@ pub enum Error {
   #[cfg(all(desktop, feature = "tray-icon"))]
   #[cfg_attr(docsrs, doc(cfg(all(desktop, feature = "tray-icon"))))]
   BadTrayIcon(#[from] tray_icon::BadIcon),
+  /// Global shortcut error.
+  #[error("global shortcut error: {0}")]
+  #[cfg(all(desktop, feature = "global-shortcut"))]
+  #[cfg_attr(docsrs, doc(cfg(all(desktop, feature = "global-shortcut"))))]
+  GlobalShortcut(#[from] global_hotkey::Error),
   /// Path does not have a parent.
   #[error("path does not have a parent")]
   NoParent,
@@ -145,6 +150,9 @@ pub enum Error {
   /// API requires the unstable feature flag.
   #[error("this feature requires the `unstable` flag on Cargo.toml")]
   UnstableFeatureNotSupported,
+  /// A capability added at runtime has scoped permissions.
+  #[error("capabilities added at runtime cannot have scoped permissions")]
+  RuntimeCapabilityScope,
 }
 
 /// `Result<T, ::tauri::Error>`
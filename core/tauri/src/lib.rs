@@ -28,6 +28,7 @@
 //! - **rustls-tls**: Provides TLS support to connect over HTTPS using rustls.
 //! - **process-relaunch-dangerous-allow-symlink-macos**: Allows the [`process::current_binary`] function to allow symlinks on macOS (this is dangerous, see the Security section in the documentation website).
 //! - **tray-icon**: Enables application tray icon APIs. Enabled by default if the `trayIcon` config is defined on the `tauri.conf.json` file.
+//! - **global-shortcut**: Registers the global shortcuts of the `tauri > globalShortcuts` config. Enabled by default if the config is not empty on the `tauri.conf.json` file.
 //! - **macos-private-api**: Enables features only available in **macOS**'s private APIs, currently the `transparent` window functionality and the `fullScreenEnabled` preference setting to `true`. Enabled by default if the `tauri > macosPrivateApi` config flag is set to `true` on the `tauri.conf.json` file.
 //! - **webview-data-url**: Enables usage of data URLs on the webview.
 //! - **compression** *(enabled by default): Enables asset compression. You should only disable this if you want faster compile times in release builds - it produces larger binaries.
@@ -302,6 +303,32 @@ impl<R: runtime::RuntimeHandle<EventLoopMessage>> RuntimeHandle for R {}
 /// If the path is relative, it will be search for relative to the Cargo manifest of the compiling
 /// crate.
 ///
+/// # Asset Chunking
+///
+/// The embedded assets are generated as a single map, which can be slow to compile for apps with a large
+/// number of assets. Passing `asset_chunking = true` splits them into several smaller statics instead,
+/// speeding up the compilation and incremental rebuilds:
+///
+/// ```rust,ignore
+/// let context = tauri::generate_context!("tauri.conf.json", asset_chunking = true);
+/// ```
+///
+/// The assets behave exactly the same at runtime, a lookup only checks a few more maps.
+///
+/// # Live Assets
+///
+/// Embedding the frontend assets makes the app rebuild every time they change. On development builds,
+/// passing `assets = "live"` reads them from the `build > devPath` directory when they are requested instead:
+///
+/// ```rust,ignore
+/// let context = tauri::generate_context!("tauri.conf.json", assets = "live");
+/// ```
+///
+/// The files are served as they are on disk, so the CSP is not injected into the HTML files and inline
+/// scripts are not hashed, and the isolation pattern is not supported. A `devPath` dev server URL is
+/// never embedded, so the option makes no difference there. Release builds (with the `custom-protocol`
+/// feature enabled) ignore this option and always embed the assets.
+///
 /// # Note
 ///
 /// This macro should not be called if you are using [`tauri-build`] to generate the context from
@@ -178,6 +178,23 @@ pub struct Asset {
   pub csp_header: Option<String>,
 }
 
+/// Converts a protocol request path to the path of the asset it refers to.
+fn asset_path(mut path: String) -> String {
+  if path.ends_with('/') {
+    path.pop();
+  }
+  path = percent_encoding::percent_decode(path.as_bytes())
+    .decode_utf8_lossy()
+    .to_string();
+  if path.is_empty() {
+    // if the url is `tauri://localhost`, we should load `index.html`
+    "index.html".to_string()
+  } else {
+    // skip leading `/`
+    path.chars().skip(1).collect::<String>()
+  }
+}
+
 #[default_runtime(crate::Wry, wry)]
 pub struct AppManager<R: Runtime> {
   pub runtime_authority: RuntimeAuthority,
@@ -327,34 +344,18 @@ impl<R: Runtime> AppManager<R> {
   }
 
   fn csp(&self) -> Option<Csp> {
-    if cfg!(feature = "custom-protocol") {
-      self.config.tauri.security.csp.clone()
+    let security = &self.config.tauri.security;
+    let csp = if cfg!(feature = "custom-protocol") {
+      security.csp.clone()
     } else {
-      self
-        .config
-        .tauri
-        .security
-        .dev_csp
-        .clone()
-        .or_else(|| self.config.tauri.security.csp.clone())
-    }
+      security.dev_csp.clone().or_else(|| security.csp.clone())
+    };
+    csp.map(|csp| security.with_csp_reporting(csp))
   }
 
-  pub fn get_asset(&self, mut path: String) -> Result<Asset, Box<dyn std::error::Error>> {
+  pub fn get_asset(&self, path: String) -> Result<Asset, Box<dyn std::error::Error>> {
     let assets = &self.assets;
-    if path.ends_with('/') {
-      path.pop();
-    }
-    path = percent_encoding::percent_decode(path.as_bytes())
-      .decode_utf8_lossy()
-      .to_string();
-    let path = if path.is_empty() {
-      // if the url is `tauri://localhost`, we should load `index.html`
-      "index.html".to_string()
-    } else {
-      // skip leading `/`
-      path.chars().skip(1).collect::<String>()
-    };
+    let path = asset_path(path);
 
     let mut asset_path = AssetKey::from(path.as_str());
 
@@ -417,6 +418,29 @@ impl<R: Runtime> AppManager<R> {
     }
   }
 
+  /// Gets the path and the length in bytes of the asset at the given request path.
+  ///
+  /// Unlike [`Self::get_asset`], there is no fallback to HTML files.
+  pub fn get_asset_len(&self, path: String) -> Option<(String, u64)> {
+    let path = asset_path(path);
+    let len = self.assets.content_len(&path.as_str().into())?;
+    Some((path, len))
+  }
+
+  /// Gets a reader over the asset at the given request path starting `offset` bytes into it,
+  /// to stream it instead of loading it into memory.
+  ///
+  /// Unlike [`Self::get_asset`], there is no fallback to HTML files and the CSP is not injected.
+  pub fn get_asset_reader(
+    &self,
+    path: String,
+    offset: u64,
+  ) -> Option<Box<dyn std::io::Read + Send + '_>> {
+    self
+      .assets
+      .get_reader(&asset_path(path).as_str().into(), offset)
+  }
+
   pub(crate) fn listeners(&self) -> &Listeners<R> {
     &self.listeners
   }
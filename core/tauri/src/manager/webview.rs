@@ -13,7 +13,10 @@ use std::{
 use serde::Serialize;
 use serialize_to_javascript::{default_template, DefaultTemplate, Template};
 use tauri_runtime::webview::{DetachedWebview, PendingWebview};
-use tauri_utils::config::WebviewUrl;
+use tauri_utils::{
+  config::{is_navigation_allowed, WebviewUrl},
+  debug_eprintln, PackageInfo,
+};
 use url::Url;
 
 use crate::{
@@ -120,7 +123,10 @@ impl<R: Runtime> WebviewManager<R> {
   ) -> crate::Result<PendingWebview<EventLoopMessage, R>> {
     let app_manager = manager.manager();
 
-    let is_init_global = app_manager.config.build.with_global_tauri;
+    let is_init_global = pending
+      .webview_attributes
+      .with_global_tauri
+      .unwrap_or(app_manager.config.build.with_global_tauri);
     let plugin_init = app_manager
       .plugins
       .lock()
@@ -133,6 +139,16 @@ impl<R: Runtime> WebviewManager<R> {
     .render_default(&Default::default())?;
 
     let mut webview_attributes = pending.webview_attributes;
+    if webview_attributes.user_agent.is_none() {
+      webview_attributes.user_agent = app_manager.config.tauri.default_user_agent.clone();
+    }
+    if let Some(user_agent) = &webview_attributes.user_agent {
+      webview_attributes.user_agent =
+        Some(render_user_agent(user_agent, &app_manager.package_info));
+    }
+    // the scripts set by the user (e.g. `WindowConfig::initialization_script`) must run after the Tauri scripts
+    let user_initialization_scripts =
+      std::mem::take(&mut webview_attributes.initialization_scripts);
 
     let ipc_init = IpcJavascript {
       isolation_origin: &match &*app_manager.pattern {
@@ -199,6 +215,22 @@ impl<R: Runtime> WebviewManager<R> {
       );
     }
 
+    if !webview_attributes.context_menu {
+      webview_attributes = webview_attributes
+        .initialization_script(include_str!("../../scripts/disable-context-menu.js"));
+    }
+
+    #[cfg(target_os = "linux")]
+    if webview_attributes.client_side_decorations {
+      webview_attributes = webview_attributes
+        .transparent(true)
+        .initialization_script(include_str!("../../scripts/client-side-decorations.js"));
+    }
+
+    webview_attributes
+      .initialization_scripts
+      .extend(user_initialization_scripts);
+
     pending.webview_attributes = webview_attributes;
 
     let mut registered_scheme_protocols = Vec::new();
@@ -290,7 +322,15 @@ impl<R: Runtime> WebviewManager<R> {
         .get::<crate::Scopes>()
         .asset_protocol
         .clone();
-      let protocol = crate::protocol::asset::get(asset_scope.clone(), window_origin.clone());
+      let mime_overrides = app_manager
+        .config()
+        .tauri
+        .security
+        .asset_protocol
+        .mime_overrides
+        .clone();
+      let protocol =
+        crate::protocol::asset::get(asset_scope.clone(), window_origin.clone(), mime_overrides);
       pending.register_uri_scheme_protocol("asset", move |request, responder| {
         protocol(request, UriSchemeResponder(responder))
       });
@@ -304,7 +344,17 @@ impl<R: Runtime> WebviewManager<R> {
       crypto_keys,
     } = &*app_manager.pattern
     {
-      let protocol = crate::protocol::isolation::get(assets.clone(), *crypto_keys.aes_gcm().raw());
+      let response_headers = match &app_manager.config.tauri.pattern {
+        tauri_utils::config::PatternKind::Isolation {
+          response_headers, ..
+        } => response_headers.clone(),
+        _ => Default::default(),
+      };
+      let protocol = crate::protocol::isolation::get(
+        assets.clone(),
+        *crypto_keys.aes_gcm().raw(),
+        response_headers,
+      );
       pending.register_uri_scheme_protocol(schema, move |request, responder| {
         protocol(request, UriSchemeResponder(responder))
       });
@@ -393,7 +443,6 @@ impl<R: Runtime> WebviewManager<R> {
 
     let app_manager = manager.manager();
 
-    #[allow(unused_mut)] // mut url only for the data-url parsing
     let mut url = match &pending.webview_attributes.url {
       WebviewUrl::App(path) => {
         let url = if PROXY_DEV_SERVER {
@@ -425,6 +474,17 @@ impl<R: Runtime> WebviewManager<R> {
       _ => unimplemented!(),
     };
 
+    if let Some(query) = &pending.webview_attributes.url_query {
+      let query = match url.query() {
+        Some(existing) if !existing.is_empty() => format!("{existing}&{query}"),
+        _ => query.clone(),
+      };
+      url.set_query(Some(&query));
+    }
+    if let Some(fragment) = &pending.webview_attributes.url_fragment {
+      url.set_fragment(Some(fragment));
+    }
+
     #[cfg(not(feature = "webview-data-url"))]
     if url.scheme() == "data" {
       return Err(crate::Error::InvalidWebviewUrl(
@@ -515,7 +575,9 @@ impl<R: Runtime> WebviewManager<R> {
     #[cfg(feature = "isolation")]
     let pattern = app_manager.pattern.clone();
     let navigation_handler = pending.navigation_handler.take();
+    let navigation_allowlist = pending.webview_attributes.navigation_allowlist.clone();
     let app_manager = manager.manager_owned();
+    let app_url = app_manager.get_url().into_owned();
     let label = pending.label.clone();
     pending.navigation_handler = Some(Box::new(move |url| {
       // always allow navigation events for the isolation iframe and do not emit them for consumers
@@ -527,6 +589,12 @@ impl<R: Runtime> WebviewManager<R> {
           return true;
         }
       }
+      if let Some(allowlist) = &navigation_allowlist {
+        if !is_app_url(&app_url, url) && !is_navigation_allowed(allowlist, url) {
+          debug_eprintln!("blocked the navigation of webview `{label}` to `{url}`, which is not in its navigation allowlist");
+          return false;
+        }
+      }
       if let Some(handler) = &navigation_handler {
         if !handler(url) {
           return false;
@@ -597,3 +665,113 @@ impl<R: Runtime> WebviewManager<R> {
     self.webviews_lock().keys().cloned().collect()
   }
 }
+
+/// Replaces the `{{app_name}}`, `{{app_version}}`, `{{os}}` and `{{os_version}}` placeholders of the user agent.
+///
+/// Unknown placeholders are left as is.
+fn render_user_agent(template: &str, package_info: &PackageInfo) -> String {
+  let mut user_agent = String::with_capacity(template.len());
+  let mut rest = template;
+  while let Some(start) = rest.find("{{") {
+    user_agent.push_str(&rest[..start]);
+    let Some(end) = rest[start..].find("}}").map(|end| start + end + 2) else {
+      rest = &rest[start..];
+      break;
+    };
+    let placeholder = &rest[start..end];
+    match placeholder[2..placeholder.len() - 2].trim() {
+      "app_name" => user_agent.push_str(&package_info.name),
+      "app_version" => user_agent.push_str(&package_info.version.to_string()),
+      "os" => user_agent.push_str(std::env::consts::OS),
+      "os_version" => user_agent.push_str(&os_version().unwrap_or_default()),
+      _ => {
+        debug_eprintln!("unknown user agent placeholder `{placeholder}`, leaving it as is");
+        user_agent.push_str(placeholder);
+      }
+    }
+    rest = &rest[end..];
+  }
+  user_agent.push_str(rest);
+  user_agent
+}
+
+/// The version of the operating system, e.g. `14.2.1` on macOS or the kernel release on Linux.
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+fn os_version() -> Option<String> {
+  use objc::{class, msg_send, sel, sel_impl};
+
+  #[repr(C)]
+  struct NSOperatingSystemVersion {
+    major: isize,
+    minor: isize,
+    patch: isize,
+  }
+
+  unsafe {
+    let process_info: cocoa::base::id = msg_send![class!(NSProcessInfo), processInfo];
+    let version: NSOperatingSystemVersion = msg_send![process_info, operatingSystemVersion];
+    Some(format!(
+      "{}.{}.{}",
+      version.major, version.minor, version.patch
+    ))
+  }
+}
+
+/// The version of the operating system, e.g. `10.0.22631` on Windows.
+#[cfg(windows)]
+fn os_version() -> Option<String> {
+  use windows::{
+    Wdk::System::SystemServices::RtlGetVersion, Win32::System::SystemInformation::OSVERSIONINFOW,
+  };
+
+  let mut info = OSVERSIONINFOW {
+    dwOSVersionInfoSize: std::mem::size_of::<OSVERSIONINFOW>() as u32,
+    ..Default::default()
+  };
+  // unlike `GetVersionExW`, the real version is returned even if the app manifest does not declare it supported
+  unsafe { RtlGetVersion(&mut info) }.ok().ok()?;
+  Some(format!(
+    "{}.{}.{}",
+    info.dwMajorVersion, info.dwMinorVersion, info.dwBuildNumber
+  ))
+}
+
+/// The version of the operating system, e.g. the kernel release on Linux and Android.
+#[cfg(not(any(target_os = "macos", target_os = "ios", windows)))]
+fn os_version() -> Option<String> {
+  std::fs::read_to_string("/proc/sys/kernel/osrelease")
+    .ok()
+    .map(|release| release.trim().to_string())
+}
+
+/// Whether the URL points to the app, i.e. has the same scheme, host and port as the app URL.
+fn is_app_url(app_url: &Url, url: &Url) -> bool {
+  url.scheme() == app_url.scheme()
+    && url.host_str() == app_url.host_str()
+    && url.port_or_known_default() == app_url.port_or_known_default()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::render_user_agent;
+  use crate::test::{mock_context, noop_assets};
+
+  #[test]
+  fn user_agent_template() {
+    let context = mock_context(noop_assets());
+    let package_info = context.package_info();
+
+    assert_eq!(
+      render_user_agent("MyApp/{{app_version}}", package_info),
+      "MyApp/0.1.0"
+    );
+    assert_eq!(
+      render_user_agent("{{ app_name }} ({{os}})", package_info),
+      format!("{} ({})", package_info.name, std::env::consts::OS)
+    );
+    assert_eq!(
+      render_user_agent("MyApp/{{unknown}} {{app_version", package_info),
+      "MyApp/{{unknown}} {{app_version"
+    );
+  }
+}
@@ -9,7 +9,7 @@ use crate::{
   command::ScopeValue,
   ipc::{Invoke, InvokeHandler},
   manager::webview::UriSchemeProtocol,
-  utils::config::PluginConfig,
+  utils::{acl::COMMAND_PREFIX_SEPARATOR, config::PluginConfig},
   webview::PageLoadPayload,
   AppHandle, Error, RunEvent, Runtime, Webview, Window,
 };
@@ -226,6 +226,7 @@ impl<R: Runtime, C: DeserializeOwned> PluginApi<R, C> {
 /// ```
 pub struct Builder<R: Runtime, C: DeserializeOwned = ()> {
   name: &'static str,
+  command_prefix: Option<&'static str>,
   invoke_handler: Box<InvokeHandler<R>>,
   setup: Option<Box<SetupHook<R, C>>>,
   js_init_script: Option<String>,
@@ -243,6 +244,7 @@ impl<R: Runtime, C: DeserializeOwned> Builder<R, C> {
   pub fn new(name: &'static str) -> Self {
     Self {
       name,
+      command_prefix: None,
       setup: None,
       js_init_script: None,
       invoke_handler: Box::new(|_| false),
@@ -288,6 +290,23 @@ impl<R: Runtime, C: DeserializeOwned> Builder<R, C> {
     self
   }
 
+  /// Namespaces the plugin commands with the given prefix,
+  /// so they are invoked as `plugin:<name>|<prefix>:<command>` (e.g. `plugin:my-plugin|fs:read_file`).
+  ///
+  /// The prefix is stripped before the command reaches the [`Self::invoke_handler`],
+  /// and commands invoked without it are rejected.
+  /// It must match the prefix given to the `tauri-plugin` build script builder,
+  /// which exposes it as the `TAURI_PLUGIN_COMMAND_PREFIX` environment variable:
+  ///
+  /// ```rust,ignore
+  /// Builder::new("example").command_prefix(env!("TAURI_PLUGIN_COMMAND_PREFIX"))
+  /// ```
+  #[must_use]
+  pub fn command_prefix(mut self, prefix: &'static str) -> Self {
+    self.command_prefix.replace(prefix);
+    self
+  }
+
   /// Sets the provided JavaScript to be run after the global object has been created,
   /// but before the HTML document has been parsed and before any other script included by the HTML document is run.
   ///
@@ -621,6 +640,7 @@ impl<R: Runtime, C: DeserializeOwned> Builder<R, C> {
   pub fn build(self) -> TauriPlugin<R, C> {
     TauriPlugin {
       name: self.name,
+      command_prefix: self.command_prefix,
       app: None,
       invoke_handler: self.invoke_handler,
       setup: self.setup,
@@ -639,6 +659,7 @@ impl<R: Runtime, C: DeserializeOwned> Builder<R, C> {
 /// Plugin struct that is returned by the [`Builder`]. Should only be constructed through the builder.
 pub struct TauriPlugin<R: Runtime, C: DeserializeOwned = ()> {
   name: &'static str,
+  command_prefix: Option<&'static str>,
   app: Option<AppHandle<R>>,
   invoke_handler: Box<InvokeHandler<R>>,
   setup: Option<Box<SetupHook<R, C>>>,
@@ -716,7 +737,25 @@ impl<R: Runtime, C: DeserializeOwned> Plugin<R> for TauriPlugin<R, C> {
     (self.on_event)(app, event)
   }
 
-  fn extend_api(&mut self, invoke: Invoke<R>) -> bool {
+  fn extend_api(&mut self, mut invoke: Invoke<R>) -> bool {
+    if let Some(prefix) = self.command_prefix {
+      let command = invoke
+        .message
+        .command
+        .strip_prefix(prefix)
+        .and_then(|c| c.strip_prefix(COMMAND_PREFIX_SEPARATOR))
+        .map(ToString::to_string);
+      match command {
+        Some(command) => invoke.message.command = command,
+        None => {
+          let command = invoke.message.command.clone();
+          invoke
+            .resolver
+            .reject(format!("Command {command} not found"));
+          return true;
+        }
+      }
+    }
     (self.invoke_handler)(invoke)
   }
 }
@@ -5,7 +5,11 @@
 //! Types and functions related to child processes management.
 
 use crate::Env;
+#[cfg(windows)]
+use crate::{utils::config::WindowsInstallScope, AppHandle, Manager, Runtime};
 
+#[cfg(windows)]
+use std::path::Path;
 use std::path::PathBuf;
 
 /// Finds the current running binary's path.
@@ -83,3 +87,149 @@ pub fn restart(env: &Env) {
 
   exit(0);
 }
+
+/// The scope the app was installed in, as recorded by the NSIS or MSI installer
+/// in the `InstallScope` value of the `Software\{manufacturer}\{productName}` registry key.
+///
+/// Returns `None` if the app was not installed by one of the Tauri installers, e.g. in development.
+#[cfg(windows)]
+pub fn install_scope<R: Runtime>(app: &AppHandle<R>) -> Option<WindowsInstallScope> {
+  use windows::Win32::System::Registry::{HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE};
+
+  let key = format!(
+    "Software\\{}\\{}",
+    app.config().tauri.bundle.manufacturer(),
+    app.package_info().name
+  );
+  [HKEY_LOCAL_MACHINE, HKEY_CURRENT_USER]
+    .into_iter()
+    .find_map(|root| read_registry_string(root, &key, "InstallScope"))
+    .and_then(|scope| serde_json::from_value(serde_json::Value::String(scope)).ok())
+}
+
+/// Runs the installer of an update with the given arguments, e.g. the installer downloaded by an updater plugin.
+///
+/// If [`respect_install_scope`](crate::utils::config::UpdaterWindowsConfig::respect_install_scope) is enabled,
+/// the app was installed per-machine (see [`install_scope`]) and the app runs without administrator privileges,
+/// the installer is launched elevated and Windows asks the user for consent with the User Account Control prompt.
+/// Otherwise the installer is spawned with the privileges of the app.
+///
+/// Returns an error if the installer could not be started, including when the user declines the prompt.
+#[cfg(windows)]
+pub fn run_update_installer<R: Runtime>(
+  app: &AppHandle<R>,
+  installer: &Path,
+  args: &[&str],
+) -> std::io::Result<()> {
+  let elevate = install_scope(app).is_some_and(|scope| {
+    app
+      .config()
+      .tauri
+      .bundle
+      .updater
+      .windows
+      .requires_elevation(scope, is_elevated())
+  });
+
+  if elevate {
+    use windows::{
+      core::{HSTRING, PCWSTR},
+      Win32::{Foundation::HWND, UI::Shell::ShellExecuteW, UI::WindowsAndMessaging::SW_SHOW},
+    };
+
+    let parameters = args
+      .iter()
+      .map(|arg| quote_argument(arg))
+      .collect::<Vec<_>>()
+      .join(" ");
+    let instance = unsafe {
+      ShellExecuteW(
+        HWND::default(),
+        &HSTRING::from("runas"),
+        &HSTRING::from(installer),
+        &HSTRING::from(parameters),
+        PCWSTR::null(),
+        SW_SHOW,
+      )
+    };
+    // values up to 32 are error codes
+    if instance.0 <= 32 {
+      return Err(std::io::Error::last_os_error());
+    }
+  } else {
+    std::process::Command::new(installer).args(args).spawn()?;
+  }
+
+  Ok(())
+}
+
+/// Reads a string value of a registry key, returning `None` if the key or value does not exist.
+#[cfg(windows)]
+fn read_registry_string(
+  root: windows::Win32::System::Registry::HKEY,
+  key: &str,
+  value: &str,
+) -> Option<String> {
+  use windows::{
+    core::HSTRING,
+    Win32::System::Registry::{RegGetValueW, RRF_RT_REG_SZ},
+  };
+
+  let mut buffer = [0u16; 64];
+  let mut len = std::mem::size_of_val(&buffer) as u32;
+  unsafe {
+    RegGetValueW(
+      root,
+      &HSTRING::from(key),
+      &HSTRING::from(value),
+      RRF_RT_REG_SZ,
+      None,
+      Some(buffer.as_mut_ptr().cast()),
+      Some(&mut len),
+    )
+  }
+  .ok()?;
+
+  // the length is in bytes and includes the nul terminator
+  let len = (len as usize / 2).saturating_sub(1);
+  String::from_utf16(&buffer[..len]).ok()
+}
+
+/// Whether the current process runs with administrator privileges.
+#[cfg(windows)]
+fn is_elevated() -> bool {
+  use windows::Win32::{
+    Foundation::{CloseHandle, HANDLE},
+    Security::{GetTokenInformation, TokenElevation, TOKEN_ELEVATION, TOKEN_QUERY},
+    System::Threading::{GetCurrentProcess, OpenProcessToken},
+  };
+
+  unsafe {
+    let mut token = HANDLE::default();
+    if OpenProcessToken(GetCurrentProcess(), TOKEN_QUERY, &mut token).is_err() {
+      return false;
+    }
+
+    let mut elevation = TOKEN_ELEVATION::default();
+    let mut len = 0;
+    let result = GetTokenInformation(
+      token,
+      TokenElevation,
+      Some(&mut elevation as *mut TOKEN_ELEVATION as *mut _),
+      std::mem::size_of::<TOKEN_ELEVATION>() as u32,
+      &mut len,
+    );
+    let _ = CloseHandle(token);
+
+    result.is_ok() && elevation.TokenIsElevated != 0
+  }
+}
+
+/// Quotes a command line argument for `ShellExecuteW` if it contains whitespace or quotes.
+#[cfg(windows)]
+fn quote_argument(arg: &str) -> String {
+  if !arg.is_empty() && !arg.contains([' ', '\t', '"']) {
+    return arg.into();
+  }
+  format!("\"{}\"", arg.replace('"', "\\\""))
+}
@@ -5,15 +5,19 @@
 use crate::{path::SafePathBuf, scope, webview::UriSchemeProtocolHandler};
 use http::{header::*, status::StatusCode, Request, Response};
 use http_range::HttpRange;
-use std::{borrow::Cow, io::SeekFrom};
+use std::{borrow::Cow, collections::HashMap, io::SeekFrom, path::Path};
 use tauri_utils::debug_eprintln;
 use tauri_utils::mime_type::MimeType;
 use tokio::fs::File;
 use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
 
-pub fn get(scope: scope::fs::Scope, window_origin: String) -> UriSchemeProtocolHandler {
-  Box::new(
-    move |request, responder| match get_response(request, &scope, &window_origin) {
+pub fn get(
+  scope: scope::fs::Scope,
+  window_origin: String,
+  mime_overrides: HashMap<String, String>,
+) -> UriSchemeProtocolHandler {
+  Box::new(move |request, responder| {
+    match get_response(request, &scope, &window_origin, &mime_overrides) {
       Ok(response) => responder.respond(response),
       Err(e) => responder.respond(
         http::Response::builder()
@@ -23,14 +27,15 @@ pub fn get(scope: scope::fs::Scope, window_origin: String) -> UriSchemeProtocolH
           .body(e.to_string().as_bytes().to_vec())
           .unwrap(),
       ),
-    },
-  )
+    }
+  })
 }
 
 fn get_response(
   request: Request<Vec<u8>>,
   scope: &scope::fs::Scope,
   window_origin: &str,
+  mime_overrides: &HashMap<String, String>,
 ) -> Result<Response<Cow<'static, [u8]>>, Box<dyn std::error::Error>> {
   // skip leading `/`
   let path = percent_encoding::percent_decode(request.uri().path()[1..].as_bytes())
@@ -49,6 +54,13 @@ fn get_response(
     return resp.status(403).body(Vec::new().into()).map_err(Into::into);
   }
 
+  // configured MIME types take precedence over sniffing
+  let mime_override = Path::new(&path)
+    .extension()
+    .and_then(|ext| ext.to_str())
+    .and_then(|ext| mime_overrides.get(&ext.to_ascii_lowercase()))
+    .cloned();
+
   let (mut file, len, mime_type, read_bytes) = crate::async_runtime::safe_block_on(async move {
     let mut file = File::open(&path).await?;
 
@@ -68,7 +80,7 @@ fn get_response(
       (&mut file).take(nbytes).read_to_end(&mut magic_buf).await?;
       file.seek(SeekFrom::Start(old_pos)).await?;
       (
-        MimeType::parse(&magic_buf, &path),
+        mime_override.unwrap_or_else(|| MimeType::parse(&magic_buf, &path)),
         // return the `magic_bytes` if we read the whole file
         // to avoid reading it again later if this is not a range request
         if len < 8192 { Some(magic_buf) } else { None },
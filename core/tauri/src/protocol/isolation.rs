@@ -2,47 +2,33 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
-use http::header::CONTENT_TYPE;
+use http::header::{HeaderName, HeaderValue, CONTENT_TYPE};
 use serialize_to_javascript::Template;
 use tauri_utils::assets::{Assets, EmbeddedAssets};
 
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use crate::{manager::webview::PROCESS_IPC_MESSAGE_FN, webview::UriSchemeProtocolHandler};
 
-pub fn get(assets: Arc<EmbeddedAssets>, aes_gcm_key: [u8; 32]) -> UriSchemeProtocolHandler {
-  Box::new(move |request, responder| {
-    let response = match request_to_path(&request).as_str() {
-      "index.html" => match assets.get(&"index.html".into()) {
-        Some(asset) => {
-          let asset = String::from_utf8_lossy(asset.as_ref());
-          let template = tauri_utils::pattern::isolation::IsolationJavascriptRuntime {
-            runtime_aes_gcm_key: &aes_gcm_key,
-            process_ipc_message_fn: PROCESS_IPC_MESSAGE_FN,
-          };
-          match template.render(asset.as_ref(), &Default::default()) {
-            Ok(asset) => http::Response::builder()
-              .header(CONTENT_TYPE, mime::TEXT_HTML.as_ref())
-              .body(asset.into_string().as_bytes().to_vec()),
-            Err(_) => http::Response::builder()
-              .status(http::StatusCode::INTERNAL_SERVER_ERROR)
-              .header(CONTENT_TYPE, mime::TEXT_PLAIN.as_ref())
-              .body(Vec::new()),
-          }
-        }
-
-        None => http::Response::builder()
-          .status(http::StatusCode::NOT_FOUND)
-          .header(CONTENT_TYPE, mime::TEXT_PLAIN.as_ref())
-          .body(Vec::new()),
-      },
-      _ => http::Response::builder()
-        .status(http::StatusCode::NOT_FOUND)
-        .header(CONTENT_TYPE, mime::TEXT_PLAIN.as_ref())
-        .body(Vec::new()),
-    };
+/// Creates the protocol serving the isolation iframe, adding the `response_headers` to its document.
+pub fn get(
+  assets: Arc<EmbeddedAssets>,
+  aes_gcm_key: [u8; 32],
+  response_headers: HashMap<String, String>,
+) -> UriSchemeProtocolHandler {
+  // the configuration rejects the values `HeaderValue` does not accept, but they may contain non-ASCII characters
+  let response_headers = response_headers
+    .iter()
+    .filter_map(|(name, value)| {
+      Some((
+        HeaderName::from_bytes(name.as_bytes()).ok()?,
+        HeaderValue::from_bytes(value.as_bytes()).ok()?,
+      ))
+    })
+    .collect::<Vec<_>>();
 
-    if let Ok(r) = response {
+  Box::new(move |request, responder| {
+    if let Ok(r) = get_response(&request, &*assets, &aes_gcm_key, &response_headers) {
       responder.respond(r);
     } else {
       responder.respond(
@@ -55,6 +41,50 @@ pub fn get(assets: Arc<EmbeddedAssets>, aes_gcm_key: [u8; 32]) -> UriSchemeProto
   })
 }
 
+fn get_response<A: Assets>(
+  request: &http::Request<Vec<u8>>,
+  assets: &A,
+  aes_gcm_key: &[u8; 32],
+  response_headers: &[(HeaderName, HeaderValue)],
+) -> http::Result<http::Response<Vec<u8>>> {
+  match request_to_path(request).as_str() {
+    "index.html" => match assets.get(&"index.html".into()) {
+      Some(asset) => {
+        let asset = String::from_utf8_lossy(asset.as_ref());
+        let template = tauri_utils::pattern::isolation::IsolationJavascriptRuntime {
+          runtime_aes_gcm_key: aes_gcm_key,
+          process_ipc_message_fn: PROCESS_IPC_MESSAGE_FN,
+        };
+        match template.render(asset.as_ref(), &Default::default()) {
+          Ok(asset) => {
+            let mut response =
+              http::Response::builder().header(CONTENT_TYPE, mime::TEXT_HTML.as_ref());
+            if let Some(headers) = response.headers_mut() {
+              for (name, value) in response_headers {
+                headers.insert(name.clone(), value.clone());
+              }
+            }
+            response.body(asset.into_string().as_bytes().to_vec())
+          }
+          Err(_) => http::Response::builder()
+            .status(http::StatusCode::INTERNAL_SERVER_ERROR)
+            .header(CONTENT_TYPE, mime::TEXT_PLAIN.as_ref())
+            .body(Vec::new()),
+        }
+      }
+
+      None => http::Response::builder()
+        .status(http::StatusCode::NOT_FOUND)
+        .header(CONTENT_TYPE, mime::TEXT_PLAIN.as_ref())
+        .body(Vec::new()),
+    },
+    _ => http::Response::builder()
+      .status(http::StatusCode::NOT_FOUND)
+      .header(CONTENT_TYPE, mime::TEXT_PLAIN.as_ref())
+      .body(Vec::new()),
+  }
+}
+
 fn request_to_path(request: &http::Request<Vec<u8>>) -> String {
   let path = request
     .uri()
@@ -74,3 +104,39 @@ fn request_to_path(request: &http::Request<Vec<u8>>) -> String {
     path.chars().skip(1).collect()
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test::DirAssets;
+
+  #[test]
+  fn response_headers() {
+    let root = std::env::temp_dir().join(format!("tauri-isolation-{}", std::process::id()));
+    std::fs::create_dir_all(&root).unwrap();
+    std::fs::write(root.join("index.html"), "<html></html>").unwrap();
+    let assets = DirAssets::new(root.clone());
+
+    let response_headers = [
+      (
+        HeaderName::from_static("x-frame-options"),
+        HeaderValue::from_static("DENY"),
+      ),
+      (
+        HeaderName::from_static("x-note"),
+        HeaderValue::from_bytes("café".as_bytes()).unwrap(),
+      ),
+    ];
+    let request = http::Request::builder()
+      .uri("isolation://localhost/")
+      .body(Vec::new())
+      .unwrap();
+    let response = get_response(&request, &assets, &[0; 32], &response_headers).unwrap();
+    std::fs::remove_dir_all(root).unwrap();
+
+    assert_eq!(response.status(), http::StatusCode::OK);
+    assert_eq!(response.headers()["x-frame-options"], "DENY");
+    assert_eq!(response.headers()["x-note"].as_bytes(), "café".as_bytes());
+    assert_eq!(response.headers()[CONTENT_TYPE], mime::TEXT_HTML.as_ref());
+  }
+}
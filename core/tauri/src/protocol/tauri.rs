@@ -5,6 +5,13 @@
 use std::{borrow::Cow, sync::Arc};
 
 use http::{header::CONTENT_TYPE, Request, Response as HttpResponse, StatusCode};
+#[cfg(not(all(dev, mobile)))]
+use http::{
+  header::{ACCEPT_RANGES, CONTENT_LENGTH, CONTENT_RANGE, IF_RANGE, RANGE},
+  response::Builder as HttpResponseBuilder,
+};
+#[cfg(not(all(dev, mobile)))]
+use std::io::Read;
 
 use crate::{
   manager::{webview::PROXY_DEV_SERVER, AppManager},
@@ -153,11 +160,28 @@ fn get_response<R: Runtime>(
   };
 
   #[cfg(not(all(dev, mobile)))]
-  let mut response = {
+  let range = request
+    .headers()
+    .get(RANGE)
+    // the protocol does not provide validators, so `If-Range` never matches and the full asset is sent
+    .filter(|_| !request.headers().contains_key(IF_RANGE))
+    .and_then(|range| range.to_str().ok())
+    .map(ToString::to_string);
+
+  #[cfg(not(all(dev, mobile)))]
+  let mut response = if let Some(response) = range
+    .filter(|_| !path.ends_with(".html"))
+    .and_then(|range| get_range_response(manager, &path, &range, window_origin))
+  {
+    response?
+  } else {
     let asset = manager.get_asset(path)?;
     builder = builder.header(CONTENT_TYPE, &asset.mime_type);
     if let Some(csp) = &asset.csp_header {
       builder = builder.header("Content-Security-Policy", csp);
+      if let Some(endpoints) = manager.config().tauri.security.csp_reporting_endpoints() {
+        builder = builder.header("Reporting-Endpoints", endpoints);
+      }
     }
     builder.body(asset.bytes.into())?
   };
@@ -175,3 +199,189 @@ fn get_response<R: Runtime>(
 
   Ok(response)
 }
+
+/// The maximum number of bytes sent in response to a single range request.
+#[cfg(not(all(dev, mobile)))]
+const MAX_RANGE_LEN: u64 = 1000 * 1024;
+
+/// Responds to a `Range` request by streaming only the requested bytes of the asset at `path`.
+///
+/// Returns `None` when the asset is not found, so the request is served by [`AppManager::get_asset`] instead.
+#[cfg(not(all(dev, mobile)))]
+fn get_range_response<R: Runtime>(
+  manager: &AppManager<R>,
+  path: &str,
+  range: &str,
+  window_origin: &str,
+) -> Option<Result<HttpResponse<Cow<'static, [u8]>>, Box<dyn std::error::Error>>> {
+  let (asset_path, len) = manager.get_asset_len(path.to_string())?;
+  let builder = HttpResponse::builder()
+    .header("Access-Control-Allow-Origin", window_origin)
+    .header(
+      CONTENT_TYPE,
+      tauri_utils::mime_type::MimeType::parse_from_uri(&asset_path).to_string(),
+    );
+  Some(range_response(
+    range,
+    len,
+    |offset| manager.get_asset_reader(path.to_string(), offset),
+    builder,
+  ))
+}
+
+/// Builds the `206 Partial Content` response to the `range` header value for an asset of `len` bytes,
+/// reading the asset with the reader returned by `reader` for the offset of the range.
+///
+/// Only the first range of a multi-range request is served, and at most [`MAX_RANGE_LEN`] bytes are sent.
+/// Unsatisfiable ranges get a `416 Range Not Satisfiable` response.
+#[cfg(not(all(dev, mobile)))]
+fn range_response<T: Read>(
+  range: &str,
+  len: u64,
+  reader: impl FnOnce(u64) -> Option<T>,
+  builder: HttpResponseBuilder,
+) -> Result<HttpResponse<Cow<'static, [u8]>>, Box<dyn std::error::Error>> {
+  let builder = builder.header(ACCEPT_RANGES, "bytes");
+
+  let Some((start, nbytes)) = parse_range(range, len) else {
+    return builder
+      .status(StatusCode::RANGE_NOT_SATISFIABLE)
+      .header(CONTENT_RANGE, format!("bytes */{len}"))
+      .body(Vec::new().into())
+      .map_err(Into::into);
+  };
+
+  let nbytes = nbytes.min(MAX_RANGE_LEN);
+  let end = start + nbytes - 1;
+
+  let reader = reader(start).ok_or("failed to read the asset")?;
+  let mut buf = Vec::with_capacity(nbytes as usize);
+  reader.take(nbytes).read_to_end(&mut buf)?;
+
+  builder
+    .status(StatusCode::PARTIAL_CONTENT)
+    .header(CONTENT_RANGE, format!("bytes {start}-{end}/{len}"))
+    .header(CONTENT_LENGTH, nbytes)
+    .body(buf.into())
+    .map_err(Into::into)
+}
+
+/// Parses the first range of a `Range` header value for an asset of `len` bytes into its start and length.
+///
+/// Returns `None` when the header is malformed or the range is not satisfiable.
+#[cfg(not(all(dev, mobile)))]
+fn parse_range(range: &str, len: u64) -> Option<(u64, u64)> {
+  let first = range.trim().strip_prefix("bytes=")?.split(',').next()?;
+  let (start, end) = first.split_once('-')?;
+  let (start, end) = (start.trim(), end.trim());
+  if start.is_empty() {
+    // suffix range: the last `end` bytes
+    let suffix = end.parse::<u64>().ok()?.min(len);
+    (suffix > 0).then(|| (len - suffix, suffix))
+  } else {
+    let start = start.parse::<u64>().ok()?;
+    let end = if end.is_empty() {
+      len.checked_sub(1)?
+    } else {
+      end.parse::<u64>().ok()?.min(len.checked_sub(1)?)
+    };
+    (start <= end).then(|| (start, end - start + 1))
+  }
+}
+
+#[cfg(all(test, not(all(dev, mobile))))]
+mod tests {
+  use super::*;
+  use crate::test::{mock_builder, mock_context, DirAssets, MockRuntime};
+
+  const ORIGIN: &str = "tauri://localhost";
+
+  fn video() -> Vec<u8> {
+    (0..=u8::MAX).cycle().take(2000).collect()
+  }
+
+  fn request(
+    app: &crate::App<MockRuntime>,
+    headers: &[(&str, &str)],
+  ) -> HttpResponse<Cow<'static, [u8]>> {
+    let mut request = Request::builder().uri("tauri://localhost/video.mp4");
+    for (name, value) in headers {
+      request = request.header(*name, *value);
+    }
+    get_response(
+      request.body(Vec::new()).unwrap(),
+      &app.handle().manager,
+      ORIGIN,
+      None,
+    )
+    .unwrap()
+  }
+
+  fn with_app(name: &str, f: impl FnOnce(&crate::App<MockRuntime>)) {
+    let root = std::env::temp_dir().join(format!("tauri-range-{name}-{}", std::process::id()));
+    std::fs::create_dir_all(&root).unwrap();
+    std::fs::write(root.join("video.mp4"), video()).unwrap();
+    let app = mock_builder()
+      .build(mock_context(DirAssets::new(root.clone())))
+      .unwrap();
+    f(&app);
+    std::fs::remove_dir_all(root).unwrap();
+  }
+
+  #[test]
+  fn valid_range() {
+    with_app("valid", |app| {
+      let response = request(app, &[("Range", "bytes=100-199")]);
+      assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+      assert_eq!(response.headers()[CONTENT_RANGE], "bytes 100-199/2000");
+      assert_eq!(response.headers()[CONTENT_LENGTH], "100");
+      assert_eq!(response.headers()[CONTENT_TYPE], "video/mp4");
+      assert_eq!(response.body().as_ref(), &video()[100..200]);
+
+      // only the first range of a multi-range request is served
+      let response = request(app, &[("Range", "bytes=1990-, 0-9")]);
+      assert_eq!(response.headers()[CONTENT_RANGE], "bytes 1990-1999/2000");
+      assert_eq!(response.body().as_ref(), &video()[1990..]);
+    });
+  }
+
+  #[test]
+  fn parse_ranges() {
+    assert_eq!(parse_range("bytes=0-99", 2000), Some((0, 100)));
+    assert_eq!(parse_range("bytes=1990-", 2000), Some((1990, 10)));
+    assert_eq!(parse_range("bytes=1990-3000", 2000), Some((1990, 10)));
+    assert_eq!(parse_range("bytes=-10", 2000), Some((1990, 10)));
+    assert_eq!(parse_range("bytes=-3000", 2000), Some((0, 2000)));
+    assert_eq!(parse_range("bytes=10-19, 30-39", 2000), Some((10, 10)));
+    assert_eq!(parse_range("bytes=2000-", 2000), None);
+    assert_eq!(parse_range("bytes=20-10", 2000), None);
+    assert_eq!(parse_range("bytes=-0", 2000), None);
+    assert_eq!(parse_range("bytes=0-", 0), None);
+    assert_eq!(parse_range("items=0-99", 2000), None);
+    assert_eq!(parse_range("bytes=a-b", 2000), None);
+  }
+
+  #[test]
+  fn unsatisfiable_range() {
+    with_app("unsatisfiable", |app| {
+      let response = request(app, &[("Range", "bytes=2000-2100")]);
+      assert_eq!(response.status(), StatusCode::RANGE_NOT_SATISFIABLE);
+      assert_eq!(response.headers()[CONTENT_RANGE], "bytes */2000");
+      assert!(response.body().is_empty());
+    });
+  }
+
+  #[test]
+  fn full_request_fallback() {
+    with_app("full", |app| {
+      let response = request(app, &[]);
+      assert_eq!(response.status(), StatusCode::OK);
+      assert_eq!(response.body().as_ref(), video().as_slice());
+
+      // `If-Range` can never match, so the full asset is sent
+      let response = request(app, &[("Range", "bytes=100-199"), ("If-Range", "\"abc\"")]);
+      assert_eq!(response.status(), StatusCode::OK);
+      assert_eq!(response.body().as_ref(), video().as_slice());
+    });
+  }
+}
@@ -36,6 +36,7 @@ pub struct Scope {
   forbidden_patterns: Arc<Mutex<HashSet<Pattern>>>,
   event_listeners: Arc<Mutex<HashMap<ScopeEventId, EventListener>>>,
   match_options: glob::MatchOptions,
+  resolve_symlinks: bool,
   next_event_id: Arc<AtomicU32>,
 }
 
@@ -176,6 +177,7 @@ impl Scope {
         require_literal_leading_dot,
         ..Default::default()
       },
+      resolve_symlinks: scope.resolve_symlinks(),
     })
   }
 
@@ -296,39 +298,93 @@ impl Scope {
 
   /// Determines if the given path is allowed on this scope.
   pub fn is_allowed<P: AsRef<Path>>(&self, path: P) -> bool {
+    self.explain(path).allowed
+  }
+
+  /// Determines if the given path is allowed on this scope, with the patterns that decided it.
+  ///
+  /// Useful to find out why a path is denied, see [`ScopeDecision`].
+  pub fn explain<P: AsRef<Path>>(&self, path: P) -> ScopeDecision {
     let path = path.as_ref();
-    let path = if !path.exists() {
-      crate::Result::Ok(path.to_path_buf())
+    let path = if path.exists() {
+      std::fs::canonicalize(path).ok()
+    } else if self.resolve_symlinks {
+      canonicalize_existing_ancestor(path)
     } else {
-      std::fs::canonicalize(path).map_err(Into::into)
+      Some(path.to_path_buf())
     };
 
-    if let Ok(path) = path {
+    if let Some(path) = path {
       let path: PathBuf = path.components().collect();
-      let forbidden = self
+      let denied_by = self
         .forbidden_patterns
         .lock()
         .unwrap()
         .iter()
-        .any(|p| p.matches_path_with(&path, self.match_options));
-
-      if forbidden {
-        false
-      } else {
-        let allowed = self
-          .allowed_patterns
-          .lock()
-          .unwrap()
-          .iter()
-          .any(|p| p.matches_path_with(&path, self.match_options));
-        allowed
+        .find(|p| p.matches_path_with(&path, self.match_options))
+        .cloned();
+      let allowed_by = self
+        .allowed_patterns
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|p| p.matches_path_with(&path, self.match_options))
+        .cloned();
+      ScopeDecision {
+        allowed: denied_by.is_none() && allowed_by.is_some(),
+        allowed_by,
+        denied_by,
       }
     } else {
-      false
+      ScopeDecision {
+        allowed: false,
+        allowed_by: None,
+        denied_by: None,
+      }
+    }
+  }
+}
+
+/// Whether a path is allowed by a [`Scope`] and the patterns that decided it, see [`Scope::explain`].
+///
+/// The patterns are the ones resolved by the scope, e.g. `$HOME/.ssh/**` is reported as `/home/user/.ssh/**`.
+/// The [`Display`](fmt::Display) implementation describes the decision, e.g. ``denied by pattern `/home/user/.ssh/**` ``.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScopeDecision {
+  /// Whether the path is allowed.
+  pub allowed: bool,
+  /// An allowed pattern matching the path, if any.
+  pub allowed_by: Option<Pattern>,
+  /// A forbidden pattern matching the path, if any. It takes precedence over [`Self::allowed_by`].
+  pub denied_by: Option<Pattern>,
+}
+
+impl fmt::Display for ScopeDecision {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match (&self.denied_by, &self.allowed_by) {
+      (Some(deny), _) => write!(f, "denied by pattern `{}`", deny.as_str()),
+      (None, Some(allow)) => write!(f, "allowed by pattern `{}`", allow.as_str()),
+      (None, None) => write!(f, "denied, no pattern allows it"),
     }
   }
 }
 
+/// Canonicalizes the longest existing ancestor of a path that does not exist, keeping the remaining components as is.
+///
+/// Returns `None` if the remaining components contain a `..`, which could escape the canonicalized ancestor.
+fn canonicalize_existing_ancestor(path: &Path) -> Option<PathBuf> {
+  let mut ancestor = path.to_path_buf();
+  let mut rest = Vec::new();
+  loop {
+    if let Ok(canonicalized) = ancestor.canonicalize() {
+      return Some(rest.into_iter().rev().fold(canonicalized, |p, c| p.join(c)));
+    }
+    // `file_name` is `None` for a trailing `..` and for the root
+    rest.push(ancestor.file_name()?.to_owned());
+    ancestor.pop();
+  }
+}
+
 fn escaped_pattern(p: &str) -> Result<Pattern, glob::PatternError> {
   Pattern::new(&glob::Pattern::escape(p))
 }
@@ -362,6 +418,7 @@ mod tests {
         require_literal_leading_dot: false,
         ..Default::default()
       },
+      resolve_symlinks: false,
     }
   }
 
@@ -461,4 +518,84 @@ mod tests {
       assert!(scope.is_allowed("C:\\home\\tauri\\anyfile"));
     }
   }
+
+  #[cfg(unix)]
+  #[test]
+  fn resolve_symlinks() {
+    let root = std::env::temp_dir().join(format!("tauri-fs-scope-{}", std::process::id()));
+    let allowed = root.join("allowed");
+    let outside = root.join("outside");
+    std::fs::create_dir_all(&allowed).unwrap();
+    std::fs::create_dir_all(&outside).unwrap();
+    std::fs::write(allowed.join("file.txt"), "").unwrap();
+    std::fs::write(outside.join("secret.txt"), "").unwrap();
+    std::os::unix::fs::symlink(&outside, allowed.join("link")).unwrap();
+
+    let scope = |resolve_symlinks| {
+      let scope = Scope {
+        resolve_symlinks,
+        ..new_scope()
+      };
+      scope.allow_directory(&allowed, true).unwrap();
+      scope
+    };
+
+    // existing paths are always canonicalized
+    let lenient = scope(false);
+    assert!(lenient.is_allowed(allowed.join("file.txt")));
+    assert!(!lenient.is_allowed(allowed.join("link").join("secret.txt")));
+    // but new files in a symlinked directory are not
+    assert!(lenient.is_allowed(allowed.join("link").join("new.txt")));
+
+    let strict = scope(true);
+    assert!(strict.is_allowed(allowed.join("file.txt")));
+    assert!(strict.is_allowed(allowed.join("new.txt")));
+    assert!(!strict.is_allowed(allowed.join("link").join("secret.txt")));
+    assert!(!strict.is_allowed(allowed.join("link").join("new.txt")));
+    assert!(!strict.is_allowed(allowed.join("missing").join("..").join("new.txt")));
+
+    std::fs::remove_dir_all(&root).unwrap();
+  }
+
+  #[test]
+  fn explain() {
+    let scope = Scope {
+      match_options: glob::MatchOptions {
+        require_literal_leading_dot: false,
+        ..new_scope().match_options
+      },
+      ..new_scope()
+    };
+    #[cfg(unix)]
+    let root = std::path::PathBuf::from("/home/tauri");
+    #[cfg(windows)]
+    let root = std::path::PathBuf::from("C:\\home\\tauri");
+    scope.allow_directory(&root, true).unwrap();
+    scope.forbid_directory(root.join(".ssh"), true).unwrap();
+
+    let decision = scope.explain(root.join("Documents").join("notes.txt"));
+    assert!(decision.allowed);
+    assert!(decision.allowed_by.is_some());
+    assert_eq!(decision.denied_by, None);
+
+    // forbidden paths take precedence over allowed paths
+    let decision = scope.explain(root.join(".ssh").join("id_rsa"));
+    assert!(!decision.allowed);
+    assert!(decision.allowed_by.is_some());
+    let denied_by = decision.denied_by.clone().unwrap();
+    assert!(denied_by
+      .as_str()
+      .ends_with(&format!(".ssh{}**", super::MAIN_SEPARATOR)));
+    assert_eq!(
+      decision.to_string(),
+      format!("denied by pattern `{}`", denied_by.as_str())
+    );
+
+    // paths matched by no pattern are denied
+    let decision = scope.explain("/etc/passwd");
+    assert!(!decision.allowed);
+    assert_eq!(decision.allowed_by, None);
+    assert_eq!(decision.denied_by, None);
+    assert_eq!(decision.to_string(), "denied, no pattern allows it");
+  }
 }
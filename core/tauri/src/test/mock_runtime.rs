@@ -7,7 +7,7 @@
 
 use tauri_runtime::{
   monitor::Monitor,
-  webview::{DetachedWebview, PendingWebview},
+  webview::{DetachedWebview, PageLoadEvent, PendingWebview},
   window::{
     dpi::{PhysicalPosition, PhysicalSize, Position, Size},
     CursorIcon, DetachedWindow, PendingWindow, RawWindow, WindowEvent, WindowId,
@@ -35,9 +35,74 @@ use std::{
     mpsc::{channel, sync_channel, Receiver, SyncSender},
     Arc, Mutex,
   },
+  time::Duration,
 };
 
 type ShortcutMap = HashMap<String, Box<dyn Fn() + Send + 'static>>;
+/// A `(width, height)` logical size constraint.
+type SizeConstraint = Option<(f64, f64)>;
+type WindowEventHandler = Box<dyn Fn(&WindowEvent) + Send + 'static>;
+
+#[derive(Clone, Default)]
+struct WindowEventListeners(Arc<Mutex<HashMap<WindowEventId, WindowEventHandler>>>);
+
+impl WindowEventListeners {
+  fn emit(&self, event: &WindowEvent) {
+    for handler in self.0.lock().unwrap().values() {
+      handler(event);
+    }
+  }
+}
+
+impl fmt::Debug for WindowEventListeners {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("WindowEventListeners").finish()
+  }
+}
+
+type PageLoadHandler = Box<dyn Fn(Url, PageLoadEvent) + Send + 'static>;
+
+#[derive(Clone)]
+struct PageLoadListener(Arc<Mutex<Option<PageLoadHandler>>>);
+
+impl PageLoadListener {
+  fn new(handler: Option<PageLoadHandler>) -> Self {
+    Self(Arc::new(Mutex::new(handler)))
+  }
+}
+
+impl fmt::Debug for PageLoadListener {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("PageLoadListener").finish()
+  }
+}
+
+type NavigationHandler = Box<dyn Fn(&Url) -> bool + Send>;
+
+#[derive(Clone)]
+struct NavigationListener(Arc<Mutex<Option<NavigationHandler>>>);
+
+impl NavigationListener {
+  fn new(handler: Option<NavigationHandler>) -> Self {
+    Self(Arc::new(Mutex::new(handler)))
+  }
+
+  /// Whether the navigation handler allows navigating to the URL.
+  fn allows(&self, url: &Url) -> bool {
+    self
+      .0
+      .lock()
+      .unwrap()
+      .as_ref()
+      .map_or(true, |handler| handler(url))
+  }
+}
+
+impl fmt::Debug for NavigationListener {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("NavigationListener").finish()
+  }
+}
 
 enum Message {
   Task(Box<dyn FnOnce() + Send>),
@@ -47,7 +112,9 @@ enum Message {
 struct Webview;
 
 struct Window {
+  label: String,
   webviews: Vec<Webview>,
+  event_listeners: WindowEventListeners,
 }
 
 #[derive(Clone)]
@@ -59,6 +126,12 @@ pub struct RuntimeContext {
   next_window_id: Arc<AtomicU32>,
   next_webview_id: Arc<AtomicU32>,
   next_window_event_id: Arc<AtomicU32>,
+  clipboard: Arc<Mutex<Option<String>>>,
+  /// The `(min, max)` delay of the IPC responses.
+  ipc_delay: Arc<Mutex<(Duration, Duration)>>,
+  /// The connected monitors, the first one being the primary monitor.
+  monitors: Arc<Mutex<Vec<Monitor>>>,
+  cursor_position: Arc<Mutex<PhysicalPosition<f64>>>,
 }
 
 // SAFETY: we ensure this type is only used on the main thread.
@@ -80,13 +153,20 @@ impl RuntimeContext {
       match message {
         Message::Task(task) => task(),
         Message::CloseWindow(id) => {
-          self.windows.borrow_mut().remove(&id);
+          self.destroy_window(id);
         }
       }
       Ok(())
     }
   }
 
+  /// Removes the window and triggers [`WindowEvent::Destroyed`] on its listeners, returning its label.
+  fn destroy_window(&self, id: WindowId) -> Option<String> {
+    let window = self.windows.borrow_mut().remove(&id)?;
+    window.event_listeners.emit(&WindowEvent::Destroyed);
+    Some(window.label)
+  }
+
   fn next_window_id(&self) -> WindowId {
     self.next_window_id.fetch_add(1, Ordering::Relaxed).into()
   }
@@ -98,6 +178,14 @@ impl RuntimeContext {
   fn next_window_event_id(&self) -> WindowEventId {
     self.next_window_event_id.fetch_add(1, Ordering::Relaxed)
   }
+
+  fn primary_monitor(&self) -> Option<Monitor> {
+    self.monitors.lock().unwrap().first().cloned()
+  }
+
+  fn available_monitors(&self) -> Vec<Monitor> {
+    self.monitors.lock().unwrap().clone()
+  }
 }
 
 impl fmt::Debug for RuntimeContext {
@@ -111,6 +199,45 @@ pub struct MockRuntimeHandle {
   context: RuntimeContext,
 }
 
+impl MockRuntimeHandle {
+  /// The text in the in-memory clipboard of the runtime.
+  pub fn clipboard_text(&self) -> Option<String> {
+    self.context.clipboard.lock().unwrap().clone()
+  }
+
+  /// Replaces the text in the in-memory clipboard of the runtime.
+  pub fn set_clipboard_text(&self, text: Option<String>) {
+    *self.context.clipboard.lock().unwrap() = text;
+  }
+
+  /// The delay of the next IPC response, picked at random between the configured bounds.
+  pub fn ipc_delay(&self) -> Duration {
+    let (min, max) = *self.context.ipc_delay.lock().unwrap();
+    if max <= min {
+      return min;
+    }
+    let mut raw = [0u8; 8];
+    getrandom::getrandom(&mut raw).expect("failed to get random bytes");
+    let jitter = u64::from_ne_bytes(raw) % (max - min).as_nanos().min(u64::MAX as u128) as u64;
+    min + Duration::from_nanos(jitter)
+  }
+
+  /// Sets the bounds of the IPC response delay, see [`Self::ipc_delay`].
+  pub fn set_ipc_delay(&self, min: Duration, max: Duration) {
+    *self.context.ipc_delay.lock().unwrap() = (min, max);
+  }
+
+  /// Replaces the connected monitors, the first one being the primary monitor.
+  pub fn set_monitors(&self, monitors: Vec<Monitor>) {
+    *self.context.monitors.lock().unwrap() = monitors;
+  }
+
+  /// Moves the mouse cursor to the given position.
+  pub fn set_cursor_position(&self, position: PhysicalPosition<f64>) {
+    *self.context.cursor_position.lock().unwrap() = position;
+  }
+}
+
 impl<T: UserEvent> RuntimeHandle<T> for MockRuntimeHandle {
   type Runtime = MockRuntime;
 
@@ -132,20 +259,37 @@ impl<T: UserEvent> RuntimeHandle<T> for MockRuntimeHandle {
       (None, Vec::new())
     };
 
-    self
-      .context
-      .windows
-      .borrow_mut()
-      .insert(id, Window { webviews });
-
-    let webview = webview_id.map(|id| DetachedWebview {
-      label: pending.label.clone(),
-      dispatcher: MockWebviewDispatcher {
-        id,
-        context: self.context.clone(),
-        url: Arc::new(Mutex::new(pending.webview.unwrap().url)),
-        last_evaluated_script: Default::default(),
+    let event_listeners = WindowEventListeners::default();
+    self.context.windows.borrow_mut().insert(
+      id,
+      Window {
+        label: pending.label.clone(),
+        webviews,
+        event_listeners: event_listeners.clone(),
       },
+    );
+
+    let webview = webview_id.map(|id| {
+      let pending_webview = pending.webview.unwrap();
+      DetachedWebview {
+        label: pending.label.clone(),
+        dispatcher: MockWebviewDispatcher {
+          id,
+          context: self.context.clone(),
+          url: Arc::new(Mutex::new(pending_webview.url)),
+          last_evaluated_script: Default::default(),
+          file_drop_enabled: pending_webview.webview_attributes.file_drop_handler_enabled,
+          accept_first_mouse: pending_webview.webview_attributes.accept_first_mouse,
+          on_page_load: PageLoadListener::new(pending_webview.on_page_load_handler),
+          navigation_handler: NavigationListener::new(pending_webview.navigation_handler),
+          initialization_scripts: pending_webview.webview_attributes.initialization_scripts,
+          #[cfg(any(debug_assertions, feature = "devtools"))]
+          devtools_open: Arc::new(AtomicBool::new(
+            pending_webview.webview_attributes.open_devtools,
+          )),
+          zoom: Arc::new(Mutex::new(1.)),
+        },
+      }
     });
 
     Ok(DetachedWindow {
@@ -154,6 +298,15 @@ impl<T: UserEvent> RuntimeHandle<T> for MockRuntimeHandle {
       dispatcher: MockWindowDispatcher {
         id,
         context: self.context.clone(),
+        event_listeners,
+        fullscreen: Arc::new(AtomicBool::new(pending.window_builder.fullscreen)),
+        maximized: Arc::new(AtomicBool::new(pending.window_builder.maximized)),
+        visible: Arc::new(AtomicBool::new(pending.window_builder.visible)),
+        inner_size_constraints: Arc::new(Mutex::new((
+          pending.window_builder.min_inner_size,
+          pending.window_builder.max_inner_size,
+        ))),
+        position: Arc::new(Mutex::new(pending.window_builder.position)),
       },
       webview,
     })
@@ -177,6 +330,14 @@ impl<T: UserEvent> RuntimeHandle<T> for MockRuntimeHandle {
         context: self.context.clone(),
         last_evaluated_script: Default::default(),
         url: Arc::new(Mutex::new(pending.url)),
+        file_drop_enabled: pending.webview_attributes.file_drop_handler_enabled,
+        accept_first_mouse: pending.webview_attributes.accept_first_mouse,
+        on_page_load: PageLoadListener::new(pending.on_page_load_handler),
+        navigation_handler: NavigationListener::new(pending.navigation_handler),
+        initialization_scripts: pending.webview_attributes.initialization_scripts,
+        #[cfg(any(debug_assertions, feature = "devtools"))]
+        devtools_open: Arc::new(AtomicBool::new(pending.webview_attributes.open_devtools)),
+        zoom: Arc::new(Mutex::new(1.)),
       },
     })
   }
@@ -202,11 +363,15 @@ impl<T: UserEvent> RuntimeHandle<T> for MockRuntimeHandle {
   }
 
   fn primary_monitor(&self) -> Option<Monitor> {
-    unimplemented!()
+    self.context.primary_monitor()
   }
 
   fn available_monitors(&self) -> Vec<Monitor> {
-    unimplemented!()
+    self.context.available_monitors()
+  }
+
+  fn cursor_position(&self) -> Result<PhysicalPosition<f64>> {
+    Ok(*self.context.cursor_position.lock().unwrap())
   }
 
   /// Shows the application, but does not automatically focus it.
@@ -246,39 +411,146 @@ pub struct MockWebviewDispatcher {
   context: RuntimeContext,
   url: Arc<Mutex<String>>,
   last_evaluated_script: Arc<Mutex<Option<String>>>,
+  file_drop_enabled: bool,
+  accept_first_mouse: bool,
+  on_page_load: PageLoadListener,
+  navigation_handler: NavigationListener,
+  initialization_scripts: Vec<String>,
+  #[cfg(any(debug_assertions, feature = "devtools"))]
+  devtools_open: Arc<AtomicBool>,
+  zoom: Arc<Mutex<f64>>,
 }
 
 impl MockWebviewDispatcher {
   pub fn last_evaluated_script(&self) -> Option<String> {
     self.last_evaluated_script.lock().unwrap().clone()
   }
+
+  pub(crate) fn file_drop_enabled(&self) -> bool {
+    self.file_drop_enabled
+  }
+
+  pub(crate) fn accept_first_mouse(&self) -> bool {
+    self.accept_first_mouse
+  }
+
+  pub(crate) fn initialization_scripts(&self) -> &[String] {
+    &self.initialization_scripts
+  }
+
+  pub(crate) fn zoom(&self) -> f64 {
+    *self.zoom.lock().unwrap()
+  }
+
+  /// Triggers the page load handler of the webview with its current URL.
+  pub(crate) fn page_load(&self, event: PageLoadEvent) {
+    let url = self.url.lock().unwrap().parse().unwrap();
+    if let Some(handler) = &*self.on_page_load.0.lock().unwrap() {
+      handler(url, event);
+    }
+  }
 }
 
 #[derive(Debug, Clone)]
 pub struct MockWindowDispatcher {
   id: WindowId,
   context: RuntimeContext,
+  event_listeners: WindowEventListeners,
+  fullscreen: Arc<AtomicBool>,
+  maximized: Arc<AtomicBool>,
+  visible: Arc<AtomicBool>,
+  inner_size_constraints: Arc<Mutex<(SizeConstraint, SizeConstraint)>>,
+  position: Arc<Mutex<PhysicalPosition<i32>>>,
+}
+
+impl MockWindowDispatcher {
+  /// The min and max inner size of the window, as set by the builder or [`WindowDispatch::set_min_size`] and [`WindowDispatch::set_max_size`].
+  pub(crate) fn inner_size_constraints(&self) -> (SizeConstraint, SizeConstraint) {
+    *self.inner_size_constraints.lock().unwrap()
+  }
+
+  /// Triggers the given event on the listeners registered with [`WindowDispatch::on_window_event`].
+  pub(crate) fn emit_window_event(&self, event: &WindowEvent) {
+    self.event_listeners.emit(event);
+  }
+
+  /// Triggers the given event on the main thread, like the event loop of a real runtime would.
+  fn dispatch_window_event(&self, event: WindowEvent) -> Result<()> {
+    let event_listeners = self.event_listeners.clone();
+    self.context.send_message(Message::Task(Box::new(move || {
+      event_listeners.emit(&event)
+    })))
+  }
 }
 
 #[derive(Debug, Clone)]
-pub struct MockWindowBuilder {}
+pub struct MockWindowBuilder {
+  fullscreen: bool,
+  maximized: bool,
+  visible: bool,
+  min_inner_size: SizeConstraint,
+  max_inner_size: SizeConstraint,
+  #[cfg(target_os = "macos")]
+  tabbing_identifier: Option<String>,
+  #[cfg(target_os = "macos")]
+  auto_tabbing: Option<bool>,
+  position: PhysicalPosition<i32>,
+}
+
+impl MockWindowBuilder {
+  /// The tabbing mode of the window, see [`WindowBuilder::auto_tabbing`].
+  ///
+  /// Like the default runtime, it is only set when the window has a tabbing identifier.
+  #[cfg(target_os = "macos")]
+  pub(crate) fn tabbing_mode(&self) -> Option<bool> {
+    self.tabbing_identifier.as_ref().and(self.auto_tabbing)
+  }
+}
 
 impl WindowBuilderBase for MockWindowBuilder {}
 
 impl WindowBuilder for MockWindowBuilder {
   fn new() -> Self {
-    Self {}
+    Self {
+      fullscreen: false,
+      maximized: false,
+      visible: true,
+      min_inner_size: None,
+      max_inner_size: None,
+      #[cfg(target_os = "macos")]
+      tabbing_identifier: None,
+      #[cfg(target_os = "macos")]
+      auto_tabbing: None,
+      position: Default::default(),
+    }
   }
 
   fn with_config(config: WindowConfig) -> Self {
-    Self {}
+    let (min_inner_size, max_inner_size) = config.inner_size_constraints();
+    let mut builder = Self {
+      min_inner_size,
+      max_inner_size,
+      #[cfg(target_os = "macos")]
+      tabbing_identifier: config.tabbing_identifier,
+      #[cfg(target_os = "macos")]
+      auto_tabbing: config.auto_tabbing,
+      ..Self::new()
+    }
+    .fullscreen(config.fullscreen)
+    .maximized(config.maximized)
+    .visible(config.visible);
+    if let (Some(x), Some(y)) = (config.x, config.y) {
+      builder = builder.position(x, y);
+    }
+    builder
   }
 
   fn center(self) -> Self {
     self
   }
 
-  fn position(self, x: f64, y: f64) -> Self {
+  fn position(mut self, x: f64, y: f64) -> Self {
+    self.position = PhysicalPosition::new(x as i32, y as i32);
     self
   }
 
@@ -286,11 +558,13 @@ impl WindowBuilder for MockWindowBuilder {
     self
   }
 
-  fn min_inner_size(self, min_width: f64, min_height: f64) -> Self {
+  fn min_inner_size(mut self, min_width: f64, min_height: f64) -> Self {
+    self.min_inner_size = Some((min_width, min_height));
     self
   }
 
-  fn max_inner_size(self, max_width: f64, max_height: f64) -> Self {
+  fn max_inner_size(mut self, max_width: f64, max_height: f64) -> Self {
+    self.max_inner_size = Some((max_width, max_height));
     self
   }
 
@@ -314,7 +588,10 @@ impl WindowBuilder for MockWindowBuilder {
     self
   }
 
-  fn fullscreen(self, fullscreen: bool) -> Self {
+  fn fullscreen(mut self, fullscreen: bool) -> Self {
+    self.fullscreen = fullscreen;
+    // fullscreen takes precedence over maximized
+    self.maximized &= !fullscreen;
     self
   }
 
@@ -322,11 +599,13 @@ impl WindowBuilder for MockWindowBuilder {
     self
   }
 
-  fn maximized(self, maximized: bool) -> Self {
+  fn maximized(mut self, maximized: bool) -> Self {
+    self.maximized = maximized && !self.fullscreen;
     self
   }
 
-  fn visible(self, visible: bool) -> Self {
+  fn visible(mut self, visible: bool) -> Self {
+    self.visible = visible;
     self
   }
 
@@ -402,7 +681,14 @@ impl WindowBuilder for MockWindowBuilder {
   }
 
   #[cfg(target_os = "macos")]
-  fn tabbing_identifier(self, identifier: &str) -> Self {
+  fn tabbing_identifier(mut self, identifier: &str) -> Self {
+    self.tabbing_identifier.replace(identifier.into());
+    self
+  }
+
+  #[cfg(target_os = "macos")]
+  fn auto_tabbing(mut self, enabled: bool) -> Self {
+    self.auto_tabbing.replace(enabled);
     self
   }
 
@@ -427,14 +713,18 @@ impl<T: UserEvent> WebviewDispatch<T> for MockWebviewDispatcher {
   }
 
   #[cfg(any(debug_assertions, feature = "devtools"))]
-  fn open_devtools(&self) {}
+  fn open_devtools(&self) {
+    self.devtools_open.store(true, Ordering::Relaxed);
+  }
 
   #[cfg(any(debug_assertions, feature = "devtools"))]
-  fn close_devtools(&self) {}
+  fn close_devtools(&self) {
+    self.devtools_open.store(false, Ordering::Relaxed);
+  }
 
   #[cfg(any(debug_assertions, feature = "devtools"))]
   fn is_devtools_open(&self) -> Result<bool> {
-    Ok(false)
+    Ok(self.devtools_open.load(Ordering::Relaxed))
   }
 
   fn eval_script<S: Into<String>>(&self, script: S) -> Result<()> {
@@ -467,7 +757,9 @@ impl<T: UserEvent> WebviewDispatch<T> for MockWebviewDispatcher {
   }
 
   fn navigate(&self, url: Url) -> Result<()> {
-    *self.url.lock().unwrap() = url.to_string();
+    if self.navigation_handler.allows(&url) {
+      *self.url.lock().unwrap() = url.to_string();
+    }
     Ok(())
   }
 
@@ -490,6 +782,11 @@ impl<T: UserEvent> WebviewDispatch<T> for MockWebviewDispatcher {
   fn set_focus(&self) -> Result<()> {
     Ok(())
   }
+
+  fn set_zoom(&self, scale_factor: f64) -> Result<()> {
+    *self.zoom.lock().unwrap() = scale_factor;
+    Ok(())
+  }
 }
 
 impl<T: UserEvent> WindowDispatch<T> for MockWindowDispatcher {
@@ -502,7 +799,14 @@ impl<T: UserEvent> WindowDispatch<T> for MockWindowDispatcher {
   }
 
   fn on_window_event<F: Fn(&WindowEvent) + Send + 'static>(&self, f: F) -> WindowEventId {
-    self.context.next_window_event_id()
+    let id = self.context.next_window_event_id();
+    self
+      .event_listeners
+      .0
+      .lock()
+      .unwrap()
+      .insert(id, Box::new(f));
+    id
   }
 
   fn scale_factor(&self) -> Result<f64> {
@@ -510,11 +814,11 @@ impl<T: UserEvent> WindowDispatch<T> for MockWindowDispatcher {
   }
 
   fn inner_position(&self) -> Result<PhysicalPosition<i32>> {
-    Ok(PhysicalPosition { x: 0, y: 0 })
+    Ok(*self.position.lock().unwrap())
   }
 
   fn outer_position(&self) -> Result<PhysicalPosition<i32>> {
-    Ok(PhysicalPosition { x: 0, y: 0 })
+    Ok(*self.position.lock().unwrap())
   }
 
   fn inner_size(&self) -> Result<PhysicalSize<u32>> {
@@ -532,7 +836,7 @@ impl<T: UserEvent> WindowDispatch<T> for MockWindowDispatcher {
   }
 
   fn is_fullscreen(&self) -> Result<bool> {
-    Ok(false)
+    Ok(self.fullscreen.load(Ordering::Relaxed))
   }
 
   fn is_minimized(&self) -> Result<bool> {
@@ -540,7 +844,7 @@ impl<T: UserEvent> WindowDispatch<T> for MockWindowDispatcher {
   }
 
   fn is_maximized(&self) -> Result<bool> {
-    Ok(false)
+    Ok(self.maximized.load(Ordering::Relaxed))
   }
 
   fn is_focused(&self) -> Result<bool> {
@@ -568,7 +872,7 @@ impl<T: UserEvent> WindowDispatch<T> for MockWindowDispatcher {
   }
 
   fn is_visible(&self) -> Result<bool> {
-    Ok(true)
+    Ok(self.visible.load(Ordering::Relaxed))
   }
 
   fn title(&self) -> Result<String> {
@@ -580,11 +884,11 @@ impl<T: UserEvent> WindowDispatch<T> for MockWindowDispatcher {
   }
 
   fn primary_monitor(&self) -> Result<Option<Monitor>> {
-    Ok(None)
+    Ok(self.context.primary_monitor())
   }
 
   fn available_monitors(&self) -> Result<Vec<Monitor>> {
-    Ok(Vec::new())
+    Ok(self.context.available_monitors())
   }
 
   fn theme(&self) -> Result<Theme> {
@@ -651,20 +955,37 @@ impl<T: UserEvent> WindowDispatch<T> for MockWindowDispatcher {
       (None, Vec::new())
     };
 
-    self
-      .context
-      .windows
-      .borrow_mut()
-      .insert(id, Window { webviews });
-
-    let webview = webview_id.map(|id| DetachedWebview {
-      label: pending.label.clone(),
-      dispatcher: MockWebviewDispatcher {
-        id,
-        context: self.context.clone(),
-        url: Arc::new(Mutex::new(pending.webview.unwrap().url)),
-        last_evaluated_script: Default::default(),
+    let event_listeners = WindowEventListeners::default();
+    self.context.windows.borrow_mut().insert(
+      id,
+      Window {
+        label: pending.label.clone(),
+        webviews,
+        event_listeners: event_listeners.clone(),
       },
+    );
+
+    let webview = webview_id.map(|id| {
+      let pending_webview = pending.webview.unwrap();
+      DetachedWebview {
+        label: pending.label.clone(),
+        dispatcher: MockWebviewDispatcher {
+          id,
+          context: self.context.clone(),
+          url: Arc::new(Mutex::new(pending_webview.url)),
+          last_evaluated_script: Default::default(),
+          file_drop_enabled: pending_webview.webview_attributes.file_drop_handler_enabled,
+          accept_first_mouse: pending_webview.webview_attributes.accept_first_mouse,
+          on_page_load: PageLoadListener::new(pending_webview.on_page_load_handler),
+          navigation_handler: NavigationListener::new(pending_webview.navigation_handler),
+          initialization_scripts: pending_webview.webview_attributes.initialization_scripts,
+          #[cfg(any(debug_assertions, feature = "devtools"))]
+          devtools_open: Arc::new(AtomicBool::new(
+            pending_webview.webview_attributes.open_devtools,
+          )),
+          zoom: Arc::new(Mutex::new(1.)),
+        },
+      }
     });
 
     Ok(DetachedWindow {
@@ -673,6 +994,15 @@ impl<T: UserEvent> WindowDispatch<T> for MockWindowDispatcher {
       dispatcher: MockWindowDispatcher {
         id,
         context: self.context.clone(),
+        event_listeners,
+        fullscreen: Arc::new(AtomicBool::new(pending.window_builder.fullscreen)),
+        maximized: Arc::new(AtomicBool::new(pending.window_builder.maximized)),
+        visible: Arc::new(AtomicBool::new(pending.window_builder.visible)),
+        inner_size_constraints: Arc::new(Mutex::new((
+          pending.window_builder.min_inner_size,
+          pending.window_builder.max_inner_size,
+        ))),
+        position: Arc::new(Mutex::new(pending.window_builder.position)),
       },
       webview,
     })
@@ -695,6 +1025,14 @@ impl<T: UserEvent> WindowDispatch<T> for MockWindowDispatcher {
         context: self.context.clone(),
         last_evaluated_script: Default::default(),
         url: Arc::new(Mutex::new(pending.url)),
+        file_drop_enabled: pending.webview_attributes.file_drop_handler_enabled,
+        accept_first_mouse: pending.webview_attributes.accept_first_mouse,
+        on_page_load: PageLoadListener::new(pending.on_page_load_handler),
+        navigation_handler: NavigationListener::new(pending.navigation_handler),
+        initialization_scripts: pending.webview_attributes.initialization_scripts,
+        #[cfg(any(debug_assertions, feature = "devtools"))]
+        devtools_open: Arc::new(AtomicBool::new(pending.webview_attributes.open_devtools)),
+        zoom: Arc::new(Mutex::new(1.)),
       },
     })
   }
@@ -720,10 +1058,12 @@ impl<T: UserEvent> WindowDispatch<T> for MockWindowDispatcher {
   }
 
   fn maximize(&self) -> Result<()> {
+    self.maximized.store(true, Ordering::Relaxed);
     Ok(())
   }
 
   fn unmaximize(&self) -> Result<()> {
+    self.maximized.store(false, Ordering::Relaxed);
     Ok(())
   }
 
@@ -736,10 +1076,12 @@ impl<T: UserEvent> WindowDispatch<T> for MockWindowDispatcher {
   }
 
   fn show(&self) -> Result<()> {
+    self.visible.store(true, Ordering::Relaxed);
     Ok(())
   }
 
   fn hide(&self) -> Result<()> {
+    self.visible.store(false, Ordering::Relaxed);
     Ok(())
   }
 
@@ -773,27 +1115,38 @@ impl<T: UserEvent> WindowDispatch<T> for MockWindowDispatcher {
   }
 
   fn set_size(&self, size: Size) -> Result<()> {
-    Ok(())
+    self.dispatch_window_event(WindowEvent::Resized(size.to_physical(1.0)))
   }
 
   fn set_min_size(&self, size: Option<Size>) -> Result<()> {
+    self.inner_size_constraints.lock().unwrap().0 = size.map(|size| {
+      let size = size.to_logical::<f64>(1.0);
+      (size.width, size.height)
+    });
     Ok(())
   }
 
   fn set_max_size(&self, size: Option<Size>) -> Result<()> {
+    self.inner_size_constraints.lock().unwrap().1 = size.map(|size| {
+      let size = size.to_logical::<f64>(1.0);
+      (size.width, size.height)
+    });
     Ok(())
   }
 
   fn set_position(&self, position: Position) -> Result<()> {
-    Ok(())
+    let position = position.to_physical(1.0);
+    *self.position.lock().unwrap() = position;
+    self.dispatch_window_event(WindowEvent::Moved(position))
   }
 
   fn set_fullscreen(&self, fullscreen: bool) -> Result<()> {
+    self.fullscreen.store(fullscreen, Ordering::Relaxed);
     Ok(())
   }
 
   fn set_focus(&self) -> Result<()> {
-    Ok(())
+    self.dispatch_window_event(WindowEvent::Focused(true))
   }
 
   fn set_icon(&self, icon: Icon) -> Result<()> {
@@ -865,6 +1218,10 @@ impl MockRuntime {
       next_window_id: Default::default(),
       next_webview_id: Default::default(),
       next_window_event_id: Default::default(),
+      clipboard: Default::default(),
+      ipc_delay: Default::default(),
+      monitors: Default::default(),
+      cursor_position: Default::default(),
     };
     Self {
       is_running,
@@ -912,20 +1269,37 @@ impl<T: UserEvent> Runtime<T> for MockRuntime {
       (None, Vec::new())
     };
 
-    self
-      .context
-      .windows
-      .borrow_mut()
-      .insert(id, Window { webviews });
-
-    let webview = webview_id.map(|id| DetachedWebview {
-      label: pending.label.clone(),
-      dispatcher: MockWebviewDispatcher {
-        id,
-        context: self.context.clone(),
-        url: Arc::new(Mutex::new(pending.webview.unwrap().url)),
-        last_evaluated_script: Default::default(),
+    let event_listeners = WindowEventListeners::default();
+    self.context.windows.borrow_mut().insert(
+      id,
+      Window {
+        label: pending.label.clone(),
+        webviews,
+        event_listeners: event_listeners.clone(),
       },
+    );
+
+    let webview = webview_id.map(|id| {
+      let pending_webview = pending.webview.unwrap();
+      DetachedWebview {
+        label: pending.label.clone(),
+        dispatcher: MockWebviewDispatcher {
+          id,
+          context: self.context.clone(),
+          url: Arc::new(Mutex::new(pending_webview.url)),
+          last_evaluated_script: Default::default(),
+          file_drop_enabled: pending_webview.webview_attributes.file_drop_handler_enabled,
+          accept_first_mouse: pending_webview.webview_attributes.accept_first_mouse,
+          on_page_load: PageLoadListener::new(pending_webview.on_page_load_handler),
+          navigation_handler: NavigationListener::new(pending_webview.navigation_handler),
+          initialization_scripts: pending_webview.webview_attributes.initialization_scripts,
+          #[cfg(any(debug_assertions, feature = "devtools"))]
+          devtools_open: Arc::new(AtomicBool::new(
+            pending_webview.webview_attributes.open_devtools,
+          )),
+          zoom: Arc::new(Mutex::new(1.)),
+        },
+      }
     });
 
     Ok(DetachedWindow {
@@ -934,6 +1308,15 @@ impl<T: UserEvent> Runtime<T> for MockRuntime {
       dispatcher: MockWindowDispatcher {
         id,
         context: self.context.clone(),
+        event_listeners,
+        fullscreen: Arc::new(AtomicBool::new(pending.window_builder.fullscreen)),
+        maximized: Arc::new(AtomicBool::new(pending.window_builder.maximized)),
+        visible: Arc::new(AtomicBool::new(pending.window_builder.visible)),
+        inner_size_constraints: Arc::new(Mutex::new((
+          pending.window_builder.min_inner_size,
+          pending.window_builder.max_inner_size,
+        ))),
+        position: Arc::new(Mutex::new(pending.window_builder.position)),
       },
       webview,
     })
@@ -957,16 +1340,28 @@ impl<T: UserEvent> Runtime<T> for MockRuntime {
         context: self.context.clone(),
         last_evaluated_script: Default::default(),
         url: Arc::new(Mutex::new(pending.url)),
+        file_drop_enabled: pending.webview_attributes.file_drop_handler_enabled,
+        accept_first_mouse: pending.webview_attributes.accept_first_mouse,
+        on_page_load: PageLoadListener::new(pending.on_page_load_handler),
+        navigation_handler: NavigationListener::new(pending.navigation_handler),
+        initialization_scripts: pending.webview_attributes.initialization_scripts,
+        #[cfg(any(debug_assertions, feature = "devtools"))]
+        devtools_open: Arc::new(AtomicBool::new(pending.webview_attributes.open_devtools)),
+        zoom: Arc::new(Mutex::new(1.)),
       },
     })
   }
 
   fn primary_monitor(&self) -> Option<Monitor> {
-    unimplemented!()
+    self.context.primary_monitor()
   }
 
   fn available_monitors(&self) -> Vec<Monitor> {
-    unimplemented!()
+    self.context.available_monitors()
+  }
+
+  fn cursor_position(&self) -> Result<PhysicalPosition<f64>> {
+    Ok(*self.context.cursor_position.lock().unwrap())
   }
 
   #[cfg(target_os = "macos")]
@@ -1008,8 +1403,12 @@ impl<T: UserEvent> Runtime<T> for MockRuntime {
         match m {
           Message::Task(p) => p(),
           Message::CloseWindow(id) => {
-            let removed = self.context.windows.borrow_mut().remove(&id).is_some();
-            if removed {
+            if let Some(label) = self.context.destroy_window(id) {
+              callback(RunEvent::WindowEvent {
+                label,
+                event: WindowEvent::Destroyed,
+              });
+
               let is_empty = self.context.windows.borrow().is_empty();
               if is_empty {
                 let (tx, rx) = channel();
@@ -45,6 +45,46 @@
 //!     ).map(|b| b.deserialize::<String>().unwrap());
 //! }
 //! ```
+//!
+//! Plugins can be tested with [`mock_app_with_plugin`], which initializes the plugin
+//! and allows the given commands so they can be called through the IPC:
+//!
+//! ```rust
+//! use tauri::{
+//!   plugin::{Builder, TauriPlugin},
+//!   test::{assert_ipc_response, mock_app_with_plugin},
+//!   Runtime,
+//! };
+//!
+//! #[tauri::command]
+//! fn ping() -> &'static str {
+//!     "pong"
+//! }
+//!
+//! fn init<R: Runtime>() -> TauriPlugin<R> {
+//!     Builder::new("echo")
+//!         .invoke_handler(tauri::generate_handler![ping])
+//!         .build()
+//! }
+//!
+//! fn main() {
+//!     let app = mock_app_with_plugin(init(), &["ping"]);
+//!     let webview = tauri::WebviewWindowBuilder::new(&app, "main", Default::default()).build().unwrap();
+//!
+//!     // plugin commands are called as `plugin:<plugin name>|<command>`
+//!     assert_ipc_response(
+//!         &webview,
+//!         tauri::webview::InvokeRequest {
+//!             cmd: "plugin:echo|ping".into(),
+//!             callback: tauri::ipc::CallbackFn(0),
+//!             error: tauri::ipc::CallbackFn(1),
+//!             body: tauri::ipc::InvokeBody::default(),
+//!             headers: Default::default(),
+//!         },
+//!         Ok("pong"),
+//!     );
+//! }
+//! ```
 
 #![allow(unused_variables)]
 
@@ -52,16 +92,30 @@ mod mock_runtime;
 pub use mock_runtime::*;
 use serde::Serialize;
 
-use std::{borrow::Cow, collections::HashMap, fmt::Debug};
+use std::{
+  borrow::Cow,
+  collections::HashMap,
+  fmt::Debug,
+  ops::Range,
+  path::{Component, Path, PathBuf},
+  sync::{Arc, Mutex, OnceLock},
+  time::Duration,
+};
 
 use crate::{
   ipc::{InvokeBody, InvokeError, InvokeResponse},
+  plugin::Plugin,
+  runtime::window::WindowEvent as RuntimeWindowEvent,
   webview::InvokeRequest,
-  App, Builder, Context, Pattern, Webview,
+  App, Builder, Context, FileDropEvent, Manager, Monitor, Pattern, PhysicalPosition, PhysicalSize,
+  Webview, Window, WindowEvent,
 };
 use tauri_utils::{
-  acl::resolved::Resolved,
-  assets::{AssetKey, Assets, CspHash},
+  acl::{
+    resolved::{CommandKey, Resolved, ResolvedCommand},
+    ExecutionContext,
+  },
+  assets::{AssetKey, Assets, AssetsIter, CspHash},
   config::{Config, PatternKind, TauriConfig},
 };
 
@@ -76,8 +130,13 @@ impl Assets for NoopAsset {
     None
   }
 
-  fn iter(&self) -> Box<dyn Iterator<Item = (&&str, &&[u8])> + '_> {
-    Box::new(self.assets.iter())
+  fn iter(&self) -> Box<AssetsIter<'_>> {
+    Box::new(
+      self
+        .assets
+        .iter()
+        .map(|(key, asset)| (Cow::Borrowed(*key), Cow::Borrowed(*asset))),
+    )
   }
 
   fn csp_hashes(&self, html_path: &AssetKey) -> Box<dyn Iterator<Item = CspHash<'_>> + '_> {
@@ -93,6 +152,145 @@ pub fn noop_assets() -> NoopAsset {
   }
 }
 
+/// An [`Assets`] implementation backed by a directory on disk.
+///
+/// [`Assets::get`] reads the file from disk on every call, so changes to the directory
+/// are picked up without rebuilding the app.
+///
+/// This is intended for development and tests, not for embedding assets in production.
+/// The directory is walked the first time [`Assets::iter`] or [`Assets::csp_hashes`] is called,
+/// and that snapshot is kept in memory until the [`DirAssets`] is dropped.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use tauri::test::{mock_builder, mock_context, DirAssets};
+///
+/// let app = mock_builder()
+///   .build(mock_context(DirAssets::new("../dist".into())))
+///   .unwrap();
+/// ```
+#[derive(Debug)]
+pub struct DirAssets {
+  root: PathBuf,
+  index: OnceLock<DirAssetsIndex>,
+}
+
+#[derive(Debug)]
+struct DirAssetsIndex {
+  files: Vec<(String, Vec<u8>)>,
+  script_hashes: Vec<String>,
+}
+
+impl DirAssets {
+  /// Creates a new [`Assets`] implementation serving the files in `root`.
+  pub fn new(root: PathBuf) -> Self {
+    Self {
+      root,
+      index: OnceLock::new(),
+    }
+  }
+
+  fn index(&self) -> &DirAssetsIndex {
+    self.index.get_or_init(|| {
+      let mut paths = Vec::new();
+      walk_dir(&self.root, &mut paths);
+      paths.sort();
+
+      let mut files = Vec::new();
+      let mut script_hashes = Vec::new();
+      for path in paths {
+        let (Ok(relative), Ok(contents)) = (path.strip_prefix(&self.root), std::fs::read(&path))
+        else {
+          continue;
+        };
+
+        // same rule as the embedded assets: every JS file is allowed by the CSP
+        if let Some("js") | Some("mjs") = path.extension().and_then(|ext| ext.to_str()) {
+          use base64::Engine;
+          use sha2::Digest;
+          script_hashes.push(format!(
+            "'sha256-{}'",
+            base64::engine::general_purpose::STANDARD.encode(sha2::Sha256::digest(&contents))
+          ));
+        }
+
+        files.push((String::from(AssetKey::from(relative)), contents));
+      }
+
+      DirAssetsIndex {
+        files,
+        script_hashes,
+      }
+    })
+  }
+}
+
+fn walk_dir(dir: &Path, paths: &mut Vec<PathBuf>) {
+  if let Ok(entries) = std::fs::read_dir(dir) {
+    for entry in entries.flatten() {
+      let path = entry.path();
+      if path.is_dir() {
+        walk_dir(&path, paths);
+      } else {
+        paths.push(path);
+      }
+    }
+  }
+}
+
+impl DirAssets {
+  fn path(&self, key: &AssetKey) -> Option<PathBuf> {
+    let relative = Path::new(key.as_ref().trim_start_matches('/'));
+    // never serve files outside of the root directory
+    if relative
+      .components()
+      .any(|component| !matches!(component, Component::Normal(_)))
+    {
+      return None;
+    }
+    Some(self.root.join(relative))
+  }
+}
+
+impl Assets for DirAssets {
+  fn get(&self, key: &AssetKey) -> Option<Cow<'_, [u8]>> {
+    std::fs::read(self.path(key)?).ok().map(Cow::Owned)
+  }
+
+  fn content_len(&self, key: &AssetKey) -> Option<u64> {
+    std::fs::metadata(self.path(key)?)
+      .ok()
+      .map(|metadata| metadata.len())
+  }
+
+  fn get_reader(&self, key: &AssetKey, offset: u64) -> Option<Box<dyn std::io::Read + Send + '_>> {
+    use std::io::Seek;
+    let mut file = std::fs::File::open(self.path(key)?).ok()?;
+    file.seek(std::io::SeekFrom::Start(offset)).ok()?;
+    Some(Box::new(file))
+  }
+
+  fn iter(&self) -> Box<AssetsIter<'_>> {
+    Box::new(self.index().files.iter().map(|(key, contents)| {
+      (
+        Cow::Borrowed(key.as_str()),
+        Cow::Borrowed(contents.as_slice()),
+      )
+    }))
+  }
+
+  fn csp_hashes(&self, html_path: &AssetKey) -> Box<dyn Iterator<Item = CspHash<'_>> + '_> {
+    Box::new(
+      self
+        .index()
+        .script_hashes
+        .iter()
+        .map(|hash| CspHash::Script(hash)),
+    )
+  }
+}
+
 /// Creates a new [`crate::Context`] for testing.
 pub fn mock_context<A: Assets>(assets: A) -> crate::Context<A> {
   Context {
@@ -106,6 +304,10 @@ pub fn mock_context<A: Assets>(assets: A) -> crate::Context<A> {
         security: Default::default(),
         tray_icon: None,
         macos_private_api: false,
+        default_user_agent: None,
+        default_theme: None,
+        minimum_webview_version: None,
+        global_shortcuts: Vec::new(),
       },
       build: Default::default(),
       plugins: Default::default(),
@@ -133,6 +335,61 @@ pub fn mock_context<A: Assets>(assets: A) -> crate::Context<A> {
   }
 }
 
+/// Creates a new [`crate::Context`] for testing with the given resolved access control list.
+///
+/// [`mock_context`] does not allow any plugin command, so this is required to test commands guarded by the ACL
+/// or to provide scope values to commands using [`crate::command::CommandScope`] and [`crate::command::GlobalScope`].
+///
+/// # Examples
+///
+/// A minimal [`Resolved`] allowing a plugin command on all windows with a command scope:
+///
+/// ```rust
+/// use tauri::utils::acl::{
+///   resolved::{CommandKey, Resolved, ResolvedCommand, ResolvedScope},
+///   ExecutionContext, Value,
+/// };
+///
+/// let resolved = Resolved {
+///   allowed_commands: [(
+///     CommandKey {
+///       name: "plugin:fs|read_file".into(),
+///       context: ExecutionContext::Local,
+///     },
+///     ResolvedCommand {
+///       windows: vec![glob::Pattern::new("*").unwrap()],
+///       // references the `command_scope` entry below
+///       scope: Some(0),
+///     },
+///   )]
+///   .into_iter()
+///   .collect(),
+///   denied_commands: Default::default(),
+///   command_scope: [(
+///     0,
+///     ResolvedScope {
+///       allow: vec![Value::String("$APPDATA/*".into())],
+///       deny: Vec::new(),
+///     },
+///   )]
+///   .into_iter()
+///   .collect(),
+///   global_scope: Default::default(),
+/// };
+///
+/// let app = tauri::test::mock_builder()
+///   .build(tauri::test::mock_context_with_acl(
+///     resolved,
+///     tauri::test::noop_assets(),
+///   ))
+///   .unwrap();
+/// ```
+pub fn mock_context_with_acl<A: Assets>(resolved: Resolved, assets: A) -> crate::Context<A> {
+  let mut context = mock_context(assets);
+  context.resolved_acl = resolved;
+  context
+}
+
 /// Creates a new [`Builder`] using the [`MockRuntime`].
 ///
 /// To use a dummy [`Context`], see [`mock_app`].
@@ -157,6 +414,46 @@ pub fn mock_app() -> App<MockRuntime> {
   mock_builder().build(mock_context(noop_assets())).unwrap()
 }
 
+/// Creates a new [`App`] for testing with the given plugin registered, see [`mock_app`].
+///
+/// The plugin is initialized before the app is returned, so its setup hook has run.
+/// The given commands of the plugin are allowed on all windows, so they can be called with
+/// [`get_ipc_response`] and [`assert_ipc_response`] as `plugin:<plugin name>|<command>`.
+///
+/// Use [`mock_context_with_acl`] to test the scopes of the commands instead.
+///
+/// See the [module documentation](self) for an example.
+pub fn mock_app_with_plugin<P: Plugin<MockRuntime> + 'static>(
+  plugin: P,
+  commands: &[&str],
+) -> App<MockRuntime> {
+  let name = plugin.name();
+  let resolved = Resolved {
+    allowed_commands: commands
+      .iter()
+      .map(|command| {
+        (
+          CommandKey {
+            name: format!("plugin:{name}|{command}"),
+            context: ExecutionContext::Local,
+          },
+          ResolvedCommand {
+            windows: vec![glob::Pattern::new("*").unwrap()],
+            scope: None,
+          },
+        )
+      })
+      .collect(),
+    denied_commands: Default::default(),
+    command_scope: Default::default(),
+    global_scope: Default::default(),
+  };
+  mock_builder()
+    .plugin(plugin)
+    .build(mock_context_with_acl(resolved, noop_assets()))
+    .unwrap()
+}
+
 /// Executes the given IPC message and assert the response matches the expected value.
 ///
 /// # Examples
@@ -256,11 +553,19 @@ pub fn get_ipc_response<W: AsRef<Webview<MockRuntime>>>(
   webview: &W,
   request: InvokeRequest,
 ) -> Result<InvokeBody, serde_json::Value> {
+  let delay = webview.as_ref().app_handle().runtime_handle.ipc_delay();
   let (tx, rx) = std::sync::mpsc::sync_channel(1);
   webview.as_ref().clone().on_message(
     request,
     Box::new(move |_window, _cmd, response, _callback, _error| {
-      tx.send(response).unwrap();
+      if delay.is_zero() {
+        tx.send(response).unwrap();
+      } else {
+        std::thread::spawn(move || {
+          std::thread::sleep(delay);
+          let _ = tx.send(response);
+        });
+      }
     }),
   );
 
@@ -271,11 +576,602 @@ pub fn get_ipc_response<W: AsRef<Webview<MockRuntime>>>(
   }
 }
 
+/// Simulates a file drop event on the window of the given webview.
+///
+/// The event is dispatched directly to the window event listeners, bypassing the operating system,
+/// so handlers registered with [`crate::Builder::on_window_event`] or [`crate::Window::on_window_event`]
+/// and the JavaScript `tauri://file-drop*` listeners receive it as if the user dragged files to the window.
+///
+/// This is a no-op if the webview has the file drop handler disabled,
+/// see [`crate::utils::config::WindowConfig#structfield.file_drop_enabled`].
+///
+/// # Examples
+///
+/// ```rust
+/// use tauri::{test::simulate_file_drop, FileDropEvent, PhysicalPosition, WindowEvent};
+///
+/// let app = tauri::test::mock_app();
+/// let webview = tauri::WebviewWindowBuilder::new(&app, "main", Default::default())
+///   .build()
+///   .unwrap();
+///
+/// webview.on_window_event(|event| {
+///   if let WindowEvent::FileDrop(FileDropEvent::Dropped { paths, .. }) = event {
+///     assert_eq!(paths, &vec![std::path::PathBuf::from("file.txt")]);
+///   }
+/// });
+///
+/// simulate_file_drop(
+///   &webview,
+///   FileDropEvent::Dropped {
+///     paths: vec!["file.txt".into()],
+///     position: PhysicalPosition::new(0., 0.),
+///   },
+/// );
+/// ```
+pub fn simulate_file_drop<W: AsRef<Webview<MockRuntime>>>(webview: &W, event: FileDropEvent) {
+  let webview = webview.as_ref();
+  if webview.webview.dispatcher.file_drop_enabled() {
+    webview
+      .window()
+      .window
+      .dispatcher
+      .emit_window_event(&RuntimeWindowEvent::FileDrop(event));
+  }
+}
+
+/// The window events recorded by [`record_window_events`].
+///
+/// The log is shared with the listener that records the events, so it can be cloned and moved to other threads.
+/// Events are appended on the thread that dispatches them, which is the main thread for [`MockRuntime`] operations,
+/// so read it after the operation has been processed (e.g. after [`App::run`] returns).
+#[derive(Debug, Clone, Default)]
+pub struct WindowEventLog(Arc<Mutex<Vec<WindowEvent>>>);
+
+impl WindowEventLog {
+  /// Returns the events recorded so far, in dispatch order.
+  pub fn events(&self) -> Vec<WindowEvent> {
+    self.0.lock().unwrap().clone()
+  }
+
+  /// Whether a recorded event matches the given predicate.
+  pub fn contains<F: Fn(&WindowEvent) -> bool>(&self, predicate: F) -> bool {
+    self.0.lock().unwrap().iter().any(predicate)
+  }
+}
+
+/// Records the events triggered on the given window.
+///
+/// The [`MockRuntime`] dispatches events for the window operations that would trigger them on a real runtime:
+/// [`Window::close`] triggers [`WindowEvent::Destroyed`], [`Window::set_focus`] triggers [`WindowEvent::Focused`],
+/// [`Window::set_size`] triggers [`WindowEvent::Resized`] and [`Window::set_position`] triggers [`WindowEvent::Moved`].
+/// Like the default runtime, closing a window programmatically does not trigger [`WindowEvent::CloseRequested`].
+/// Events simulated with functions like [`simulate_file_drop`] are recorded too.
+///
+/// # Examples
+///
+/// ```rust
+/// use tauri::{test::record_window_events, WindowEvent};
+///
+/// let app = tauri::test::mock_app();
+/// let webview = tauri::WebviewWindowBuilder::new(&app, "main", Default::default())
+///   .build()
+///   .unwrap();
+///
+/// let events = record_window_events(&webview.as_ref().window());
+/// webview.set_focus().unwrap();
+/// webview.close().unwrap();
+///
+/// assert!(events.contains(|event| matches!(event, WindowEvent::Focused(true))));
+/// assert!(events.contains(|event| matches!(event, WindowEvent::Destroyed)));
+/// ```
+pub fn record_window_events(window: &Window<MockRuntime>) -> WindowEventLog {
+  let log = WindowEventLog::default();
+  let events = log.0.clone();
+  window.on_window_event(move |event| events.lock().unwrap().push(event.clone()));
+  log
+}
+
+/// Writes text to the in-memory clipboard of the [`MockRuntime`].
+///
+/// The clipboard is shared by all handles of the app and never touches the OS clipboard,
+/// so the clipboard logic of commands and plugins can be tested by calling this function
+/// in place of the OS clipboard APIs when running on the [`MockRuntime`].
+/// Only text is supported for now.
+///
+/// # Examples
+///
+/// ```rust
+/// use tauri::test::{get_mock_clipboard, mock_app, set_mock_clipboard};
+///
+/// let app = mock_app();
+/// assert_eq!(get_mock_clipboard(&app), None);
+///
+/// set_mock_clipboard(&app, "copied");
+/// assert_eq!(get_mock_clipboard(&app).as_deref(), Some("copied"));
+/// ```
+pub fn set_mock_clipboard<M: Manager<MockRuntime>>(manager: &M, text: impl Into<String>) {
+  manager
+    .app_handle()
+    .runtime_handle
+    .set_clipboard_text(Some(text.into()));
+}
+
+/// Reads the text in the in-memory clipboard of the [`MockRuntime`], see [`set_mock_clipboard`].
+pub fn get_mock_clipboard<M: Manager<MockRuntime>>(manager: &M) -> Option<String> {
+  manager.app_handle().runtime_handle.clipboard_text()
+}
+
+/// Delays the delivery of the IPC responses of the [`MockRuntime`] by the given duration.
+///
+/// The command still runs right away, only its response is delivered late,
+/// so [`get_ipc_response`] and [`assert_ipc_response`] wait for the delay before returning.
+/// Use [`set_mock_ipc_delay_range`] to pick a random delay for each response instead,
+/// which makes concurrent requests resolve out of order.
+///
+/// This only affects the [`MockRuntime`]. The delay defaults to zero, set it back to [`Duration::ZERO`] to disable it.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::{Duration, Instant};
+/// use tauri::test::{get_ipc_response, mock_builder, mock_context, noop_assets, set_mock_ipc_delay};
+///
+/// #[tauri::command]
+/// fn ping() -> &'static str {
+///   "pong"
+/// }
+///
+/// fn main() {
+///   let app = mock_builder()
+///     .invoke_handler(tauri::generate_handler![ping])
+///     .build(mock_context(noop_assets()))
+///     .unwrap();
+///   let webview = tauri::WebviewWindowBuilder::new(&app, "main", Default::default())
+///     .build()
+///     .unwrap();
+///
+///   set_mock_ipc_delay(&app, Duration::from_millis(50));
+///
+///   let start = Instant::now();
+///   let res = get_ipc_response(
+///     &webview,
+///     tauri::webview::InvokeRequest {
+///       cmd: "ping".into(),
+///       callback: tauri::ipc::CallbackFn(0),
+///       error: tauri::ipc::CallbackFn(1),
+///       body: tauri::ipc::InvokeBody::default(),
+///       headers: Default::default(),
+///     },
+///   );
+///   assert!(res.is_ok());
+///   assert!(start.elapsed() >= Duration::from_millis(50));
+/// }
+/// ```
+pub fn set_mock_ipc_delay<M: Manager<MockRuntime>>(manager: &M, delay: Duration) {
+  manager
+    .app_handle()
+    .runtime_handle
+    .set_ipc_delay(delay, delay);
+}
+
+/// Delays the delivery of each IPC response of the [`MockRuntime`] by a random duration in the given range,
+/// see [`set_mock_ipc_delay`].
+pub fn set_mock_ipc_delay_range<M: Manager<MockRuntime>>(manager: &M, delay: Range<Duration>) {
+  manager
+    .app_handle()
+    .runtime_handle
+    .set_ipc_delay(delay.start, delay.end);
+}
+
+/// Creates a [`Monitor`] for [`set_mock_monitors`].
+pub fn mock_monitor(
+  name: Option<&str>,
+  position: PhysicalPosition<i32>,
+  size: PhysicalSize<u32>,
+  scale_factor: f64,
+) -> Monitor {
+  Monitor {
+    name: name.map(Into::into),
+    size,
+    position,
+    scale_factor,
+  }
+}
+
+/// Replaces the monitors of the [`MockRuntime`], which has none by default.
+///
+/// The first monitor is the primary monitor.
+///
+/// # Examples
+///
+/// ```rust
+/// use tauri::{
+///   test::{mock_app, mock_monitor, set_mock_monitors},
+///   PhysicalPosition, PhysicalSize,
+/// };
+///
+/// let app = mock_app();
+/// set_mock_monitors(
+///   &app,
+///   vec![mock_monitor(
+///     Some("main"),
+///     PhysicalPosition::new(0, 0),
+///     PhysicalSize::new(1920, 1080),
+///     1.,
+///   )],
+/// );
+/// assert_eq!(app.available_monitors().unwrap().len(), 1);
+/// ```
+pub fn set_mock_monitors<M: Manager<MockRuntime>>(manager: &M, monitors: Vec<Monitor>) {
+  manager.app_handle().runtime_handle.set_monitors(
+    monitors
+      .into_iter()
+      .map(|monitor| crate::runtime::monitor::Monitor {
+        name: monitor.name,
+        size: monitor.size,
+        position: monitor.position,
+        scale_factor: monitor.scale_factor,
+      })
+      .collect(),
+  );
+}
+
+/// Moves the mouse cursor of the [`MockRuntime`] to the given position, `(0, 0)` by default.
+pub fn set_mock_cursor_position<M: Manager<MockRuntime>>(
+  manager: &M,
+  position: PhysicalPosition<f64>,
+) {
+  manager
+    .app_handle()
+    .runtime_handle
+    .set_cursor_position(position);
+}
+
 #[cfg(test)]
 mod tests {
-  use std::time::Duration;
+  use tauri_utils::acl::{resolved::ResolvedScope, Value};
+
+  use super::*;
+
+  #[crate::command(root = "crate")]
+  fn read_scope(scope: crate::command::CommandScope<'_, String>) -> Vec<String> {
+    scope.allows().clone()
+  }
+
+  #[crate::command(root = "crate")]
+  fn not_allowed() {}
+
+  fn scoped_app() -> crate::App<MockRuntime> {
+    let resolved = Resolved {
+      allowed_commands: [(
+        CommandKey {
+          name: "plugin:test|read_scope".into(),
+          context: ExecutionContext::Local,
+        },
+        ResolvedCommand {
+          windows: vec![glob::Pattern::new("*").unwrap()],
+          scope: Some(0),
+        },
+      )]
+      .into_iter()
+      .collect(),
+      denied_commands: Default::default(),
+      command_scope: [(
+        0,
+        ResolvedScope {
+          allow: vec![Value::String("allowed".into())],
+          deny: Vec::new(),
+        },
+      )]
+      .into_iter()
+      .collect(),
+      global_scope: Default::default(),
+    };
+
+    mock_builder()
+      .plugin(
+        crate::plugin::Builder::new("test")
+          .invoke_handler(crate::generate_handler![read_scope, not_allowed])
+          .build(),
+      )
+      .build(mock_context_with_acl(resolved, noop_assets()))
+      .unwrap()
+  }
+
+  fn plugin_request(cmd: &str) -> InvokeRequest {
+    InvokeRequest {
+      cmd: format!("plugin:test|{cmd}"),
+      callback: crate::ipc::CallbackFn(0),
+      error: crate::ipc::CallbackFn(1),
+      body: Default::default(),
+      headers: Default::default(),
+    }
+  }
+
+  #[test]
+  fn command_scope_from_acl() {
+    let app = scoped_app();
+    let webview = crate::WebviewWindowBuilder::new(&app, "main", Default::default())
+      .build()
+      .unwrap();
+
+    assert_ipc_response(
+      &webview,
+      plugin_request("read_scope"),
+      Ok(vec!["allowed".to_string()]),
+    );
+    assert_ipc_response(&webview, plugin_request("not_allowed"), Err("NOT ALLOWED"));
+  }
+
+  #[crate::command(root = "crate")]
+  fn ping() -> &'static str {
+    "pong"
+  }
+
+  struct Greeting(&'static str);
+
+  #[crate::command(root = "crate")]
+  fn greet(greeting: crate::State<'_, Greeting>) -> &'static str {
+    greeting.0
+  }
+
+  #[test]
+  fn plugin_commands() {
+    let plugin = crate::plugin::Builder::new("greeter")
+      .invoke_handler(crate::generate_handler![greet, not_allowed])
+      .setup(|app, _api| {
+        app.manage(Greeting("hello"));
+        Ok(())
+      })
+      .build();
+    let app = mock_app_with_plugin(plugin, &["greet"]);
+    let webview = crate::WebviewWindowBuilder::new(&app, "main", Default::default())
+      .build()
+      .unwrap();
+
+    let request = |cmd: &str| InvokeRequest {
+      cmd: format!("plugin:greeter|{cmd}"),
+      ..plugin_request(cmd)
+    };
+    assert_ipc_response(&webview, request("greet"), Ok("hello"));
+    assert_ipc_response(&webview, request("not_allowed"), Err("NOT ALLOWED"));
+  }
+
+  #[crate::command(root = "crate")]
+  fn copy(app: crate::AppHandle<MockRuntime>, text: String) {
+    set_mock_clipboard(&app, text);
+  }
+
+  #[test]
+  fn mock_clipboard() {
+    let app = mock_builder()
+      .invoke_handler(crate::generate_handler![copy])
+      .build(mock_context(noop_assets()))
+      .unwrap();
+    let webview = crate::WebviewWindowBuilder::new(&app, "main", Default::default())
+      .build()
+      .unwrap();
+    assert_eq!(get_mock_clipboard(&app), None);
 
-  use super::mock_app;
+    assert_ipc_response(
+      &webview,
+      InvokeRequest {
+        cmd: "copy".into(),
+        callback: crate::ipc::CallbackFn(0),
+        error: crate::ipc::CallbackFn(1),
+        body: InvokeBody::Json(serde_json::json!({ "text": "copied" })),
+        headers: Default::default(),
+      },
+      Ok(()),
+    );
+    assert_eq!(get_mock_clipboard(&webview).as_deref(), Some("copied"));
+  }
+
+  #[test]
+  fn ipc_delay() {
+    let app = mock_builder()
+      .invoke_handler(crate::generate_handler![ping])
+      .build(mock_context(noop_assets()))
+      .unwrap();
+    let webview = crate::WebviewWindowBuilder::new(&app, "main", Default::default())
+      .build()
+      .unwrap();
+    let ping = || InvokeRequest {
+      cmd: "ping".into(),
+      callback: crate::ipc::CallbackFn(0),
+      error: crate::ipc::CallbackFn(1),
+      body: InvokeBody::default(),
+      headers: Default::default(),
+    };
+
+    assert_eq!(app.handle().runtime_handle.ipc_delay(), Duration::ZERO);
+
+    set_mock_ipc_delay_range(&app, Duration::from_millis(10)..Duration::from_millis(20));
+    for _ in 0..10 {
+      let delay = app.handle().runtime_handle.ipc_delay();
+      assert!(delay >= Duration::from_millis(10) && delay < Duration::from_millis(20));
+    }
+
+    // the slow response is delivered after the fast one even though it was requested first
+    let (tx, rx) = std::sync::mpsc::channel();
+    set_mock_ipc_delay(&app, Duration::from_millis(200));
+    let slow = {
+      let webview = webview.clone();
+      let tx = tx.clone();
+      let request = ping();
+      std::thread::spawn(move || {
+        get_ipc_response(&webview, request).unwrap();
+        tx.send("slow").unwrap();
+      })
+    };
+    std::thread::sleep(Duration::from_millis(50));
+    set_mock_ipc_delay(&app, Duration::ZERO);
+    get_ipc_response(&webview, ping()).unwrap();
+    tx.send("fast").unwrap();
+    slow.join().unwrap();
+
+    assert_eq!(rx.try_iter().collect::<Vec<_>>(), vec!["fast", "slow"]);
+  }
+
+  #[test]
+  fn command_prefix() {
+    let allowed_command = |name: &str| {
+      (
+        CommandKey {
+          name: name.into(),
+          context: ExecutionContext::Local,
+        },
+        ResolvedCommand {
+          windows: vec![glob::Pattern::new("*").unwrap()],
+          scope: None,
+        },
+      )
+    };
+    let resolved = Resolved {
+      allowed_commands: [
+        allowed_command("plugin:prefixed|ns:ping"),
+        allowed_command("plugin:prefixed|ping"),
+      ]
+      .into_iter()
+      .collect(),
+      denied_commands: Default::default(),
+      command_scope: Default::default(),
+      global_scope: Default::default(),
+    };
+
+    let app = mock_builder()
+      .plugin(
+        crate::plugin::Builder::new("prefixed")
+          .command_prefix("ns")
+          .invoke_handler(crate::generate_handler![ping])
+          .build(),
+      )
+      .build(mock_context_with_acl(resolved, noop_assets()))
+      .unwrap();
+    let webview = crate::WebviewWindowBuilder::new(&app, "main", Default::default())
+      .build()
+      .unwrap();
+
+    let request = |cmd: &str| InvokeRequest {
+      cmd: format!("plugin:prefixed|{cmd}"),
+      callback: crate::ipc::CallbackFn(0),
+      error: crate::ipc::CallbackFn(1),
+      body: Default::default(),
+      headers: Default::default(),
+    };
+    assert_ipc_response(&webview, request("ns:ping"), Ok("pong"));
+    assert_ipc_response(&webview, request("ping"), Err("Command ping not found"));
+  }
+
+  #[test]
+  fn file_drop() {
+    use std::{
+      path::PathBuf,
+      sync::{Arc, Mutex},
+    };
+
+    use crate::{PhysicalPosition, WindowEvent};
+
+    let app = mock_app();
+
+    let dropped = Arc::new(Mutex::new(Vec::new()));
+    let create_webview = |label: &str, file_drop_enabled: bool| {
+      let mut builder = crate::WebviewWindowBuilder::new(&app, label, Default::default());
+      if !file_drop_enabled {
+        builder = builder.disable_file_drop_handler();
+      }
+      let webview = builder.build().unwrap();
+      let dropped_ = dropped.clone();
+      let label = label.to_string();
+      webview.on_window_event(move |event| {
+        if let WindowEvent::FileDrop(FileDropEvent::Dropped { paths, .. }) = event {
+          dropped_
+            .lock()
+            .unwrap()
+            .push((label.clone(), paths.clone()));
+        }
+      });
+      webview
+    };
+
+    let enabled = create_webview("enabled", true);
+    let disabled = create_webview("disabled", false);
+
+    let paths = vec![PathBuf::from("a.txt"), PathBuf::from("b.txt")];
+    for webview in [&enabled, &disabled] {
+      simulate_file_drop(
+        webview,
+        FileDropEvent::Dropped {
+          paths: paths.clone(),
+          position: PhysicalPosition::new(0., 0.),
+        },
+      );
+    }
+
+    assert_eq!(
+      *dropped.lock().unwrap(),
+      vec![("enabled".to_string(), paths)]
+    );
+  }
+
+  #[cfg(desktop)]
+  #[test]
+  fn show_hidden_window_on_page_load() {
+    use crate::{utils::config::ShowTrigger, webview::PageLoadEvent};
+
+    let app = mock_app();
+
+    let webview = crate::WebviewWindowBuilder::from_config(
+      &app,
+      crate::utils::config::WindowConfig {
+        label: "hidden".into(),
+        visible: false,
+        show_when: Some(ShowTrigger::PageLoad),
+        ..Default::default()
+      },
+    )
+    .build()
+    .unwrap();
+    assert!(!webview.is_visible().unwrap());
+
+    let dispatcher = &webview.as_ref().webview.dispatcher;
+    dispatcher.page_load(PageLoadEvent::Started);
+    assert!(!webview.is_visible().unwrap());
+    dispatcher.page_load(PageLoadEvent::Finished);
+    assert!(webview.is_visible().unwrap());
+  }
+
+  #[test]
+  fn dir_assets() {
+    let root = std::env::temp_dir().join(format!("tauri-dir-assets-{}", std::process::id()));
+    std::fs::create_dir_all(root.join("js")).unwrap();
+    std::fs::write(root.join("index.html"), "<html></html>").unwrap();
+    std::fs::write(root.join("js/main.js"), "console.log('hi')").unwrap();
+
+    let assets = DirAssets::new(root.clone());
+
+    assert_eq!(
+      assets.get(&"index.html".into()).as_deref(),
+      Some(b"<html></html>".as_slice())
+    );
+    assert_eq!(
+      assets.get(&"/js/main.js".into()).as_deref(),
+      Some(b"console.log('hi')".as_slice())
+    );
+    assert!(assets.get(&"missing.html".into()).is_none());
+    assert!(assets.get(&"../index.html".into()).is_none());
+
+    let mut keys = assets
+      .iter()
+      .map(|(key, _)| key.into_owned())
+      .collect::<Vec<_>>();
+    keys.sort();
+    assert_eq!(keys, vec!["/index.html", "/js/main.js"]);
+    assert_eq!(assets.csp_hashes(&"index.html".into()).count(), 1);
+
+    std::fs::remove_dir_all(root).unwrap();
+  }
 
   #[test]
   fn run_app() {
@@ -284,6 +1180,7 @@ mod tests {
     let w = crate::WebviewWindowBuilder::new(&app, "main", Default::default())
       .build()
       .unwrap();
+    let events = record_window_events(&w.as_ref().window());
 
     std::thread::spawn(move || {
       std::thread::sleep(Duration::from_secs(1));
@@ -293,5 +1190,7 @@ mod tests {
     app.run(|_app, event| {
       println!("{:?}", event);
     });
+
+    assert!(events.contains(|event| matches!(event, WindowEvent::Destroyed)));
   }
 }
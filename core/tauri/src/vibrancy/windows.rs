@@ -12,6 +12,7 @@ use std::ffi::c_void;
 use crate::utils::config::WindowEffectsConfig;
 use crate::window::{Color, Effect};
 use raw_window_handle::HasRawWindowHandle;
+use tauri_utils::debug_eprintln;
 use windows::Win32::Foundation::HWND;
 
 pub fn apply_effects(window: impl HasRawWindowHandle, effects: WindowEffectsConfig) {
@@ -34,17 +35,33 @@ pub fn apply_effects(window: impl HasRawWindowHandle, effects: WindowEffectsConf
     return;
   };
 
-  match effect {
-    Effect::Blur => window_vibrancy::apply_blur(window, color.map(Into::into)),
-    Effect::Acrylic => window_vibrancy::apply_acrylic(window, color.map(Into::into)),
-    Effect::Mica => window_vibrancy::apply_mica(window, None),
-    Effect::MicaDark => window_vibrancy::apply_mica(window, Some(true)),
-    Effect::MicaLight => window_vibrancy::apply_mica(window, Some(false)),
-    Effect::Tabbed => window_vibrancy::apply_tabbed(window, None),
-    Effect::TabbedDark => window_vibrancy::apply_tabbed(window, Some(true)),
-    Effect::TabbedLight => window_vibrancy::apply_tabbed(window, Some(false)),
-    _ => unreachable!(),
-  };
+  // the effects fail on the Windows versions that do not support them, so walk the fallback chain
+  let mut candidate = Some(*effect);
+  while let Some(e) = candidate {
+    let result = match e {
+      Effect::Blur => window_vibrancy::apply_blur(&window, color.map(Into::into)),
+      Effect::Acrylic => window_vibrancy::apply_acrylic(&window, color.map(Into::into)),
+      Effect::Mica => window_vibrancy::apply_mica(&window, None),
+      Effect::MicaDark => window_vibrancy::apply_mica(&window, Some(true)),
+      Effect::MicaLight => window_vibrancy::apply_mica(&window, Some(false)),
+      Effect::Tabbed => window_vibrancy::apply_tabbed(&window, None),
+      Effect::TabbedDark => window_vibrancy::apply_tabbed(&window, Some(true)),
+      Effect::TabbedLight => window_vibrancy::apply_tabbed(&window, Some(false)),
+      _ => unreachable!(),
+    };
+
+    match result {
+      Ok(()) => {
+        if e != *effect {
+          debug_eprintln!("the {effect:?} window effect is not supported on this Windows version, using {e:?} instead");
+        }
+        return;
+      }
+      Err(_) => candidate = e.fallback(),
+    }
+  }
+
+  debug_eprintln!("the {effect:?} window effect is not supported on this Windows version");
 }
 
 pub fn clear_effects(window: impl HasRawWindowHandle) {
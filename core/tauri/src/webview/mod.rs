@@ -6,6 +6,7 @@
 
 pub(crate) mod plugin;
 mod webview_window;
+pub(crate) mod zoom;
 
 pub use webview_window::{WebviewWindow, WebviewWindowBuilder};
 
@@ -680,8 +681,19 @@ impl<R: Runtime> WebviewBuilder<R> {
     self
   }
 
+  /// Sets whether the Tauri API is injected on `window.__TAURI__` in this webview,
+  /// overriding [`withGlobalTauri`](crate::utils::config::BuildConfig::with_global_tauri).
+  ///
+  /// Disable it on webviews loading remote content.
+  #[must_use]
+  pub fn with_global_tauri(mut self, enabled: bool) -> Self {
+    self.webview_attributes = self.webview_attributes.with_global_tauri(enabled);
+    self
+  }
+
   /// Adds the provided JavaScript to a list of scripts that should be run after the global object has been created,
   /// but before the HTML document has been parsed and before any other script included by the HTML document is run.
+  /// The scripts injected by Tauri run first, so `window.__TAURI_INTERNALS__` is available.
   ///
   /// Since it runs on all top-level document and child frame page navigations,
   /// it's recommended to check the `window.location` to guard your script from running on unexpected origins.
@@ -724,7 +736,10 @@ fn main() {
     self
   }
 
-  /// Set the user agent for the webview
+  /// Set the user agent for the webview, overriding the `defaultUserAgent` configuration value.
+  ///
+  /// Supports the placeholders documented on [`crate::utils::config::WindowConfig#structfield.user_agent`],
+  /// e.g. `MyApp/{{app_version}}`.
   #[must_use]
   pub fn user_agent(mut self, user_agent: &str) -> Self {
     self.webview_attributes.user_agent = Some(user_agent.to_string());
@@ -785,6 +800,43 @@ fn main() {
     self
   }
 
+  /// Enable or disable the default context menu shown on right click. Enabled by default.
+  ///
+  /// This does not affect the devtools, see [`WindowConfig::enable_context_menu`](crate::utils::config::WindowConfig::enable_context_menu).
+  #[must_use]
+  pub fn context_menu(mut self, enabled: bool) -> Self {
+    self.webview_attributes.context_menu = enabled;
+    self
+  }
+
+  /// Restricts the navigations of the webview to the URLs matching the glob patterns.
+  ///
+  /// See [`WindowConfig::navigation_allowlist`](crate::utils::config::WindowConfig::navigation_allowlist) for the pattern syntax.
+  /// The [`Self::on_navigation`] handler only runs for the allowed URLs.
+  #[must_use]
+  pub fn navigation_allowlist<I: IntoIterator<Item = S>, S: Into<String>>(
+    mut self,
+    allowlist: I,
+  ) -> Self {
+    self.webview_attributes.navigation_allowlist =
+      Some(allowlist.into_iter().map(Into::into).collect());
+    self
+  }
+
+  /// Opens the devtools (Web Inspector) when the webview is created.
+  ///
+  /// The devtools are only enabled on debug builds or with the `devtools` feature flag,
+  /// the setting is ignored otherwise.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Android / iOS:** Unsupported.
+  #[must_use]
+  pub fn open_devtools(mut self, open: bool) -> Self {
+    self.webview_attributes.open_devtools = open;
+    self
+  }
+
   /// Enable or disable transparency for the WebView.
   #[cfg(any(not(target_os = "macos"), feature = "macos-private-api"))]
   #[cfg_attr(
@@ -934,6 +986,21 @@ impl<R: Runtime> Webview<R> {
     self.webview.dispatcher.set_focus().map_err(Into::into)
   }
 
+  /// Sets the zoom level of the webview, where `1.0` is the default size.
+  ///
+  /// The zoom level is saved when [`WindowConfig::remember_zoom`](crate::utils::config::WindowConfig::remember_zoom) is enabled.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Android**: Unsupported.
+  pub fn set_zoom(&self, scale_factor: f64) -> crate::Result<()> {
+    self.webview.dispatcher.set_zoom(scale_factor)?;
+    if zoom::is_remembered(self) {
+      zoom::save(self, scale_factor)?;
+    }
+    Ok(())
+  }
+
   /// Returns the webview position.
   ///
   /// - For child webviews, returns the position of the top-left hand corner of the webviews's client area relative to the top-left hand corner of the parent window.
@@ -1093,23 +1160,20 @@ fn main() {
       request.headers,
     );
 
-    let resolved_acl = manager
-      .runtime_authority
-      .resolve_access(
-        &request.cmd,
-        &message.webview.webview.label,
-        if is_local {
-          Origin::Local
-        } else {
-          Origin::Remote {
-            domain: current_url
-              .domain()
-              .map(|d| d.to_string())
-              .unwrap_or_default(),
-          }
-        },
-      )
-      .cloned();
+    let resolved_acl = manager.runtime_authority.resolve_access(
+      &request.cmd,
+      &message.webview.webview.label,
+      if is_local {
+        Origin::Local
+      } else {
+        Origin::Remote {
+          domain: current_url
+            .domain()
+            .map(|d| d.to_string())
+            .unwrap_or_default(),
+        }
+      },
+    );
 
     let mut invoke = Invoke {
       message,
@@ -1558,9 +1622,158 @@ impl<'de, R: Runtime> CommandArg<'de, R> for Webview<R> {
 
 #[cfg(test)]
 mod tests {
+  use crate::{test::MockRuntime, utils::config::WindowConfig, App, WebviewWindow};
+
+  /// Creates a webview window from the given configuration with the mock runtime.
+  fn webview_window(app: &App<MockRuntime>, config: WindowConfig) -> WebviewWindow<MockRuntime> {
+    crate::WebviewWindowBuilder::from_config(app, config)
+      .build()
+      .unwrap()
+  }
+
   #[test]
   fn webview_is_send_sync() {
     crate::test_utils::assert_send::<super::Webview>();
     crate::test_utils::assert_sync::<super::Webview>();
   }
+
+  #[test]
+  fn context_menu_from_config() {
+    use crate::{test::MockRuntime, utils::config::WindowConfig};
+
+    let builder = super::WebviewBuilder::<MockRuntime>::from_config(WindowConfig::default());
+    assert!(builder.webview_attributes.context_menu);
+
+    let builder = super::WebviewBuilder::<MockRuntime>::from_config(WindowConfig {
+      enable_context_menu: false,
+      ..Default::default()
+    });
+    assert!(!builder.webview_attributes.context_menu);
+    assert!(builder.context_menu(true).webview_attributes.context_menu);
+  }
+
+  #[test]
+  fn client_side_decorations_from_config() {
+    use crate::{test::MockRuntime, utils::config::WindowConfig};
+
+    let builder = super::WebviewBuilder::<MockRuntime>::from_config(WindowConfig {
+      decorations: false,
+      client_side_decorations: true,
+      ..Default::default()
+    });
+    assert!(builder.webview_attributes.client_side_decorations);
+
+    // no-op on decorated windows, which have a native shadow
+    let builder = super::WebviewBuilder::<MockRuntime>::from_config(WindowConfig {
+      client_side_decorations: true,
+      ..Default::default()
+    });
+    assert!(!builder.webview_attributes.client_side_decorations);
+  }
+
+  #[test]
+  fn navigation_allowlist_blocks_navigation() {
+    use crate::{
+      sealed::ManagerBase, test::mock_app, utils::config::WindowConfig, WebviewWindowBuilder,
+    };
+
+    let app = mock_app();
+    let mut webview = WebviewWindowBuilder::from_config(
+      &app,
+      WindowConfig {
+        navigation_allowlist: Some(vec!["https://tauri.app".into()]),
+        ..Default::default()
+      },
+    )
+    .build()
+    .unwrap();
+
+    webview.navigate("https://tauri.app/docs".parse().unwrap());
+    assert_eq!(webview.url().as_str(), "https://tauri.app/docs");
+
+    webview.navigate("https://attacker.com".parse().unwrap());
+    assert_eq!(webview.url().as_str(), "https://tauri.app/docs");
+
+    // the app URL is always allowed
+    let app_url = app.manager().get_url().join("index.html").unwrap();
+    webview.navigate(app_url.clone());
+    assert_eq!(webview.url(), app_url);
+  }
+
+  #[test]
+  fn accept_first_mouse_from_config() {
+    let app = crate::test::mock_app();
+
+    let webview = webview_window(
+      &app,
+      WindowConfig {
+        label: "accept".into(),
+        accept_first_mouse: true,
+        ..Default::default()
+      },
+    );
+    assert!(webview.as_ref().webview.dispatcher.accept_first_mouse());
+
+    let webview = webview_window(
+      &app,
+      WindowConfig {
+        label: "ignore".into(),
+        ..Default::default()
+      },
+    );
+    assert!(!webview.as_ref().webview.dispatcher.accept_first_mouse());
+  }
+
+  #[test]
+  fn with_global_tauri_override() {
+    let mut context = crate::test::mock_context(crate::test::noop_assets());
+    context.config_mut().build.with_global_tauri = true;
+    let app = crate::test::mock_builder().build(context).unwrap();
+
+    let has_global_tauri = |label: &str, with_global_tauri: Option<bool>| {
+      webview_window(
+        &app,
+        WindowConfig {
+          label: label.into(),
+          with_global_tauri,
+          ..Default::default()
+        },
+      )
+      .as_ref()
+      .webview
+      .dispatcher
+      .initialization_scripts()
+      .iter()
+      .any(|script| script.contains("__TAURI_IIFE__"))
+    };
+
+    assert!(has_global_tauri("inherit", None));
+    assert!(has_global_tauri("trusted", Some(true)));
+    assert!(!has_global_tauri("remote", Some(false)));
+  }
+
+  #[cfg(any(debug_assertions, feature = "devtools"))]
+  #[test]
+  fn open_devtools_from_config() {
+    let app = crate::test::mock_app();
+
+    let webview = webview_window(
+      &app,
+      WindowConfig {
+        label: "inspect".into(),
+        open_devtools: true,
+        ..Default::default()
+      },
+    );
+    assert!(webview.is_devtools_open());
+
+    let webview = webview_window(
+      &app,
+      WindowConfig {
+        label: "main".into(),
+        ..Default::default()
+      },
+    );
+    assert!(!webview.is_devtools_open());
+  }
 }
@@ -23,6 +23,8 @@ use crate::{
   window::Monitor,
 };
 use serde::Serialize;
+#[cfg(desktop)]
+use tauri_utils::config::ShowTrigger;
 use tauri_utils::config::{WebviewUrl, WindowConfig};
 use url::Url;
 
@@ -31,8 +33,7 @@ use crate::{
   ipc::{InvokeError, OwnedInvokeResponder},
   manager::{webview::WebviewLabelDef, AppManager},
   sealed::{ManagerBase, RuntimeOrDispatch},
-  webview::PageLoadPayload,
-  webview::WebviewBuilder,
+  webview::{PageLoadPayload, WebviewBuilder},
   window::WindowBuilder,
   AppHandle, Event, EventId, Manager, Runtime, Webview, Window, WindowEvent,
 };
@@ -46,6 +47,8 @@ use windows::Win32::Foundation::HWND;
 pub struct WebviewWindowBuilder<'a, R: Runtime, M: Manager<R>> {
   window_builder: WindowBuilder<'a, R, M>,
   webview_builder: WebviewBuilder<R>,
+  #[cfg(desktop)]
+  show_when: Option<ShowTrigger>,
 }
 
 impl<'a, R: Runtime, M: Manager<R>> WebviewWindowBuilder<'a, R, M> {
@@ -101,6 +104,8 @@ impl<'a, R: Runtime, M: Manager<R>> WebviewWindowBuilder<'a, R, M> {
     Self {
       window_builder: WindowBuilder::new(manager, &label),
       webview_builder: WebviewBuilder::new(&label, url),
+      #[cfg(desktop)]
+      show_when: None,
     }
   }
 
@@ -134,6 +139,12 @@ async fn reopen_window(app: tauri::AppHandle) {
   /// [the Webview2 issue]: https://github.com/tauri-apps/wry/issues/583
   pub fn from_config(manager: &'a M, config: WindowConfig) -> Self {
     Self {
+      #[cfg(desktop)]
+      show_when: if config.visible || config.splash.is_some() {
+        None
+      } else {
+        config.show_when.clone()
+      },
       window_builder: WindowBuilder::from_config(manager, config.clone()),
       webview_builder: WebviewBuilder::from_config(config),
     }
@@ -311,22 +322,64 @@ tauri::Builder::default()
   }
 
   /// Creates a new window.
-  pub fn build(self) -> crate::Result<WebviewWindow<R>> {
+  #[cfg_attr(mobile, allow(unused_mut))]
+  pub fn build(mut self) -> crate::Result<WebviewWindow<R>> {
+    #[cfg(desktop)]
+    let show_on_event = self.attach_show_trigger();
     let (_window, webview) = self.window_builder.with_webview(self.webview_builder)?;
-    Ok(WebviewWindow { webview })
+    let window = WebviewWindow { webview };
+    #[cfg(desktop)]
+    if let Some(event) = show_on_event {
+      window.show_on_event(event);
+    }
+    Ok(window)
   }
 
+  #[cfg_attr(mobile, allow(unused_mut))]
   pub(crate) fn build_internal(
-    self,
+    mut self,
     window_labels: &[String],
     webview_labels: &[WebviewLabelDef],
   ) -> crate::Result<WebviewWindow<R>> {
+    #[cfg(desktop)]
+    let show_on_event = self.attach_show_trigger();
     let (_window, webview) = self.window_builder.with_webview_internal(
       self.webview_builder,
       window_labels,
       webview_labels,
     )?;
-    Ok(WebviewWindow { webview })
+    let window = WebviewWindow { webview };
+    #[cfg(desktop)]
+    if let Some(event) = show_on_event {
+      window.show_on_event(event);
+    }
+    Ok(window)
+  }
+
+  /// Shows the window when its page finishes loading if using [`ShowTrigger::PageLoad`],
+  /// keeping the page load handler set on the builder.
+  ///
+  /// Returns the event that shows the window if using [`ShowTrigger::Event`].
+  #[cfg(desktop)]
+  fn attach_show_trigger(&mut self) -> Option<String> {
+    match self.show_when.take()? {
+      ShowTrigger::PageLoad => {
+        let on_page_load_handler = self.webview_builder.on_page_load_handler.take();
+        self
+          .webview_builder
+          .on_page_load_handler
+          .replace(Box::new(move |webview, payload| {
+            if payload.event() == crate::webview::PageLoadEvent::Finished {
+              let _ = webview.window().show();
+            }
+            if let Some(handler) = &on_page_load_handler {
+              handler(webview, payload);
+            }
+          }));
+        None
+      }
+      ShowTrigger::Event(event) => Some(event),
+    }
   }
 }
 
@@ -428,6 +481,8 @@ impl<'a, R: Runtime, M: Manager<R>> WebviewWindowBuilder<'a, R, M> {
   }
 
   /// Whether to start the window in fullscreen or not.
+  ///
+  /// Takes precedence over [`Self::maximized`], so a fullscreen window is never maximized on creation.
   #[must_use]
   pub fn fullscreen(mut self, fullscreen: bool) -> Self {
     self.window_builder = self.window_builder.fullscreen(fullscreen);
@@ -453,6 +508,8 @@ impl<'a, R: Runtime, M: Manager<R>> WebviewWindowBuilder<'a, R, M> {
   }
 
   /// Whether the window should be maximized upon creation.
+  ///
+  /// Ignored when the window starts in [fullscreen](Self::fullscreen).
   #[must_use]
   pub fn maximized(mut self, maximized: bool) -> Self {
     self.window_builder = self.window_builder.maximized(maximized);
@@ -466,7 +523,9 @@ impl<'a, R: Runtime, M: Manager<R>> WebviewWindowBuilder<'a, R, M> {
     self
   }
 
-  /// Forces a theme or uses the system settings if None was provided.
+  /// Forces a theme or uses the system settings if None or [`Theme::System`](crate::Theme::System) was provided.
+  ///
+  /// A forced theme is kept when the system theme changes.
   ///
   /// ## Platform-specific
   ///
@@ -553,13 +612,35 @@ impl<'a, R: Runtime, M: Manager<R>> WebviewWindowBuilder<'a, R, M> {
   ///   - `false` has no effect on decorated window, shadows are always ON.
   ///   - `true` will make ndecorated window have a 1px white border,
   /// and on Windows 11, it will have a rounded corners.
-  /// - **Linux:** Unsupported.
+  /// - **Linux:** Unsupported, see [`Self::client_side_decorations`].
   #[must_use]
   pub fn shadow(mut self, enable: bool) -> Self {
     self.window_builder = self.window_builder.shadow(enable);
     self
   }
 
+  /// Whether the undecorated window reserves a transparent margin around its content
+  /// that can be styled with CSS, e.g. to draw a shadow or rounded corners.
+  ///
+  /// See [`WindowConfig::client_side_decorations`](crate::utils::config::WindowConfig::client_side_decorations).
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Linux:** Should only be used with undecorated windows.
+  /// - **Windows / macOS:** Unsupported, the native window shadow is used instead.
+  #[must_use]
+  pub fn client_side_decorations(mut self, enable: bool) -> Self {
+    #[cfg(target_os = "linux")]
+    if enable {
+      self.window_builder = self.window_builder.transparent(true);
+    }
+    self.webview_builder.webview_attributes = self
+      .webview_builder
+      .webview_attributes
+      .client_side_decorations(enable);
+    self
+  }
+
   /// Sets a parent to the window to be created.
   ///
   /// A child window has the WS_CHILD style and is confined to the client area of its parent window.
@@ -632,6 +713,17 @@ impl<'a, R: Runtime, M: Manager<R>> WebviewWindowBuilder<'a, R, M> {
     self
   }
 
+  /// Whether the window always opens as a tab (`true`) or never does (`false`) on macOS,
+  /// instead of following the system setting.
+  ///
+  /// Only applies to windows with a [tabbing identifier](Self::tabbing_identifier).
+  #[cfg(target_os = "macos")]
+  #[must_use]
+  pub fn auto_tabbing(mut self, enabled: bool) -> Self {
+    self.window_builder = self.window_builder.auto_tabbing(enabled);
+    self
+  }
+
   /// Sets window effects.
   ///
   /// Requires the window to be transparent.
@@ -655,8 +747,19 @@ impl<'a, R: Runtime, M: Manager<R>> WebviewWindowBuilder<'a, R, M> {
     self
   }
 
+  /// Sets whether the Tauri API is injected on `window.__TAURI__` in this window,
+  /// overriding [`withGlobalTauri`](crate::utils::config::BuildConfig::with_global_tauri).
+  ///
+  /// Disable it on windows loading remote content.
+  #[must_use]
+  pub fn with_global_tauri(mut self, enabled: bool) -> Self {
+    self.webview_builder = self.webview_builder.with_global_tauri(enabled);
+    self
+  }
+
   /// Adds the provided JavaScript to a list of scripts that should be run after the global object has been created,
   /// but before the HTML document has been parsed and before any other script included by the HTML document is run.
+  /// The scripts injected by Tauri run first, so `window.__TAURI_INTERNALS__` is available.
   ///
   /// Since it runs on all top-level document and child frame page navigations,
   /// it's recommended to check the `window.location` to guard your script from running on unexpected origins.
@@ -695,7 +798,10 @@ fn main() {
     self
   }
 
-  /// Set the user agent for the webview
+  /// Set the user agent for the webview, overriding the `defaultUserAgent` configuration value.
+  ///
+  /// Supports the placeholders documented on [`crate::utils::config::WindowConfig#structfield.user_agent`],
+  /// e.g. `MyApp/{{app_version}}`.
   #[must_use]
   pub fn user_agent(mut self, user_agent: &str) -> Self {
     self.webview_builder = self.webview_builder.user_agent(user_agent);
@@ -755,6 +861,42 @@ fn main() {
     self
   }
 
+  /// Enable or disable the default context menu shown on right click. Enabled by default.
+  ///
+  /// This does not affect the devtools, see [`WindowConfig::enable_context_menu`](crate::utils::config::WindowConfig::enable_context_menu).
+  #[must_use]
+  pub fn context_menu(mut self, enabled: bool) -> Self {
+    self.webview_builder = self.webview_builder.context_menu(enabled);
+    self
+  }
+
+  /// Restricts the navigations of the webview to the URLs matching the glob patterns.
+  ///
+  /// See [`WindowConfig::navigation_allowlist`](crate::utils::config::WindowConfig::navigation_allowlist) for the pattern syntax.
+  /// The [`Self::on_navigation`] handler only runs for the allowed URLs.
+  #[must_use]
+  pub fn navigation_allowlist<I: IntoIterator<Item = S>, S: Into<String>>(
+    mut self,
+    allowlist: I,
+  ) -> Self {
+    self.webview_builder = self.webview_builder.navigation_allowlist(allowlist);
+    self
+  }
+
+  /// Opens the devtools (Web Inspector) when the webview is created.
+  ///
+  /// The devtools are only enabled on debug builds or with the `devtools` feature flag,
+  /// the setting is ignored otherwise.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Android / iOS:** Unsupported.
+  #[must_use]
+  pub fn open_devtools(mut self, open: bool) -> Self {
+    self.webview_builder = self.webview_builder.open_devtools(open);
+    self
+  }
+
   /// Sets the webview to automatically grow and shrink its size and position when the parent window resizes.
   #[must_use]
   pub fn auto_resize(mut self) -> Self {
@@ -832,6 +974,24 @@ impl<'de, R: Runtime> CommandArg<'de, R> for WebviewWindow<R> {
 
 /// Base webview window functions.
 impl<R: Runtime> WebviewWindow<R> {
+  /// Shows the window once the given global event is emitted, see [`ShowTrigger::Event`].
+  ///
+  /// The trigger is skipped if the event name is invalid, leaving the window hidden.
+  #[cfg(desktop)]
+  fn show_on_event(&self, event: String) {
+    if !crate::event::is_event_name_valid(&event) {
+      tauri_utils::debug_eprintln!(
+        "the `{}` window is not shown on the invalid event name `{event}`",
+        self.label()
+      );
+      return;
+    }
+    let window = self.clone();
+    self.once_global(event, move |_| {
+      let _ = window.show();
+    });
+  }
+
   /// Initializes a [`WebviewWindowBuilder`] with the given window label and webview URL.
   ///
   /// Data URLs are only supported with the `webview-data-url` feature flag.
@@ -1360,6 +1520,17 @@ impl<R: Runtime> WebviewWindow<R> {
     self.webview.window().set_focus()
   }
 
+  /// Sets the zoom level of the webview, where `1.0` is the default size.
+  ///
+  /// The zoom level is saved when [`WindowConfig::remember_zoom`](crate::utils::config::WindowConfig::remember_zoom) is enabled.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Android**: Unsupported.
+  pub fn set_zoom(&self, scale_factor: f64) -> crate::Result<()> {
+    self.webview.set_zoom(scale_factor)
+  }
+
   /// Sets this window' icon.
   pub fn set_icon(&self, icon: Icon) -> crate::Result<()> {
     self.webview.window().set_icon(icon)
@@ -0,0 +1,142 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! Persistence of the zoom level for the webviews using [`WindowConfig::remember_zoom`](crate::utils::config::WindowConfig::remember_zoom).
+
+use std::{
+  collections::HashMap,
+  path::{Path, PathBuf},
+};
+
+use tauri_runtime::WebviewDispatch;
+use tauri_utils::debug_eprintln;
+
+use crate::{Manager, Runtime, Webview};
+
+/// The file storing the zoom level of all webviews, in the app config directory.
+const ZOOM_FILE_NAME: &str = ".webview-zoom.json";
+
+/// The range of the restored zoom levels, matching the one of the browsers.
+const ZOOM_RANGE: std::ops::RangeInclusive<f64> = 0.25..=5.;
+
+fn zoom_path<R: Runtime>(webview: &Webview<R>) -> crate::Result<PathBuf> {
+  Ok(webview.path().app_config_dir()?.join(ZOOM_FILE_NAME))
+}
+
+fn load(path: &Path) -> HashMap<String, f64> {
+  let Ok(zoom) = std::fs::read(path) else {
+    return HashMap::new();
+  };
+  serde_json::from_slice(&zoom).unwrap_or_else(|e| {
+    debug_eprintln!(
+      "ignoring the corrupted webview zoom file {}: {e}",
+      path.display()
+    );
+    HashMap::new()
+  })
+}
+
+/// Clamps the saved zoom level to [`ZOOM_RANGE`], ignoring invalid values.
+fn sanitize(label: &str, zoom: f64) -> Option<f64> {
+  if !zoom.is_finite() {
+    debug_eprintln!("ignoring the invalid saved zoom level {zoom} of the `{label}` webview");
+    return None;
+  }
+  let clamped = zoom.clamp(*ZOOM_RANGE.start(), *ZOOM_RANGE.end());
+  if clamped != zoom {
+    debug_eprintln!(
+      "the saved zoom level {zoom} of the `{label}` webview is out of range, using {clamped}"
+    );
+  }
+  Some(clamped)
+}
+
+/// Whether the zoom level of the webview is saved, which is only supported for the windows created from the configuration.
+pub(crate) fn is_remembered<R: Runtime>(webview: &Webview<R>) -> bool {
+  webview
+    .config()
+    .tauri
+    .windows
+    .iter()
+    .any(|config| config.label == webview.label() && config.remember_zoom)
+}
+
+/// Applies the saved zoom level to the webview, if any.
+pub(crate) fn restore<R: Runtime>(webview: &Webview<R>) -> crate::Result<()> {
+  restore_from(webview, &zoom_path(webview)?)
+}
+
+fn restore_from<R: Runtime>(webview: &Webview<R>, path: &Path) -> crate::Result<()> {
+  let label = webview.label();
+  if let Some(zoom) = load(path)
+    .get(label)
+    .and_then(|zoom| sanitize(label, *zoom))
+  {
+    webview.webview.dispatcher.set_zoom(zoom)?;
+  }
+  Ok(())
+}
+
+/// Saves the zoom level of the webview.
+pub(crate) fn save<R: Runtime>(webview: &Webview<R>, zoom: f64) -> crate::Result<()> {
+  save_to(webview, &zoom_path(webview)?, zoom)
+}
+
+fn save_to<R: Runtime>(webview: &Webview<R>, path: &Path, zoom: f64) -> crate::Result<()> {
+  // serde_json writes non-finite numbers as `null`, which would corrupt the whole file
+  if !zoom.is_finite() {
+    debug_eprintln!(
+      "not saving the invalid zoom level {zoom} of the `{}` webview",
+      webview.label()
+    );
+    return Ok(());
+  }
+
+  let mut levels = load(path);
+  levels.insert(webview.label().into(), zoom);
+
+  if let Some(parent) = path.parent() {
+    std::fs::create_dir_all(parent)?;
+  }
+  std::fs::write(path, serde_json::to_vec(&levels)?)?;
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{restore_from, save_to};
+  use crate::{test::mock_app, WebviewUrl, WebviewWindowBuilder};
+
+  #[test]
+  fn save_and_restore_zoom() {
+    let app = mock_app();
+    let window = WebviewWindowBuilder::new(&app, "main", WebviewUrl::default())
+      .build()
+      .unwrap();
+    let webview = window.as_ref();
+    let path = std::env::temp_dir().join(format!("tauri-zoom-{}.json", std::process::id()));
+
+    save_to(webview, &path, 1.5).unwrap();
+    restore_from(webview, &path).unwrap();
+    assert_eq!(webview.webview.dispatcher.zoom(), 1.5);
+
+    // out of range levels are clamped
+    save_to(webview, &path, 100.).unwrap();
+    restore_from(webview, &path).unwrap();
+    assert_eq!(webview.webview.dispatcher.zoom(), 5.);
+
+    // non-finite levels are not saved and do not corrupt the file
+    save_to(webview, &path, 2.).unwrap();
+    save_to(webview, &path, f64::NAN).unwrap();
+    restore_from(webview, &path).unwrap();
+    assert_eq!(webview.webview.dispatcher.zoom(), 2.);
+
+    // corrupted files are ignored
+    std::fs::write(&path, "{").unwrap();
+    restore_from(webview, &path).unwrap();
+    assert_eq!(webview.webview.dispatcher.zoom(), 2.);
+
+    std::fs::remove_file(path).unwrap();
+  }
+}
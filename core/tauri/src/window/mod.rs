@@ -4,7 +4,11 @@
 
 //! The Tauri window types and functions.
 
+#[cfg(desktop)]
+mod placement;
 pub(crate) mod plugin;
+#[cfg(desktop)]
+pub(crate) mod state;
 
 use tauri_runtime::ResizeDirection;
 use tauri_runtime::{
@@ -120,6 +124,9 @@ unstable_struct!(
     #[cfg(desktop)]
     on_menu_event: Option<crate::app::GlobalMenuEventListener<Window<R>>>,
     window_effects: Option<WindowEffectsConfig>,
+    /// The physical position on the monitor selected with [`WindowConfig::monitor`].
+    #[cfg(desktop)]
+    monitor_position: Option<PhysicalPosition<i32>>,
   }
 );
 
@@ -199,13 +206,16 @@ async fn create_window(app: tauri::AppHandle) {
     Self {
       manager,
       label: label.into(),
-      window_builder: <R::WindowDispatcher as WindowDispatch<EventLoopMessage>>::WindowBuilder::new(
-      ),
+      window_builder:
+        <R::WindowDispatcher as WindowDispatch<EventLoopMessage>>::WindowBuilder::new()
+          .theme(manager.config().tauri.default_theme),
       #[cfg(desktop)]
       menu: None,
       #[cfg(desktop)]
       on_menu_event: None,
       window_effects: None,
+      #[cfg(desktop)]
+      monitor_position: None,
     }
   }
 
@@ -237,7 +247,10 @@ async fn reopen_window(app: tauri::AppHandle) {
   )]
   ///
   /// [the Webview2 issue]: https://github.com/tauri-apps/wry/issues/583
-  pub fn from_config(manager: &'a M, config: WindowConfig) -> Self {
+  pub fn from_config(manager: &'a M, mut config: WindowConfig) -> Self {
+    config.theme = manager.config().tauri.theme_for(&config);
+    #[cfg(desktop)]
+    let monitor_position = placement::apply(manager.app_handle(), &mut config);
     Self {
       manager,
       label: config.label.clone(),
@@ -250,6 +263,8 @@ async fn reopen_window(app: tauri::AppHandle) {
       menu: None,
       #[cfg(desktop)]
       on_menu_event: None,
+      #[cfg(desktop)]
+      monitor_position,
     }
   }
 
@@ -407,6 +422,11 @@ tauri::Builder::default()
       window.on_menu_event(handler);
     }
 
+    #[cfg(desktop)]
+    if let Some(position) = self.monitor_position {
+      window.set_position(position)?;
+    }
+
     if let Some(effects) = self.window_effects {
       crate::vibrancy::set_window_effects(&window, Some(effects))?;
     }
@@ -514,6 +534,8 @@ impl<'a, R: Runtime, M: Manager<R>> WindowBuilder<'a, R, M> {
   }
 
   /// Whether to start the window in fullscreen or not.
+  ///
+  /// Takes precedence over [`Self::maximized`], so a fullscreen window is never maximized on creation.
   #[must_use]
   pub fn fullscreen(mut self, fullscreen: bool) -> Self {
     self.window_builder = self.window_builder.fullscreen(fullscreen);
@@ -539,6 +561,8 @@ impl<'a, R: Runtime, M: Manager<R>> WindowBuilder<'a, R, M> {
   }
 
   /// Whether the window should be maximized upon creation.
+  ///
+  /// Ignored when the window starts in [fullscreen](Self::fullscreen).
   #[must_use]
   pub fn maximized(mut self, maximized: bool) -> Self {
     self.window_builder = self.window_builder.maximized(maximized);
@@ -552,7 +576,10 @@ impl<'a, R: Runtime, M: Manager<R>> WindowBuilder<'a, R, M> {
     self
   }
 
-  /// Forces a theme or uses the system settings if None was provided.
+  /// Forces a theme or uses the system settings if None or [`Theme::System`](crate::Theme::System) was provided.
+  ///
+  /// A forced theme is kept when the system theme changes.
+  /// Defaults to [`TauriConfig::default_theme`](crate::utils::config::TauriConfig::default_theme).
   ///
   /// ## Platform-specific
   ///
@@ -717,6 +744,17 @@ impl<'a, R: Runtime, M: Manager<R>> WindowBuilder<'a, R, M> {
     self
   }
 
+  /// Whether the window always opens as a tab (`true`) or never does (`false`) on macOS,
+  /// instead of following the system setting.
+  ///
+  /// Only applies to windows with a [tabbing identifier](Self::tabbing_identifier).
+  #[cfg(target_os = "macos")]
+  #[must_use]
+  pub fn auto_tabbing(mut self, enabled: bool) -> Self {
+    self.window_builder = self.window_builder.auto_tabbing(enabled);
+    self
+  }
+
   /// Sets window effects.
   ///
   /// Requires the window to be transparent.
@@ -1990,4 +2028,160 @@ mod tests {
     crate::test_utils::assert_send::<super::Window>();
     crate::test_utils::assert_sync::<super::Window>();
   }
+
+  #[cfg(desktop)]
+  #[test]
+  fn runtime_mutable_fields_have_setters() {
+    use crate::{utils::config::WindowConfig, LogicalPosition, LogicalSize};
+
+    let app = crate::test::mock_app();
+    let window = super::WindowBuilder::new(&app, "main").build().unwrap();
+
+    for field in WindowConfig::runtime_mutable_fields() {
+      match *field {
+        "center" => window.center(),
+        "x" | "y" => window.set_position(LogicalPosition::new(0., 0.)),
+        "width" | "height" => window.set_size(LogicalSize::new(800., 600.)),
+        "min_width" | "min_height" => window.set_min_size(Some(LogicalSize::new(100., 100.))),
+        "max_width" | "max_height" => window.set_max_size(Some(LogicalSize::new(1000., 1000.))),
+        "resizable" => window.set_resizable(true),
+        "maximizable" => window.set_maximizable(true),
+        "minimizable" => window.set_minimizable(true),
+        "closable" => window.set_closable(true),
+        "title" => window.set_title("title"),
+        "fullscreen" => window.set_fullscreen(false),
+        "focus" => window.set_focus(),
+        "maximized" => window.maximize(),
+        "visible" => window.show(),
+        "decorations" => window.set_decorations(true),
+        "always_on_bottom" => window.set_always_on_bottom(false),
+        "always_on_top" => window.set_always_on_top(false),
+        "visible_on_all_workspaces" => window.set_visible_on_all_workspaces(false),
+        "content_protected" => window.set_content_protected(true),
+        "skip_taskbar" => window.set_skip_taskbar(false),
+        "shadow" => window.set_shadow(true),
+        "window_effects" => window.set_effects(None::<crate::utils::config::WindowEffectsConfig>),
+        field => panic!("`{field}` is listed as runtime mutable but has no window setter"),
+      }
+      .unwrap();
+    }
+  }
+
+  #[cfg(desktop)]
+  #[test]
+  fn fullscreen_takes_precedence_over_maximized() {
+    use crate::utils::config::WindowConfig;
+
+    let app = crate::test::mock_app();
+
+    let window = super::WindowBuilder::new(&app, "builder")
+      .maximized(true)
+      .fullscreen(true)
+      .build()
+      .unwrap();
+    assert!(window.is_fullscreen().unwrap());
+    assert!(!window.is_maximized().unwrap());
+
+    let window = super::WindowBuilder::new(&app, "builder-reversed")
+      .fullscreen(true)
+      .maximized(true)
+      .build()
+      .unwrap();
+    assert!(window.is_fullscreen().unwrap());
+    assert!(!window.is_maximized().unwrap());
+
+    let window = super::WindowBuilder::from_config(
+      &app,
+      WindowConfig {
+        label: "config".into(),
+        fullscreen: true,
+        maximized: true,
+        ..Default::default()
+      },
+    )
+    .build()
+    .unwrap();
+    assert!(window.is_fullscreen().unwrap());
+    assert!(!window.is_maximized().unwrap());
+
+    let window = super::WindowBuilder::new(&app, "maximized")
+      .maximized(true)
+      .build()
+      .unwrap();
+    assert!(!window.is_fullscreen().unwrap());
+    assert!(window.is_maximized().unwrap());
+  }
+
+  #[cfg(desktop)]
+  #[test]
+  fn size_constraints_apply_to_inner_size() {
+    use crate::{utils::config::WindowConfig, LogicalSize};
+
+    let app = crate::test::mock_app();
+
+    let window = super::WindowBuilder::from_config(
+      &app,
+      WindowConfig {
+        label: "config".into(),
+        min_width: Some(400.),
+        min_height: Some(300.),
+        max_width: Some(1200.),
+        ..Default::default()
+      },
+    )
+    .build()
+    .unwrap();
+    assert_eq!(
+      window.window.dispatcher.inner_size_constraints(),
+      (Some((400., 300.)), Some((1200., f64::MAX)))
+    );
+
+    window
+      .set_min_size(Some(LogicalSize::new(500., 350.)))
+      .unwrap();
+    window.set_max_size(None::<LogicalSize<f64>>).unwrap();
+    assert_eq!(
+      window.window.dispatcher.inner_size_constraints(),
+      (Some((500., 350.)), None)
+    );
+
+    let window = super::WindowBuilder::new(&app, "builder")
+      .min_inner_size(200., 100.)
+      .build()
+      .unwrap();
+    assert_eq!(
+      window.window.dispatcher.inner_size_constraints(),
+      (Some((200., 100.)), None)
+    );
+  }
+
+  #[cfg(target_os = "macos")]
+  #[test]
+  fn auto_tabbing_requires_tabbing_identifier() {
+    use crate::utils::config::WindowConfig;
+
+    let app = crate::test::mock_app();
+    let tabbing_mode = |tabbing_identifier: Option<&str>, auto_tabbing: Option<bool>| {
+      super::WindowBuilder::from_config(
+        &app,
+        WindowConfig {
+          tabbing_identifier: tabbing_identifier.map(Into::into),
+          auto_tabbing,
+          ..Default::default()
+        },
+      )
+      .window_builder
+      .tabbing_mode()
+    };
+
+    assert_eq!(tabbing_mode(Some("documents"), Some(true)), Some(true));
+    assert_eq!(tabbing_mode(Some("documents"), Some(false)), Some(false));
+    assert_eq!(tabbing_mode(Some("documents"), None), None);
+    assert_eq!(tabbing_mode(None, Some(true)), None);
+
+    let builder = super::WindowBuilder::new(&app, "builder")
+      .tabbing_identifier("documents")
+      .auto_tabbing(true);
+    assert_eq!(builder.window_builder.tabbing_mode(), Some(true));
+  }
 }
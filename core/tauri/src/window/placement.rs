@@ -0,0 +1,195 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! Placement of the windows on the monitor selected with [`WindowConfig::monitor`].
+
+use tauri_utils::debug_eprintln;
+
+use crate::{
+  utils::config::{MonitorSelector, WindowConfig},
+  AppHandle, Monitor, PhysicalPosition, Runtime,
+};
+
+fn contains(monitor: &Monitor, point: PhysicalPosition<f64>) -> bool {
+  let left = monitor.position.x as f64;
+  let top = monitor.position.y as f64;
+  (left..left + monitor.size.width as f64).contains(&point.x)
+    && (top..top + monitor.size.height as f64).contains(&point.y)
+}
+
+/// The monitor matching the selector, falling back to the primary monitor when it is not connected.
+fn select<R: Runtime>(
+  app: &AppHandle<R>,
+  label: &str,
+  selector: &MonitorSelector,
+) -> Option<Monitor> {
+  let monitors = app.available_monitors().unwrap_or_default();
+  let monitor = match selector {
+    MonitorSelector::Index(index) => monitors.get(*index as usize).cloned(),
+    MonitorSelector::Name(name) => monitors
+      .iter()
+      .find(|monitor| monitor.name.as_ref() == Some(name))
+      .cloned(),
+    MonitorSelector::Primary => None,
+    MonitorSelector::Cursor => app.cursor_position().ok().and_then(|cursor| {
+      monitors
+        .iter()
+        .find(|monitor| contains(monitor, cursor))
+        .cloned()
+    }),
+  };
+  if monitor.is_none() && *selector != MonitorSelector::Primary {
+    debug_eprintln!("{selector} of window `{label}` not found, opening it on the primary monitor");
+  }
+  monitor.or_else(|| app.primary_monitor().ok().flatten())
+}
+
+/// Moves the position of the window configuration to its monitor.
+///
+/// The `x` and `y` position of the configuration is relative to the monitor and in its logical pixels,
+/// and a centered window is centered on the monitor.
+///
+/// Returns the physical position of the window, since the runtime may convert the logical position
+/// with the scale factor of another monitor.
+fn place(config: &mut WindowConfig, monitor: &Monitor) -> PhysicalPosition<i32> {
+  let scale_factor = monitor.scale_factor;
+  let (x, y) = if config.center {
+    (
+      (monitor.size.width as f64 - config.width * scale_factor) / 2.,
+      (monitor.size.height as f64 - config.height * scale_factor) / 2.,
+    )
+  } else {
+    (
+      config.x.unwrap_or_default() * scale_factor,
+      config.y.unwrap_or_default() * scale_factor,
+    )
+  };
+  let position = PhysicalPosition::new(
+    monitor.position.x + x.round() as i32,
+    monitor.position.y + y.round() as i32,
+  );
+
+  let logical = position.to_logical::<f64>(scale_factor);
+  config.x = Some(logical.x);
+  config.y = Some(logical.y);
+  config.center = false;
+  position
+}
+
+/// Applies [`WindowConfig::monitor`] to the position of the window configuration, if set.
+///
+/// Returns the physical position the window must be moved to once created,
+/// or `None` when no monitor is selected or known.
+pub(crate) fn apply<R: Runtime>(
+  app: &AppHandle<R>,
+  config: &mut WindowConfig,
+) -> Option<PhysicalPosition<i32>> {
+  let selector = config.monitor.as_ref()?;
+  let monitor = select(app, &config.label, selector)?;
+  Some(place(config, &monitor))
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    test::{mock_app, mock_monitor, set_mock_cursor_position, set_mock_monitors},
+    utils::config::{MonitorSelector, WindowConfig},
+    window::WindowBuilder,
+    PhysicalPosition, PhysicalSize,
+  };
+
+  fn open(config: WindowConfig) -> PhysicalPosition<i32> {
+    open_with_scale_factor(config, 1.)
+  }
+
+  fn open_with_scale_factor(config: WindowConfig, scale_factor: f64) -> PhysicalPosition<i32> {
+    let app = mock_app();
+    set_mock_monitors(
+      &app,
+      vec![
+        mock_monitor(
+          Some("primary"),
+          PhysicalPosition::new(0, 0),
+          PhysicalSize::new(1920, 1080),
+          1.,
+        ),
+        mock_monitor(
+          Some("secondary"),
+          PhysicalPosition::new(1920, 0),
+          PhysicalSize::new(2560, 1440),
+          scale_factor,
+        ),
+      ],
+    );
+    set_mock_cursor_position(&app, PhysicalPosition::new(2000., 100.));
+    WindowBuilder::from_config(&app, config)
+      .build()
+      .unwrap()
+      .outer_position()
+      .unwrap()
+  }
+
+  #[test]
+  fn places_window_on_monitor() {
+    let centered = WindowConfig {
+      monitor: Some(MonitorSelector::Index(1)),
+      center: true,
+      width: 800.,
+      height: 600.,
+      ..Default::default()
+    };
+    assert_eq!(open(centered), PhysicalPosition::new(2800, 420));
+
+    let positioned = WindowConfig {
+      monitor: Some(MonitorSelector::Name("secondary".into())),
+      x: Some(10.),
+      y: Some(20.),
+      ..Default::default()
+    };
+    assert_eq!(open(positioned), PhysicalPosition::new(1930, 20));
+
+    let under_cursor = WindowConfig {
+      monitor: Some(MonitorSelector::Cursor),
+      ..Default::default()
+    };
+    assert_eq!(open(under_cursor), PhysicalPosition::new(1920, 0));
+  }
+
+  #[test]
+  fn falls_back_to_primary_monitor() {
+    let config = WindowConfig {
+      monitor: Some(MonitorSelector::Index(2)),
+      x: Some(10.),
+      y: Some(20.),
+      ..Default::default()
+    };
+    assert_eq!(open(config), PhysicalPosition::new(10, 20));
+  }
+
+  #[test]
+  fn converts_position_with_monitor_scale_factor() {
+    let centered = WindowConfig {
+      monitor: Some(MonitorSelector::Index(1)),
+      center: true,
+      width: 800.,
+      height: 600.,
+      ..Default::default()
+    };
+    assert_eq!(
+      open_with_scale_factor(centered, 2.),
+      PhysicalPosition::new(2400, 120)
+    );
+
+    let positioned = WindowConfig {
+      monitor: Some(MonitorSelector::Name("secondary".into())),
+      x: Some(10.),
+      y: Some(20.),
+      ..Default::default()
+    };
+    assert_eq!(
+      open_with_scale_factor(positioned, 2.),
+      PhysicalPosition::new(1940, 40)
+    );
+  }
+}
@@ -247,7 +247,16 @@ mod desktop_commands {
     }
   }
 
-  fn hit_test(window_size: PhysicalSize<u32>, x: i32, y: i32, scale: f64) -> HitTestResult {
+  /// Hit tests the resize region of an undecorated window.
+  ///
+  /// The `inset` defaults to 5 logical pixels, it is the margin of the window when using client-side decorations.
+  fn hit_test(
+    window_size: PhysicalSize<u32>,
+    x: i32,
+    y: i32,
+    scale: f64,
+    inset: Option<f64>,
+  ) -> HitTestResult {
     const BORDERLESS_RESIZE_INSET: f64 = 5.0;
 
     const CLIENT: isize = 0b0000;
@@ -265,7 +274,7 @@ mod desktop_commands {
     let bottom = top + window_size.height as i32;
     let right = left + window_size.width as i32;
 
-    let inset = (BORDERLESS_RESIZE_INSET * scale) as i32;
+    let inset = (inset.unwrap_or(BORDERLESS_RESIZE_INSET) * scale) as i32;
 
     #[rustfmt::skip]
         let result =
@@ -293,8 +302,9 @@ mod desktop_commands {
     window: Window<R>,
     x: i32,
     y: i32,
+    inset: Option<f64>,
   ) -> crate::Result<()> {
-    hit_test(window.inner_size()?, x, y, window.scale_factor()?).change_cursor(&window);
+    hit_test(window.inner_size()?, x, y, window.scale_factor()?, inset).change_cursor(&window);
     Ok(())
   }
 
@@ -303,8 +313,9 @@ mod desktop_commands {
     window: Window<R>,
     x: i32,
     y: i32,
+    inset: Option<f64>,
   ) -> crate::Result<()> {
-    let res = hit_test(window.inner_size()?, x, y, window.scale_factor()?);
+    let res = hit_test(window.inner_size()?, x, y, window.scale_factor()?, inset);
     match res {
       HitTestResult::Client | HitTestResult::NoWhere => {}
       _ => res.drag_resize_window(&window),
@@ -8,13 +8,15 @@
     document.addEventListener('mousemove', (e) => {
       window.__TAURI_INTERNALS__.invoke('plugin:window|internal_on_mousemove', {
         x: e.clientX,
-        y: e.clientY
+        y: e.clientY,
+        inset: window.__TAURI_INTERNALS__.clientSideDecorationsMargin
       })
     })
     document.addEventListener('mousedown', (e) => {
       window.__TAURI_INTERNALS__.invoke('plugin:window|internal_on_mousedown', {
         x: e.clientX,
-        y: e.clientY
+        y: e.clientY,
+        inset: window.__TAURI_INTERNALS__.clientSideDecorationsMargin
       })
     })
   }
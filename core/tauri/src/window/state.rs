@@ -0,0 +1,330 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! Persistence of the window state for the windows using [`WindowConfig::remember_state`].
+//!
+//! In development, the state of all windows is also kept across the app restarts triggered by `tauri dev`.
+
+use std::{
+  collections::HashMap,
+  path::{Path, PathBuf},
+  sync::mpsc::channel,
+  time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+  utils::config::WindowConfig, AppHandle, Manager, Monitor, PhysicalPosition, PhysicalSize,
+  Runtime, WebviewWindow, WindowEvent,
+};
+
+/// The file storing the state of all windows, in the app config directory.
+const STATE_FILE_NAME: &str = ".window-state.json";
+
+/// How long a window must stop moving and resizing before its state is saved.
+const SAVE_DELAY: Duration = Duration::from_millis(500);
+
+/// The saved state of a window, in physical pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct WindowState {
+  x: i32,
+  y: i32,
+  width: u32,
+  height: u32,
+  maximized: bool,
+}
+
+impl WindowState {
+  /// The monitor containing the center of the window.
+  fn monitor<'a>(&self, monitors: &'a [Monitor]) -> Option<&'a Monitor> {
+    let x = self.x as i64 + self.width as i64 / 2;
+    let y = self.y as i64 + self.height as i64 / 2;
+    monitors.iter().find(|monitor| {
+      let left = monitor.position.x as i64;
+      let top = monitor.position.y as i64;
+      (left..left + monitor.size.width as i64).contains(&x)
+        && (top..top + monitor.size.height as i64).contains(&y)
+    })
+  }
+
+  /// Applies this state to the window configuration.
+  ///
+  /// The position is not restored when the window is centered, and the window is centered
+  /// when the saved position is off-screen, e.g. because its monitor was disconnected.
+  /// A restored position is absolute, so it overrides [`WindowConfig::monitor`].
+  ///
+  /// The configuration only gets an approximate geometry, since the runtime converts its logical pixels
+  /// with the scale factor of another monitor on mixed-DPI setups. The returned [`RestoredState`]
+  /// must be applied once the window is created.
+  fn apply(&self, config: &mut WindowConfig, monitors: &[Monitor]) -> RestoredState {
+    let monitor = self.monitor(monitors);
+    let scale_factor = monitor.map(|m| m.scale_factor).unwrap_or(1.);
+
+    config.width = self.width as f64 / scale_factor;
+    config.height = self.height as f64 / scale_factor;
+    // the window is maximized once it is on its monitor
+    config.maximized = false;
+
+    let mut restored = RestoredState {
+      position: None,
+      size: PhysicalSize::new(self.width, self.height),
+      maximized: self.maximized,
+    };
+
+    if config.center {
+      return restored;
+    }
+
+    // the position cannot be checked when the monitors are unknown
+    if monitor.is_some() || monitors.is_empty() {
+      config.x = Some(self.x as f64 / scale_factor);
+      config.y = Some(self.y as f64 / scale_factor);
+      config.monitor = None;
+      restored.position = Some(PhysicalPosition::new(self.x, self.y));
+    } else {
+      config.x = None;
+      config.y = None;
+      config.center = true;
+    }
+
+    restored
+  }
+}
+
+/// The saved geometry of a window, applied once it is created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RestoredState {
+  position: Option<PhysicalPosition<i32>>,
+  size: PhysicalSize<u32>,
+  maximized: bool,
+}
+
+impl RestoredState {
+  /// Moves and resizes the window to its saved geometry, then maximizes it if it was maximized.
+  pub(crate) fn apply<R: Runtime>(&self, window: &WebviewWindow<R>) -> crate::Result<()> {
+    if let Some(position) = self.position {
+      window.set_position(position)?;
+    }
+    window.set_size(self.size)?;
+    if self.maximized {
+      window.maximize()?;
+    }
+    Ok(())
+  }
+}
+
+/// The environment variable set by `tauri dev` with the path of the file storing the window state across the app restarts.
+#[cfg(dev)]
+const DEV_STATE_PATH_ENV: &str = "TAURI_DEV_WINDOW_STATE_PATH";
+
+fn state_path<R: Runtime>(app: &AppHandle<R>) -> crate::Result<PathBuf> {
+  Ok(app.path().app_config_dir()?.join(STATE_FILE_NAME))
+}
+
+/// The file storing the state of the windows across the app restarts triggered by `tauri dev`,
+/// unless disabled with its `--no-restore-geometry` flag.
+#[cfg(dev)]
+pub(crate) fn dev_state_path() -> Option<PathBuf> {
+  std::env::var_os(DEV_STATE_PATH_ENV).map(PathBuf::from)
+}
+
+fn load(path: &Path) -> HashMap<String, WindowState> {
+  std::fs::read(path)
+    .ok()
+    .and_then(|state| serde_json::from_slice(&state).ok())
+    .unwrap_or_default()
+}
+
+/// Applies the saved state of the window to its configuration, if any.
+///
+/// The returned state must be applied to the window once it is created, see [`RestoredState::apply`].
+pub(crate) fn restore<R: Runtime>(
+  app: &AppHandle<R>,
+  config: &mut WindowConfig,
+) -> Option<RestoredState> {
+  restore_from(app, &state_path(app).ok()?, config)
+}
+
+/// Applies the state of the window saved in the given file to its configuration, if any.
+fn restore_from<R: Runtime>(
+  app: &AppHandle<R>,
+  path: &Path,
+  config: &mut WindowConfig,
+) -> Option<RestoredState> {
+  let state = *load(path).get(&config.label)?;
+  let monitors = app.available_monitors().unwrap_or_default();
+  Some(state.apply(config, &monitors))
+}
+
+/// Applies the state the window had before `tauri dev` restarted the app, if any.
+///
+/// Unlike [`restore`], the saved position also takes precedence over [`WindowConfig::center`].
+#[cfg(dev)]
+pub(crate) fn restore_dev<R: Runtime>(
+  app: &AppHandle<R>,
+  path: &Path,
+  config: &mut WindowConfig,
+) -> Option<RestoredState> {
+  if load(path).contains_key(&config.label) {
+    config.center = false;
+    restore_from(app, path, config)
+  } else {
+    None
+  }
+}
+
+/// Saves the state of the window when it is closed, and once it stops moving or resizing
+/// so the state is not lost if the app crashes or is killed.
+pub(crate) fn remember<R: Runtime>(window: &WebviewWindow<R>) -> crate::Result<()> {
+  watch(window, state_path(window.app_handle())?);
+  Ok(())
+}
+
+/// Saves the state of the window to the given file when it is closed,
+/// and [`SAVE_DELAY`] after it stops moving or resizing.
+pub(crate) fn watch<R: Runtime>(window: &WebviewWindow<R>, path: PathBuf) {
+  let (tx, rx) = channel::<()>();
+
+  let window_ = window.clone();
+  let path_ = path.clone();
+  std::thread::spawn(move || {
+    while rx.recv().is_ok() {
+      // the window moves and resizes continuously while it is dragged
+      while rx.recv_timeout(SAVE_DELAY).is_ok() {}
+      let _ = save_to(&window_, &path_);
+    }
+  });
+
+  let window_ = window.clone();
+  window.on_window_event(move |event| match event {
+    WindowEvent::Moved(_) | WindowEvent::Resized(_) => {
+      let _ = tx.send(());
+    }
+    WindowEvent::CloseRequested { .. } => {
+      let _ = save_to(&window_, &path);
+    }
+    _ => {}
+  });
+}
+
+/// Saves the state of the window to the given file.
+///
+/// The size and position of a maximized window are not saved, so it is restored to its previous size when unmaximized.
+/// Minimized windows are ignored.
+fn save_to<R: Runtime>(window: &WebviewWindow<R>, path: &Path) -> crate::Result<()> {
+  if window.is_minimized()? {
+    return Ok(());
+  }
+
+  let mut states = load(path);
+  let maximized = window.is_maximized()?;
+  let state = match states.get(window.label()) {
+    Some(previous) if maximized => WindowState {
+      maximized,
+      ..*previous
+    },
+    _ => {
+      let position = window.outer_position()?;
+      let size = window.inner_size()?;
+      WindowState {
+        x: position.x,
+        y: position.y,
+        width: size.width,
+        height: size.height,
+        maximized,
+      }
+    }
+  };
+  states.insert(window.label().into(), state);
+
+  if let Some(parent) = path.parent() {
+    std::fs::create_dir_all(parent)?;
+  }
+  std::fs::write(path, serde_json::to_vec(&states)?)?;
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{RestoredState, WindowState};
+  use crate::{
+    utils::config::{MonitorSelector, WindowConfig},
+    Monitor, PhysicalPosition, PhysicalSize,
+  };
+
+  fn monitor(x: i32, scale_factor: f64) -> Monitor {
+    Monitor {
+      name: None,
+      size: PhysicalSize::new(1920, 1080),
+      position: PhysicalPosition::new(x, 0),
+      scale_factor,
+    }
+  }
+
+  const STATE: WindowState = WindowState {
+    x: 2120,
+    y: 100,
+    width: 1600,
+    height: 1200,
+    maximized: false,
+  };
+
+  #[test]
+  fn restores_state() {
+    let mut config = WindowConfig {
+      monitor: Some(MonitorSelector::Primary),
+      ..Default::default()
+    };
+    let restored = STATE.apply(&mut config, &[monitor(0, 1.), monitor(1920, 2.)]);
+    // the saved physical geometry is applied as is, whatever monitor the window is created on
+    assert_eq!(
+      restored,
+      RestoredState {
+        position: Some(PhysicalPosition::new(2120, 100)),
+        size: PhysicalSize::new(1600, 1200),
+        maximized: false,
+      }
+    );
+    assert_eq!((config.width, config.height), (800., 600.));
+    assert!(!config.center);
+    assert_eq!(config.monitor, None);
+  }
+
+  #[test]
+  fn maximizes_after_restoring() {
+    let mut config = WindowConfig::default();
+    let state = WindowState {
+      maximized: true,
+      ..STATE
+    };
+    let restored = state.apply(&mut config, &[monitor(1920, 2.)]);
+    assert!(!config.maximized);
+    assert!(restored.maximized);
+    assert_eq!(restored.position, Some(PhysicalPosition::new(2120, 100)));
+  }
+
+  #[test]
+  fn centers_off_screen_window() {
+    let mut config = WindowConfig::default();
+    let restored = STATE.apply(&mut config, &[monitor(0, 1.)]);
+    assert_eq!((config.x, config.y), (None, None));
+    assert_eq!((config.width, config.height), (1600., 1200.));
+    assert!(config.center);
+    assert_eq!(restored.position, None);
+  }
+
+  #[test]
+  fn center_overrides_position() {
+    let mut config = WindowConfig {
+      center: true,
+      ..Default::default()
+    };
+    let restored = STATE.apply(&mut config, &[monitor(1920, 2.)]);
+    assert_eq!((config.x, config.y), (None, None));
+    assert_eq!((config.width, config.height), (800., 600.));
+    assert_eq!(restored.position, None);
+    assert_eq!(restored.size, PhysicalSize::new(1600, 1200));
+  }
+}
@@ -11,7 +11,13 @@ mod tests {
   };
 
   use tauri_utils::{
-    acl::{build::parse_capabilities, plugin::Manifest, resolved::Resolved},
+    acl::{
+      build::{parse_capabilities, validate_capabilities},
+      capability::Capability,
+      plugin::Manifest,
+      resolved::Resolved,
+      Error,
+    },
     platform::Target,
   };
 
@@ -34,6 +40,35 @@ mod tests {
     manifests
   }
 
+  #[test]
+  fn merge_permission_dirs() {
+    let plugins_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+      .join("fixtures")
+      .join("plugins");
+    let fs_pattern = format!("{}/*.toml", plugins_path.join("fs").display());
+    let ping_pattern = format!("{}/*.toml", plugins_path.join("ping").display());
+
+    let permission_files =
+      tauri_utils::acl::build::define_permissions_from(&[&fs_pattern, &ping_pattern], "merged")
+        .expect("failed to define permissions");
+    let manifest = Manifest::from_files(permission_files);
+    assert!(manifest.permissions.contains_key("allow-ping"));
+    assert!(manifest.permissions.contains_key("allow-read-dir"));
+
+    let error = tauri_utils::acl::build::define_permissions_from(
+      &[
+        &ping_pattern,
+        &format!("{}/*.toml", plugins_path.join("ping-duplicate").display()),
+      ],
+      "duplicated",
+    )
+    .unwrap_err();
+    assert!(matches!(
+      error,
+      tauri_utils::acl::Error::DuplicatePermission { .. }
+    ));
+  }
+
   #[test]
   fn resolve_acl() {
     let mut settings = insta::Settings::clone_current();
@@ -67,4 +102,73 @@ mod tests {
       );
     }
   }
+
+  #[test]
+  fn platform_specific_permission() {
+    let permission_file = serde_json::from_value(serde_json::json!({
+      "permission": [{
+        "identifier": "allow-intent",
+        "commands": { "allow": ["intent"] },
+        "platforms": ["android"]
+      }]
+    }))
+    .unwrap();
+    let manifests = BTreeMap::from([(
+      "intent".to_string(),
+      Manifest::from_files(vec![permission_file]),
+    )]);
+
+    let capabilities = |platform: &str| -> BTreeMap<String, Capability> {
+      let capability: Capability = serde_json::from_value(serde_json::json!({
+        "identifier": "main",
+        "windows": ["main"],
+        "permissions": ["intent:allow-intent"],
+        "platforms": [platform]
+      }))
+      .unwrap();
+      BTreeMap::from([("main".to_string(), capability)])
+    };
+
+    assert!(validate_capabilities(&manifests, &capabilities("android"), Target::Android).is_ok());
+
+    let error =
+      validate_capabilities(&manifests, &capabilities("windows"), Target::Windows).unwrap_err();
+    assert_eq!(
+      error.to_string(),
+      "Permission intent:allow-intent referenced by capability main is not available on windows, it only applies to android"
+    );
+  }
+
+  #[test]
+  fn reports_all_missing_permissions() {
+    let manifests = load_plugins(&["ping".to_string()]);
+    let capability = |identifier: &str, permission: &str| -> (String, Capability) {
+      let capability = serde_json::from_value(serde_json::json!({
+        "identifier": identifier,
+        "windows": ["main"],
+        "permissions": ["ping:allow-ping", permission]
+      }))
+      .unwrap();
+      (identifier.to_string(), capability)
+    };
+    let capabilities = BTreeMap::from([
+      capability("main", "ping:allow-pong"),
+      capability("settings", "fs:allow-read"),
+    ]);
+
+    let error = validate_capabilities(&manifests, &capabilities, Target::Linux).unwrap_err();
+    let Error::InvalidCapabilities(errors) = &error else {
+      panic!("unexpected error {error}");
+    };
+    assert_eq!(errors.len(), 2);
+
+    let message = error.to_string();
+    assert!(message.starts_with("found 2 capability errors:"));
+    assert!(message.contains(
+      "Permission ping:allow-pong referenced by capability main not found, expected one of ping:"
+    ));
+    assert!(
+      message.contains("Permission fs:allow-read referenced by capability settings not found")
+    );
+  }
 }
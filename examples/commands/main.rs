@@ -90,6 +90,24 @@ fn force_async_with_result(the_argument: &str) -> Result<&str, MyError> {
     .ok_or(MyError::FooError)
 }
 
+// ------------------------ Execution context ------------------------
+
+#[command(threaded)]
+fn threaded_command(the_argument: String) -> String {
+  // blocking IO here does not stall the IPC thread
+  the_argument
+}
+
+#[command(on_main_thread)]
+fn main_thread_command(window: Window) -> String {
+  window.label().into()
+}
+
+#[command(on_main_thread)]
+async fn async_main_thread_command(the_argument: String) -> Result<String, MyError> {
+  Ok(the_argument)
+}
+
 // ------------------------ Raw future commands - snake_case ------------------------
 
 #[command(async, rename_all = "snake_case")]
@@ -236,6 +254,9 @@ fn main() {
       window_label,
       force_async,
       force_async_with_result,
+      threaded_command,
+      main_thread_command,
+      async_main_thread_command,
       commands::simple_command,
       commands::stateful_command,
       cmd,
@@ -665,6 +665,7 @@ interface WebviewOptions {
   acceptFirstMouse?: boolean
   /**
    * The user agent for the webview.
+   * Supports the `{{app_name}}`, `{{app_version}}`, `{{os}}` and `{{os_version}}` placeholders.
    */
   userAgent?: string
   /**
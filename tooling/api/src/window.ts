@@ -1986,9 +1986,11 @@ interface WindowOptions {
   /**
    * The initial window theme. Defaults to the system theme.
    *
+   * `light` and `dark` pin the window theme, while `system` follows the system theme.
+   *
    * Only implemented on Windows and macOS 10.14+.
    */
-  theme?: Theme
+  theme?: Theme | 'system'
   /**
    * The style of the macOS title bar.
    */
@@ -2004,6 +2006,13 @@ interface WindowOptions {
    * If the tabbing identifier is not set, automatic tabbing will be disabled.
    */
   tabbingIdentifier?: string
+  /**
+   * Whether the window always opens as a tab (`true`) or never does (`false`) on macOS,
+   * instead of following the system setting.
+   *
+   * Only applies to windows with a {@link WindowOptions.tabbingIdentifier}.
+   */
+  autoTabbing?: boolean
   /**
    * Whether the window's native maximize button is enabled or not. Defaults to `true`.
    */
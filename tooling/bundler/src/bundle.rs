@@ -72,17 +72,20 @@ pub fn bundle_project(settings: Settings) -> crate::Result<Vec<Bundle>> {
     }
 
     // Sign the sidecar binaries
-    for bin in settings.external_binaries() {
-      let path = bin?;
-      let skip = std::env::var("TAURI_SKIP_SIDECAR_SIGNATURE_CHECK").map_or(false, |v| v == "true");
-
-      if !skip && windows::sign::verify(&path)? {
-        info!(
-          "sidecar at \"{}\" already signed. Skipping...",
-          path.display()
-        )
-      } else {
-        windows::sign::try_sign(&path, &settings)?;
+    if settings.windows().sign_external_binaries {
+      for bin in settings.external_binaries() {
+        let path = bin?;
+        let skip =
+          std::env::var("TAURI_SKIP_SIDECAR_SIGNATURE_CHECK").map_or(false, |v| v == "true");
+
+        if !skip && windows::sign::verify(&path)? {
+          info!(
+            "sidecar at \"{}\" already signed. Skipping...",
+            path.display()
+          )
+        } else {
+          windows::sign::try_sign(&path, &settings)?;
+        }
       }
     }
   }
@@ -33,6 +33,7 @@ use anyhow::Context;
 use log::{info, warn};
 
 use std::{
+  collections::HashMap,
   ffi::OsStr,
   fs,
   path::{Path, PathBuf},
@@ -51,6 +52,16 @@ pub fn bundle_project(settings: &Settings) -> crate::Result<Vec<PathBuf>> {
     .join("bundle/macos")
     .join(&app_product_name);
 
+  // resolve the configured notarization credentials before bundling,
+  // so an incomplete configuration fails the build instead of skipping notarization at the end
+  let configured_notarize_auth = match &settings.macos().notarization {
+    Some(config) if settings.macos().signing_identity.is_some() => Some(
+      notarize_auth(Some(config))
+        .map_err(|e| anyhow::anyhow!("failed to resolve the notarization credentials: {e}"))?,
+    ),
+    _ => None,
+  };
+
   info!(action = "Bundling"; "{} ({})", app_product_name, app_bundle_path.display());
 
   if app_bundle_path.exists() {
@@ -95,10 +106,10 @@ pub fn bundle_project(settings: &Settings) -> crate::Result<Vec<PathBuf>> {
   let bin_paths = settings
     .copy_binaries(&bin_dir)
     .with_context(|| "Failed to copy external binaries")?;
-  sign_paths.extend(bin_paths.into_iter().map(|path| SignTarget {
-    path,
-    is_an_executable: true,
-  }));
+  sign_paths.extend(external_binaries_sign_targets(
+    bin_paths,
+    settings.macos().sign_external_binaries,
+  ));
 
   let bin_paths = copy_binaries_to_bundle(&bundle_directory, settings)?;
   sign_paths.extend(bin_paths.into_iter().map(|path| SignTarget {
@@ -109,6 +120,12 @@ pub fn bundle_project(settings: &Settings) -> crate::Result<Vec<PathBuf>> {
   copy_custom_files_to_bundle(&bundle_directory, settings)?;
 
   if let Some(identity) = &settings.macos().signing_identity {
+    let mut sign_paths = filter_excluded_sign_targets(
+      sign_paths,
+      &bundle_directory,
+      &settings.macos().exclude_from_signing,
+    )?;
+
     // Sign frameworks and sidecar binaries first, per apple, signing must be done inside out
     // https://developer.apple.com/forums/thread/701514
     sign_paths.push(SignTarget {
@@ -124,7 +141,11 @@ pub fn bundle_project(settings: &Settings) -> crate::Result<Vec<PathBuf>> {
     sign(sign_paths, identity, settings)?;
 
     // notarization is required for distribution
-    match notarize_auth() {
+    let auth = match configured_notarize_auth {
+      Some(auth) => Ok(auth),
+      None => notarize_auth(None),
+    };
+    match auth {
       Ok(auth) => {
         notarize(app_bundle_path.clone(), auth, settings)?;
       }
@@ -141,6 +162,70 @@ pub fn bundle_project(settings: &Settings) -> crate::Result<Vec<PathBuf>> {
   Ok(vec![app_bundle_path])
 }
 
+/// The sign targets of the external binaries copied to the bundle, if they must be signed.
+fn external_binaries_sign_targets(
+  bin_paths: Vec<PathBuf>,
+  sign_external_binaries: bool,
+) -> Vec<SignTarget> {
+  if !sign_external_binaries {
+    return Vec::new();
+  }
+
+  bin_paths
+    .into_iter()
+    .map(|path| SignTarget {
+      path,
+      is_an_executable: true,
+    })
+    .collect()
+}
+
+/// Removes the sign targets matching one of the `exclude_from_signing` glob patterns,
+/// which are relative to the bundle's Contents directory.
+///
+/// A target is also excluded when one of its parent directories matches, e.g. a binary inside an excluded `.app`.
+fn filter_excluded_sign_targets(
+  targets: Vec<SignTarget>,
+  bundle_directory: &Path,
+  exclude_from_signing: &[String],
+) -> crate::Result<Vec<SignTarget>> {
+  if exclude_from_signing.is_empty() {
+    return Ok(targets);
+  }
+
+  let patterns = exclude_from_signing
+    .iter()
+    .map(|p| glob::Pattern::new(p))
+    .collect::<Result<Vec<_>, _>>()?;
+  let options = glob::MatchOptions {
+    require_literal_separator: true,
+    ..Default::default()
+  };
+
+  Ok(
+    targets
+      .into_iter()
+      .filter(|target| {
+        let Ok(relative_path) = target.path.strip_prefix(bundle_directory) else {
+          return true;
+        };
+        let excluded = relative_path
+          .ancestors()
+          .filter(|p| !p.as_os_str().is_empty())
+          .any(|p| {
+            patterns
+              .iter()
+              .any(|pattern| pattern.matches_path_with(p, options))
+          });
+        if excluded {
+          info!("skipping code signing of {}", target.path.display());
+        }
+        !excluded
+      })
+      .collect(),
+  )
+}
+
 fn remove_extra_attr(app_bundle_path: &Path) -> crate::Result<()> {
   Command::new("xattr")
     .arg("-cr")
@@ -295,6 +380,8 @@ fn create_info_plist(
     plist.insert("NSAppTransportSecurity".into(), security.into());
   }
 
+  merge_info_plist_config(&mut plist, &settings.macos().info_plist);
+
   if let Some(user_plist_path) = &settings.macos().info_plist_path {
     let user_plist = plist::Value::from_file(user_plist_path)?;
     if let Some(dict) = user_plist.into_dictionary() {
@@ -309,6 +396,46 @@ fn create_info_plist(
   Ok(())
 }
 
+// Adds the Info.plist keys from the configuration that are not already set by the bundler.
+fn merge_info_plist_config(
+  plist: &mut plist::Dictionary,
+  info_plist: &HashMap<String, serde_json::Value>,
+) {
+  for (key, value) in info_plist {
+    if plist.contains_key(key) {
+      warn!(
+        "Ignoring the `{key}` Info.plist key from the configuration since it is managed by Tauri"
+      );
+    } else if let Some(value) = json_to_plist(value) {
+      plist.insert(key.clone(), value);
+    }
+  }
+}
+
+// Converts a JSON value to its plist equivalent. Returns `None` for `null`, which cannot be represented.
+fn json_to_plist(value: &serde_json::Value) -> Option<plist::Value> {
+  use serde_json::Value;
+  match value {
+    Value::Null => None,
+    Value::Bool(b) => Some((*b).into()),
+    Value::Number(n) => n
+      .as_i64()
+      .map(Into::into)
+      .or_else(|| n.as_u64().map(Into::into))
+      .or_else(|| n.as_f64().map(Into::into)),
+    Value::String(s) => Some(s.clone().into()),
+    Value::Array(values) => Some(plist::Value::Array(
+      values.iter().filter_map(json_to_plist).collect(),
+    )),
+    Value::Object(values) => Some(plist::Value::Dictionary(
+      values
+        .iter()
+        .filter_map(|(key, value)| json_to_plist(value).map(|value| (key.clone(), value)))
+        .collect(),
+    )),
+  }
+}
+
 // Copies the framework under `{src_dir}/{framework}.framework` to `{dest_dir}/{framework}.framework`.
 fn copy_framework_from(dest_dir: &Path, framework: &str, src_dir: &Path) -> crate::Result<bool> {
   let src_name = format!("{}.framework", framework);
@@ -390,3 +517,105 @@ fn copy_frameworks_to_bundle(
   }
   Ok(paths)
 }
+
+#[cfg(test)]
+mod tests {
+  use std::{collections::HashMap, path::PathBuf};
+
+  use super::SignTarget;
+
+  use serde_json::json;
+
+  #[test]
+  fn merge_info_plist_config() {
+    let mut plist = plist::Dictionary::new();
+    plist.insert("CFBundleName".into(), "Tauri App".into());
+
+    let info_plist = HashMap::from([
+      (
+        "NSCameraUsageDescription".to_string(),
+        json!("Used to take your profile picture"),
+      ),
+      ("CFBundleName".to_string(), json!("Overwritten")),
+      (
+        "CFBundleURLTypes".to_string(),
+        json!([{ "CFBundleURLSchemes": ["myapp"], "LSIsAppleDefaultForScheme": true }]),
+      ),
+    ]);
+    super::merge_info_plist_config(&mut plist, &info_plist);
+
+    assert_eq!(
+      plist.get("NSCameraUsageDescription"),
+      Some(&plist::Value::from("Used to take your profile picture"))
+    );
+    assert_eq!(
+      plist.get("CFBundleName"),
+      Some(&plist::Value::from("Tauri App"))
+    );
+    let url_types = plist
+      .get("CFBundleURLTypes")
+      .and_then(|v| v.as_array())
+      .unwrap();
+    let url_type = url_types[0].as_dictionary().unwrap();
+    assert_eq!(
+      url_type.get("LSIsAppleDefaultForScheme"),
+      Some(&plist::Value::Boolean(true))
+    );
+  }
+
+  #[test]
+  fn external_binaries_sign_targets() {
+    let bin_paths = vec![PathBuf::from(
+      "/out/bundle/macos/App.app/Contents/MacOS/sidecar",
+    )];
+
+    let targets = super::external_binaries_sign_targets(bin_paths.clone(), true);
+    assert_eq!(
+      targets
+        .iter()
+        .map(|target| (&target.path, target.is_an_executable))
+        .collect::<Vec<_>>(),
+      vec![(&bin_paths[0], true)]
+    );
+
+    assert!(super::external_binaries_sign_targets(bin_paths, false).is_empty());
+  }
+
+  #[test]
+  fn filter_excluded_sign_targets() {
+    let contents = PathBuf::from("/out/bundle/macos/App.app/Contents");
+    let targets = [
+      "Frameworks/Prebuilt.framework",
+      "Frameworks/Other.framework",
+      "Resources/helpers/Helper.app/Contents/MacOS/helper",
+      "MacOS/sidecar",
+    ]
+    .into_iter()
+    .map(|path| SignTarget {
+      path: contents.join(path),
+      is_an_executable: true,
+    })
+    .collect();
+
+    let signed = super::filter_excluded_sign_targets(
+      targets,
+      &contents,
+      &[
+        "Frameworks/Prebuilt.framework".into(),
+        "Resources/helpers/*.app".into(),
+      ],
+    )
+    .unwrap()
+    .into_iter()
+    .map(|target| target.path)
+    .collect::<Vec<_>>();
+
+    assert_eq!(
+      signed,
+      vec![
+        contents.join("Frameworks/Other.framework"),
+        contents.join("MacOS/sidecar")
+      ]
+    );
+  }
+}
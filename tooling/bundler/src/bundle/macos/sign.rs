@@ -16,6 +16,7 @@ use crate::{bundle::common::CommandExt, Settings};
 use anyhow::Context;
 use log::info;
 use serde::Deserialize;
+use tauri_utils::config::NotarizationConfig;
 
 const KEYCHAIN_ID: &str = "tauri-build.keychain";
 const KEYCHAIN_PWD: &str = "tauri-build";
@@ -356,6 +357,10 @@ pub enum NotarizeAuth {
     key_path: PathBuf,
     issuer: OsString,
   },
+  KeychainProfile {
+    profile: OsString,
+    keychain: Option<PathBuf>,
+  },
 }
 
 pub trait NotarytoolCmdExt {
@@ -387,6 +392,13 @@ impl NotarytoolCmdExt for Command {
         .arg(key_path)
         .arg("--issuer")
         .arg(issuer),
+      NotarizeAuth::KeychainProfile { profile, keychain } => {
+        self.arg("--keychain-profile").arg(profile);
+        if let Some(keychain) = keychain {
+          self.arg("--keychain").arg(keychain);
+        }
+        self
+      }
     }
   }
 }
@@ -401,7 +413,52 @@ pub enum NotarizeAuthError {
   Anyhow(#[from] anyhow::Error),
 }
 
-pub fn notarize_auth() -> Result<NotarizeAuth, NotarizeAuthError> {
+/// Resolves the notarization credentials from the configuration,
+/// falling back to the environment variables when it is not set.
+pub fn notarize_auth(
+  config: Option<&NotarizationConfig>,
+) -> Result<NotarizeAuth, NotarizeAuthError> {
+  match config {
+    Some(NotarizationConfig::KeychainProfile { profile, keychain }) => {
+      Ok(NotarizeAuth::KeychainProfile {
+        profile: profile.into(),
+        keychain: keychain.clone(),
+      })
+    }
+    Some(NotarizationConfig::AppleId {
+      apple_id_env,
+      password_env,
+      team_id_env,
+    }) => match (
+      var_os(apple_id_env),
+      var_os(password_env),
+      var_os(team_id_env),
+    ) {
+      (Some(apple_id), Some(password), Some(team_id)) => Ok(NotarizeAuth::AppleId {
+        apple_id,
+        password,
+        team_id,
+      }),
+      _ => {
+        let missing = [apple_id_env, password_env, team_id_env]
+          .into_iter()
+          .filter(|name| var_os(name).is_none())
+          .map(|name| format!("`{name}`"))
+          .collect::<Vec<_>>();
+        Err(
+          anyhow::anyhow!(
+            "the {} environment variables configured in `tauri.conf.json > tauri > bundle > macOS > notarization` are not set",
+            missing.join(", ")
+          )
+          .into(),
+        )
+      }
+    },
+    None => notarize_auth_from_env(),
+  }
+}
+
+fn notarize_auth_from_env() -> Result<NotarizeAuth, NotarizeAuthError> {
   match (
     var_os("APPLE_ID"),
     var_os("APPLE_PASSWORD"),
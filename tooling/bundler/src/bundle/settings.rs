@@ -7,7 +7,10 @@ use super::category::AppCategory;
 use crate::bundle::{common, platform::target_triple};
 pub use tauri_utils::config::WebviewInstallMode;
 use tauri_utils::{
-  config::{BundleType, FileAssociation, NSISInstallerMode, NsisCompression},
+  config::{
+    BundleType, FileAssociation, NSISInstallerMode, NotarizationConfig, NsisCompression,
+    RegistryEntry,
+  },
   resources::{external_binaries, ResourcePaths},
 };
 
@@ -164,6 +167,9 @@ pub struct UpdaterSettings {
   pub pubkey: String,
   /// Args to pass to `msiexec.exe` to run the updater on Windows.
   pub msiexec_args: Option<&'static [&'static str]>,
+  /// Whether to create the updater artifacts or not.
+  /// `None` creates them if the updater is active and the `updater` package type is selected.
+  pub create_artifacts: Option<bool>,
 }
 
 /// The Linux debian bundle settings.
@@ -245,7 +251,7 @@ pub struct DmgSettings {
 }
 
 /// The macOS bundle settings.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug)]
 pub struct MacOsSettings {
   /// MacOS frameworks that need to be bundled with the app.
   ///
@@ -279,6 +285,34 @@ pub struct MacOsSettings {
   pub entitlements: Option<String>,
   /// Path to the Info.plist file for the bundle.
   pub info_plist_path: Option<PathBuf>,
+  /// Additional Info.plist keys. Keys set by the bundler take precedence.
+  pub info_plist: HashMap<String, serde_json::Value>,
+  /// Glob patterns, relative to the bundle's Contents directory, of already signed components to skip when code signing.
+  pub exclude_from_signing: Vec<String>,
+  /// Whether to sign the external binaries with the signing identity. Defaults to `true`.
+  pub sign_external_binaries: bool,
+  /// The notarization credentials. Falls back to the environment variables when not set.
+  pub notarization: Option<NotarizationConfig>,
+}
+
+impl Default for MacOsSettings {
+  fn default() -> Self {
+    Self {
+      frameworks: None,
+      files: HashMap::new(),
+      minimum_system_version: None,
+      license: None,
+      exception_domain: None,
+      signing_identity: None,
+      provider_short_name: None,
+      entitlements: None,
+      info_plist_path: None,
+      info_plist: HashMap::new(),
+      exclude_from_signing: Vec::new(),
+      sign_external_binaries: true,
+      notarization: None,
+    }
+  }
 }
 
 /// Configuration for a target language for the WiX build.
@@ -336,6 +370,10 @@ pub struct WixSettings {
   pub dialog_image_path: Option<PathBuf>,
   /// Enables FIPS compliant algorithms.
   pub fips_compliant: bool,
+  /// Whether to show the launch app checkbox on the finish dialog. `None` uses the template default.
+  pub launch_on_finish: Option<bool>,
+  /// Registry values to write on install and remove on uninstall.
+  pub registry_keys: Vec<RegistryEntry>,
 }
 
 /// Settings specific to the NSIS implementation.
@@ -375,6 +413,14 @@ pub struct NsisSettings {
   pub display_language_selector: bool,
   /// Set compression algorithm used to compress files in the installer.
   pub compression: Option<NsisCompression>,
+  /// Whether to show the run app checkbox on the finish page. `None` uses the template default.
+  pub launch_on_finish: Option<bool>,
+  /// Registry values to write on install and remove on uninstall.
+  ///
+  /// `HKLM` values require a per-machine installation.
+  pub registry_keys: Vec<RegistryEntry>,
+  /// The minimum Windows version the installer runs on, in the `major.minor[.build]` format.
+  pub minimum_windows_version: Option<String>,
 }
 
 /// The Windows bundle settings.
@@ -389,6 +435,8 @@ pub struct WindowsSettings {
   /// Whether to use Time-Stamp Protocol (TSP, a.k.a. RFC 3161) for the timestamp server. Your code signing provider may
   /// use a TSP timestamp server, like e.g. SSL.com does. If so, enable TSP by setting to true.
   pub tsp: bool,
+  /// Whether to sign the external binaries with the certificate. Defaults to `true`.
+  pub sign_external_binaries: bool,
   /// WiX configuration.
   pub wix: Option<WixSettings>,
   /// Nsis configuration.
@@ -418,6 +466,7 @@ impl Default for WindowsSettings {
       certificate_thumbprint: None,
       timestamp_url: None,
       tsp: false,
+      sign_external_binaries: true,
       wix: None,
       nsis: None,
       icon_path: PathBuf::from("icons/icon.ico"),
@@ -460,6 +509,8 @@ pub struct BundleSettings {
   pub short_description: Option<String>,
   /// the app's long description.
   pub long_description: Option<String>,
+  /// The license file used by the WiX, NSIS and DMG bundles when their own `license` setting is not set.
+  pub license_file: Option<PathBuf>,
   // Bundles for other binaries:
   /// Configuration map for the apps to bundle.
   pub bin: Option<HashMap<String, BundleSettings>>,
@@ -492,6 +543,41 @@ pub struct BundleSettings {
   pub windows: WindowsSettings,
 }
 
+impl BundleSettings {
+  /// Returns the license file for the WiX installer,
+  /// falling back to [`Self::license_file`] when the WiX `license` is not set.
+  pub fn wix_license(&self) -> Option<&PathBuf> {
+    self
+      .windows
+      .wix
+      .as_ref()
+      .and_then(|wix| wix.license.as_ref())
+      .or(self.license_file.as_ref())
+  }
+
+  /// Returns the license file for the NSIS installer,
+  /// falling back to [`Self::license_file`] when the NSIS `license` is not set.
+  pub fn nsis_license(&self) -> Option<&PathBuf> {
+    self
+      .windows
+      .nsis
+      .as_ref()
+      .and_then(|nsis| nsis.license.as_ref())
+      .or(self.license_file.as_ref())
+  }
+
+  /// Returns the license file for the DMG bundle,
+  /// falling back to [`Self::license_file`] when the macOS `license` is not set.
+  pub fn dmg_license(&self) -> Option<PathBuf> {
+    self
+      .macos
+      .license
+      .as_ref()
+      .map(PathBuf::from)
+      .or_else(|| self.license_file.clone())
+  }
+}
+
 /// A binary to bundle.
 #[derive(Clone, Debug)]
 pub struct BundleBinary {
@@ -757,7 +843,7 @@ impl Settings {
     };
 
     // add updater if needed
-    if self.is_update_enabled() {
+    if self.create_updater_artifacts() {
       platform_types.push(PackageType::Updater)
     }
 
@@ -773,6 +859,13 @@ impl Settings {
           types.push(package_type);
         }
       }
+      // the updater artifacts were explicitly requested, so the target does not need to be listed
+      if self.updater().and_then(|updater| updater.create_artifacts) == Some(true)
+        && !types.is_empty()
+        && !types.contains(&PackageType::Updater)
+      {
+        types.push(PackageType::Updater);
+      }
       Ok(types)
     } else {
       Ok(platform_types)
@@ -943,6 +1036,21 @@ impl Settings {
     &self.bundle_settings.windows
   }
 
+  /// Returns the license file for the WiX installer. See [`BundleSettings::wix_license`].
+  pub fn wix_license(&self) -> Option<&PathBuf> {
+    self.bundle_settings.wix_license()
+  }
+
+  /// Returns the license file for the NSIS installer. See [`BundleSettings::nsis_license`].
+  pub fn nsis_license(&self) -> Option<&PathBuf> {
+    self.bundle_settings.nsis_license()
+  }
+
+  /// Returns the license file for the DMG bundle. See [`BundleSettings::dmg_license`].
+  pub fn dmg_license(&self) -> Option<PathBuf> {
+    self.bundle_settings.dmg_license()
+  }
+
   /// Returns the Updater settings.
   pub fn updater(&self) -> Option<&UpdaterSettings> {
     self.bundle_settings.updater.as_ref()
@@ -955,4 +1063,48 @@ impl Settings {
       None => false,
     }
   }
+
+  /// Whether the updater artifacts should be created.
+  /// Defaults to [`Self::is_update_enabled`] unless [`UpdaterSettings::create_artifacts`] is set.
+  pub fn create_updater_artifacts(&self) -> bool {
+    match &self.bundle_settings.updater {
+      Some(val) => val.create_artifacts.unwrap_or(val.active),
+      None => false,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn license_file_fallback() {
+    let license_file = PathBuf::from("LICENSE");
+    let mut settings = BundleSettings {
+      license_file: Some(license_file.clone()),
+      ..Default::default()
+    };
+    settings.windows.wix = Some(Default::default());
+    settings.windows.nsis = Some(Default::default());
+
+    assert_eq!(settings.wix_license(), Some(&license_file));
+    assert_eq!(settings.nsis_license(), Some(&license_file));
+    assert_eq!(settings.dmg_license(), Some(license_file.clone()));
+
+    // the installer-specific license takes precedence
+    let wix_license = PathBuf::from("wix-license.rtf");
+    settings.windows.wix.as_mut().unwrap().license = Some(wix_license.clone());
+    settings.macos.license = Some("dmg-license.txt".into());
+    assert_eq!(settings.wix_license(), Some(&wix_license));
+    assert_eq!(settings.nsis_license(), Some(&license_file));
+    assert_eq!(
+      settings.dmg_license(),
+      Some(PathBuf::from("dmg-license.txt"))
+    );
+
+    settings.windows.wix = None;
+    assert_eq!(settings.wix_license(), Some(&license_file));
+    assert_eq!(BundleSettings::default().nsis_license(), None);
+  }
 }
@@ -27,7 +27,10 @@ use std::{
   path::{Path, PathBuf},
   process::Command,
 };
-use tauri_utils::{config::WebviewInstallMode, display_path};
+use tauri_utils::{
+  config::{RegistryEntry, RegistryValueType, WebviewInstallMode},
+  display_path,
+};
 use uuid::Uuid;
 
 // URLS for the WIX toolchain.  Can be used for cross-platform compilation.
@@ -91,6 +94,62 @@ struct ResourceFile {
   path: PathBuf,
 }
 
+/// A registry value to write with WIX.
+/// Each value is installed by its own component, which removes it on uninstall.
+#[derive(Serialize)]
+struct RegistryValue {
+  /// the id of the component on the WIX XML.
+  id: String,
+  /// the registry root key.
+  root: &'static str,
+  /// the registry key path.
+  key: String,
+  /// the value name, the default value of the key when not set.
+  name: Option<String>,
+  /// the WIX value type.
+  #[serde(rename = "type")]
+  type_: &'static str,
+  /// the value data.
+  value: String,
+}
+
+/// Escapes a string to be used verbatim in a formatted WIX attribute.
+fn escape_wix_attribute(value: &str) -> String {
+  let mut escaped = String::with_capacity(value.len());
+  for c in value.chars() {
+    match c {
+      '[' => escaped.push_str(r"[\[]"),
+      ']' => escaped.push_str(r"[\]]"),
+      '&' => escaped.push_str("&amp;"),
+      '<' => escaped.push_str("&lt;"),
+      '>' => escaped.push_str("&gt;"),
+      '"' => escaped.push_str("&quot;"),
+      _ => escaped.push(c),
+    }
+  }
+  escaped
+}
+
+fn generate_registry_data(entries: &[RegistryEntry]) -> Vec<RegistryValue> {
+  entries
+    .iter()
+    .enumerate()
+    .map(|(i, entry)| RegistryValue {
+      id: format!("RegistryValue{i}"),
+      root: entry.root.as_str(),
+      key: escape_wix_attribute(&entry.key),
+      name: entry.name.as_deref().map(escape_wix_attribute),
+      type_: match entry.value_type {
+        RegistryValueType::String => "string",
+        RegistryValueType::ExpandString => "expandable",
+        RegistryValueType::Dword => "integer",
+        RegistryValueType::Binary => "binary",
+      },
+      value: escape_wix_attribute(&entry.value),
+    })
+    .collect()
+}
+
 /// A resource directory to bundle with WIX.
 /// This data structure is needed because WIX requires each path to have its own `id` and `guid`.
 #[derive(Serialize)]
@@ -488,27 +547,25 @@ pub fn build_wix_app_installer(
   let language_map: HashMap<String, LanguageMetadata> =
     serde_json::from_str(include_str!("./languages.json")).unwrap();
 
-  if let Some(wix) = &settings.windows().wix {
-    if let Some(license) = &wix.license {
-      if license.ends_with(".rtf") {
-        data.insert("license", to_json(license));
-      } else {
-        let license_contents = read_to_string(license)?;
-        let license_rtf = format!(
-          r#"{{\rtf1\ansi\ansicpg1252\deff0\nouicompat\deflang1033{{\fonttbl{{\f0\fnil\fcharset0 Calibri;}}}}
+  if let Some(license) = settings.wix_license() {
+    if license.ends_with(".rtf") {
+      data.insert("license", to_json(license));
+    } else {
+      let license_contents = read_to_string(license)?;
+      let license_rtf = format!(
+        r#"{{\rtf1\ansi\ansicpg1252\deff0\nouicompat\deflang1033{{\fonttbl{{\f0\fnil\fcharset0 Calibri;}}}}
 {{\*\generator Riched20 10.0.18362}}\viewkind4\uc1
 \pard\sa200\sl276\slmult1\f0\fs22\lang9 {}\par
 }}
  "#,
-          license_contents.replace('\n', "\\par ")
-        );
-        let rtf_output_path = settings
-          .project_out_directory()
-          .join("wix")
-          .join("LICENSE.rtf");
-        std::fs::write(&rtf_output_path, license_rtf)?;
-        data.insert("license", to_json(rtf_output_path));
-      }
+        license_contents.replace('\n', "\\par ")
+      );
+      let rtf_output_path = settings
+        .project_out_directory()
+        .join("wix")
+        .join("LICENSE.rtf");
+      std::fs::write(&rtf_output_path, license_rtf)?;
+      data.insert("license", to_json(rtf_output_path));
     }
   }
 
@@ -589,10 +646,18 @@ pub fn build_wix_app_installer(
     data.insert("feature_group_refs", to_json(&wix.feature_group_refs));
     data.insert("feature_refs", to_json(&wix.feature_refs));
     data.insert("merge_refs", to_json(&wix.merge_refs));
+    data.insert(
+      "registry_values",
+      to_json(generate_registry_data(&wix.registry_keys)),
+    );
     fragment_paths = wix.fragment_paths.clone();
     enable_elevated_update_task = wix.enable_elevated_update_task;
     custom_template_path = wix.template.clone();
 
+    if wix.launch_on_finish == Some(false) {
+      data.insert("disable_launch_on_finish", to_json(true));
+    }
+
     if let Some(banner_path) = &wix.banner_path {
       let filename = banner_path
         .file_name()
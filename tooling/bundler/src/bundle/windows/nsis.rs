@@ -20,7 +20,10 @@ use tauri_utils::display_path;
 use anyhow::Context;
 use handlebars::{to_json, Handlebars};
 use log::{info, warn};
-use tauri_utils::config::{NSISInstallerMode, NsisCompression, WebviewInstallMode};
+use tauri_utils::config::{
+  NSISInstallerMode, NsisCompression, RegistryEntry, RegistryRoot, RegistryValueType,
+  WebviewInstallMode,
+};
 
 use std::{
   collections::{BTreeMap, HashMap},
@@ -215,6 +218,9 @@ fn build_nsis_app_installer(
   let mut languages = vec!["English".into()];
   let mut custom_template_path = None;
   let mut custom_language_files = None;
+  if let Some(license) = settings.nsis_license() {
+    data.insert("license", to_json(dunce::canonicalize(license)?));
+  }
   if let Some(nsis) = &settings.windows().nsis {
     custom_template_path = nsis.template.clone();
     custom_language_files = nsis.custom_language_files.clone();
@@ -223,9 +229,6 @@ fn build_nsis_app_installer(
       languages.clear();
       languages.extend_from_slice(langs);
     }
-    if let Some(license) = &nsis.license {
-      data.insert("license", to_json(dunce::canonicalize(license)?));
-    }
     if let Some(installer_icon) = &nsis.installer_icon {
       data.insert(
         "installer_icon",
@@ -255,6 +258,29 @@ fn build_nsis_app_installer(
       "display_language_selector",
       to_json(nsis.display_language_selector && languages.len() > 1),
     );
+
+    if let Some(launch_on_finish) = nsis.launch_on_finish {
+      data.insert("launch_on_finish", to_json(launch_on_finish));
+    }
+
+    if install_mode == NSISInstallerMode::CurrentUser {
+      for entry in &nsis.registry_keys {
+        if entry.root == RegistryRoot::LocalMachine {
+          warn!(
+            "The NSIS installer writes the `HKLM\\{}` registry key but its install mode is `currentUser`, which does not have the required Administrator access. Set `tauri.conf.json > tauri > bundle > windows > nsis > installMode` to `perMachine` or `both`.",
+            entry.key
+          );
+        }
+      }
+    }
+    data.insert(
+      "registry_keys",
+      to_json(generate_registry_data(&nsis.registry_keys)),
+    );
+
+    if let Some(minimum_windows_version) = &nsis.minimum_windows_version {
+      data.insert("minimum_windows_version", to_json(minimum_windows_version));
+    }
   }
   data.insert(
     "install_mode",
@@ -592,6 +618,30 @@ fn generate_binaries_data(settings: &Settings) -> crate::Result<BinariesMap> {
   Ok(binaries)
 }
 
+fn generate_registry_data(entries: &[RegistryEntry]) -> Vec<BTreeMap<&'static str, String>> {
+  entries
+    .iter()
+    .map(|entry| {
+      BTreeMap::from([
+        ("root", entry.root.as_str().to_string()),
+        (
+          "command",
+          match entry.value_type {
+            RegistryValueType::String => "WriteRegStr",
+            RegistryValueType::ExpandString => "WriteRegExpandStr",
+            RegistryValueType::Dword => "WriteRegDWORD",
+            RegistryValueType::Binary => "WriteRegBin",
+          }
+          .to_string(),
+        ),
+        ("key", entry.key.clone()),
+        ("name", entry.name.clone().unwrap_or_default()),
+        ("value", entry.value.clone()),
+      ])
+    })
+    .collect()
+}
+
 fn generate_estimated_size(
   main: &Path,
   binaries: &BinariesMap,
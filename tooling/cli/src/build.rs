@@ -52,7 +52,8 @@ pub struct Options {
   /// Each bundle must be one of `deb`, `rpm`, `appimage`, `msi`, `app` or `dmg` on MacOS and `updater` on all platforms.
   /// If `none` is specified, the bundler will be skipped.
   ///
-  /// Note that the `updater` bundle is not automatically added so you must specify it if the updater is enabled.
+  /// Note that the `updater` bundle is not automatically added so you must specify it if the updater is enabled,
+  /// unless `tauri > bundle > updater > createArtifacts` is set to `true`.
   #[clap(short, long, action = ArgAction::Append, num_args(0..))]
   pub bundles: Option<Vec<String>>,
   /// JSON string or path to JSON file to merge with tauri.conf.json
@@ -123,7 +124,10 @@ pub fn command(mut options: Options, verbosity: u8) -> Result<()> {
     };
 
     if let Some(types) = &package_types {
-      if config_.tauri.bundle.updater.active && !types.contains(&PackageType::Updater) {
+      if config_.tauri.bundle.updater.active
+        && config_.tauri.bundle.updater.create_artifacts.is_none()
+        && !types.contains(&PackageType::Updater)
+      {
         warn!("The updater is enabled but the bundle target list does not contain `updater`, so the updater artifacts won't be generated.");
       }
     }
@@ -166,8 +170,8 @@ pub fn command(mut options: Options, verbosity: u8) -> Result<()> {
       .iter()
       .filter(|bundle| bundle.package_type == PackageType::Updater)
       .collect();
-    // If updater is active and we bundled it
-    if config_.tauri.bundle.updater.active && !updater_bundles.is_empty() {
+    // If we bundled the updater artifacts, sign them
+    if !updater_bundles.is_empty() {
       // if no password provided we use an empty string
       let password = var_os("TAURI_SIGNING_PRIVATE_KEY_PASSWORD")
         .map(|v| v.to_str().unwrap().to_string())
@@ -313,14 +317,64 @@ pub fn setup(target: Target, options: &mut Options, mobile: bool) -> Result<AppI
   Ok(interface)
 }
 
+/// A script of a [`HookCommand`].
+struct HookScript {
+  script: String,
+  cwd: Option<PathBuf>,
+  continue_on_error: bool,
+}
+
+/// The scripts of the hook, in the order they run.
+fn hook_scripts(hook: HookCommand, scripts: &mut Vec<HookScript>) {
+  match hook {
+    HookCommand::Script(s) if s.is_empty() => {}
+    HookCommand::Script(script) => scripts.push(HookScript {
+      script,
+      cwd: None,
+      continue_on_error: false,
+    }),
+    HookCommand::ScriptWithOptions {
+      script,
+      cwd,
+      continue_on_error,
+    } => scripts.push(HookScript {
+      script,
+      cwd: cwd.map(Into::into),
+      continue_on_error,
+    }),
+    HookCommand::List(hooks) => {
+      for hook in hooks {
+        hook_scripts(hook, scripts);
+      }
+    }
+  }
+}
+
+/// Runs the scripts of the hook in order with the given function.
+///
+/// A failing script aborts the hook, unless it is marked with `continueOnError`.
+fn run_hook_scripts<F: FnMut(&HookScript) -> Result<()>>(
+  name: &str,
+  hook: HookCommand,
+  mut run: F,
+) -> Result<()> {
+  let mut scripts = Vec::new();
+  hook_scripts(hook, &mut scripts);
+  for script in scripts {
+    if let Err(e) = run(&script) {
+      if script.continue_on_error {
+        warn!("{e:#}, continuing the {name} because the script sets `continueOnError`");
+      } else {
+        return Err(e);
+      }
+    }
+  }
+  Ok(())
+}
+
 fn run_hook(name: &str, hook: HookCommand, interface: &AppInterface, debug: bool) -> Result<()> {
-  let (script, script_cwd) = match hook {
-    HookCommand::Script(s) if s.is_empty() => (None, None),
-    HookCommand::Script(s) => (Some(s), None),
-    HookCommand::ScriptWithOptions { script, cwd } => (Some(script), cwd.map(Into::into)),
-  };
-  let cwd = script_cwd.unwrap_or_else(|| app_dir().clone());
-  if let Some(script) = script {
+  run_hook_scripts(name, hook, |HookScript { script, cwd, .. }| {
+    let cwd = cwd.clone().unwrap_or_else(|| app_dir().clone());
     info!(action = "Running"; "{} `{}`", name, script);
 
     let mut env = command_env(debug);
@@ -332,7 +386,7 @@ fn run_hook(name: &str, hook: HookCommand, interface: &AppInterface, debug: bool
     let status = Command::new("cmd")
       .arg("/S")
       .arg("/C")
-      .arg(&script)
+      .arg(script)
       .current_dir(cwd)
       .envs(env)
       .piped()
@@ -340,7 +394,7 @@ fn run_hook(name: &str, hook: HookCommand, interface: &AppInterface, debug: bool
     #[cfg(not(target_os = "windows"))]
     let status = Command::new("sh")
       .arg("-c")
-      .arg(&script)
+      .arg(script)
       .current_dir(cwd)
       .envs(env)
       .piped()
@@ -354,9 +408,9 @@ fn run_hook(name: &str, hook: HookCommand, interface: &AppInterface, debug: bool
         status.code().unwrap_or_default()
       );
     }
-  }
 
-  Ok(())
+    Ok(())
+  })
 }
 
 fn print_signed_updater_archive(output_paths: &[PathBuf]) -> crate::Result<()> {
@@ -379,3 +433,56 @@ fn print_signed_updater_archive(output_paths: &[PathBuf]) -> crate::Result<()> {
   }
   Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+  use super::{run_hook_scripts, HookCommand};
+
+  fn script(script: &str, continue_on_error: bool) -> HookCommand {
+    HookCommand::ScriptWithOptions {
+      script: script.into(),
+      cwd: None,
+      continue_on_error,
+    }
+  }
+
+  /// Runs the hook, failing the scripts starting with `fail`, and returns the scripts that ran.
+  fn run(hook: HookCommand) -> (Vec<String>, bool) {
+    let mut ran = Vec::new();
+    let result = run_hook_scripts("beforeBundleCommand", hook, |hook| {
+      ran.push(hook.script.clone());
+      if hook.script.starts_with("fail") {
+        anyhow::bail!("`{}` failed", hook.script);
+      }
+      Ok(())
+    });
+    (ran, result.is_ok())
+  }
+
+  #[test]
+  fn runs_commands_in_order() {
+    let hook = HookCommand::List(vec![
+      HookCommand::Script("first".into()),
+      HookCommand::Script(String::new()),
+      HookCommand::List(vec![script("second", false)]),
+      HookCommand::Script("third".into()),
+    ]);
+    assert_eq!(
+      run(hook),
+      (vec!["first".into(), "second".into(), "third".into()], true)
+    );
+  }
+
+  #[test]
+  fn continues_on_error() {
+    let hook = HookCommand::List(vec![
+      script("fail optional", true),
+      HookCommand::Script("fail required".into()),
+      HookCommand::Script("skipped".into()),
+    ]);
+    assert_eq!(
+      run(hook),
+      (vec!["fail optional".into(), "fail required".into()], false)
+    );
+  }
+}
@@ -6,7 +6,10 @@ use crate::{
   helpers::{
     app_paths::{app_dir, tauri_dir},
     command_env,
-    config::{get as get_config, reload as reload_config, AppUrl, BeforeDevCommand, WebviewUrl},
+    config::{
+      auto_features, get as get_config, reload as reload_config, AppUrl, BeforeDevCommand,
+      WebviewUrl,
+    },
     resolve_merge_config,
   },
   interface::{AppInterface, DevProcess, ExitReason, Interface},
@@ -20,7 +23,9 @@ use shared_child::SharedChild;
 use tauri_utils::platform::Target;
 
 use std::{
-  env::set_current_dir,
+  env::{set_current_dir, set_var},
+  ffi::OsString,
+  fs::{read_to_string, remove_file},
   net::{IpAddr, Ipv4Addr},
   process::{exit, Command, Stdio},
   sync::{
@@ -35,13 +40,16 @@ static KILL_BEFORE_DEV_FLAG: OnceLock<AtomicBool> = OnceLock::new();
 #[cfg(unix)]
 const KILL_CHILDREN_SCRIPT: &[u8] = include_bytes!("../scripts/kill-children.sh");
 
+/// Environment variable with the path of the file storing the window geometry across the app restarts.
+const DEV_WINDOW_STATE_PATH_ENV: &str = "TAURI_DEV_WINDOW_STATE_PATH";
+
 pub const TAURI_CLI_BUILTIN_WATCHER_IGNORE_FILE: &[u8] =
   include_bytes!("../tauri-dev-watcher.gitignore");
 
 #[derive(Debug, Clone, Parser)]
 #[clap(
   about = "Run your app in development mode",
-  long_about = "Run your app in development mode with hot-reloading for the Rust code. It makes use of the `build.devPath` property from your `tauri.conf.json` file. It also runs your `build.beforeDevCommand` which usually starts your frontend devServer.",
+  long_about = "Run your app in development mode with hot-reloading for the Rust code. It makes use of the `build.devPath` property from your `tauri.conf.json` file. It also runs your `build.beforeDevCommand` which usually starts your frontend devServer.\n\nArguments can also be read from a response file with `@path/to/file`, one argument per line. Blank lines and lines starting with `#` are ignored. Response files are expanded before the arguments are parsed, so any flag can be used in them. Arguments after `--` are not expanded.",
   trailing_var_arg(true)
 )]
 pub struct Options {
@@ -54,12 +62,49 @@ pub struct Options {
   /// List of cargo features to activate
   #[clap(short, long, action = ArgAction::Append, num_args(0..))]
   pub features: Option<Vec<String>>,
+  /// Do not enable the `tauri` features implied by the configuration, e.g. `tauri/isolation` for the isolation pattern.
+  ///
+  /// By default they are passed to the runner so the app matches a production build.
+  #[clap(long)]
+  pub no_auto_features: bool,
   /// Exit on panic
   #[clap(short, long)]
   pub exit_on_panic: bool,
+  /// Relaunch the app when it exits with a non-zero code, e.g. when it panics on startup.
+  ///
+  /// The app is relaunched at most 3 times in a row, the counter is reset when it is rebuilt.
+  /// The file watcher and the `build.beforeDevCommand` keep running while it is relaunched.
+  #[clap(long, conflicts_with = "exit_on_panic")]
+  pub restart_on_crash: bool,
+  /// Launch the app under a debugger, e.g. `lldb`, `rust-lldb`, `gdb` or `rust-gdb`.
+  ///
+  /// The value is split on whitespace, so it can include debugger arguments, e.g. `--debugger "gdb -q"`.
+  /// The app binary and its arguments are passed after `--` to lldb and after `--args` to gdb,
+  /// and appended as is for other debuggers.
+  ///
+  /// The app is not rebuilt and restarted on file changes while it runs under the debugger,
+  /// and `tauri dev` exits when the debugger exits.
+  /// Ctrl+C is left to the debugger, e.g. to interrupt the app, so quit the debugger to stop `tauri dev`.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **macOS**: `lldb` may require the developer mode to be enabled with `DevToolsSecurity -enable`.
+  /// - **Linux**: attaching to the app may require `gdb` or `lldb` to be allowed by the `ptrace_scope` setting of Yama.
+  /// - **Windows**: console debuggers such as `cdb` work in the terminal; GUI debuggers open their own window.
+  #[clap(long, conflicts_with = "restart_on_crash")]
+  pub debugger: Option<String>,
   /// JSON string or path to JSON file to merge with tauri.conf.json
+  ///
+  /// Can be used multiple times, mixing files and JSON strings.
+  /// The values are merged from left to right, so the last one wins on conflicts.
   #[clap(short, long)]
-  pub config: Option<String>,
+  pub config: Vec<String>,
+  /// Print the resolved configuration as JSON and exit without running the app.
+  ///
+  /// The printed configuration is the exact one the app receives,
+  /// after merging the platform-specific config files and the `--config` argument.
+  #[clap(long)]
+  pub print_config: bool,
   /// Run the code in release mode
   #[clap(long = "release")]
   pub release_mode: bool,
@@ -70,6 +115,11 @@ pub struct Options {
   /// Skip waiting for the frontend dev server to start before building the tauri application.
   #[clap(long, env = "TAURI_CLI_NO_DEV_SERVER_WAIT")]
   pub no_dev_server_wait: bool,
+  /// Skip running the `build.beforeDevCommand`, e.g. when the frontend dev server is started manually.
+  ///
+  /// The CLI still waits for the dev server to start unless `--no-dev-server-wait` is also set.
+  #[clap(long)]
+  pub no_before_dev_command: bool,
   /// Disable the file watcher.
   #[clap(long)]
   pub no_watch: bool,
@@ -81,8 +131,70 @@ pub struct Options {
   #[clap(long)]
   pub no_dev_server: bool,
   /// Specify port for the built-in dev server for static files. Defaults to 1430.
+  ///
+  /// When not set, the port picked by the previous run is reused if it is still free,
+  /// so the dev server URL does not change across restarts.
+  /// It is persisted in the `.tauri/dev-server-port` file of the `src-tauri` directory.
   #[clap(long, env = "TAURI_CLI_PORT")]
   pub port: Option<u16>,
+  /// Do not reuse the built-in dev server port of the previous run, picking the first free port from 1430 instead.
+  #[clap(long)]
+  pub fresh_port: bool,
+  /// Allow the dev server origin on the development CSP.
+  ///
+  /// The dev server origin is added to the `connect-src` and `script-src` directives of `tauri > security > devCsp`,
+  /// or of `tauri > security > csp` if no development CSP is set. `connect-src` also gets the matching WebSocket origin.
+  /// The production `tauri > security > csp` is never modified, so release builds are not affected.
+  #[clap(long)]
+  pub relax_csp: bool,
+  /// Do not restore the size and position of the windows when the app is restarted after a Rust code change.
+  ///
+  /// By default the window geometry is kept across the restarts of a `tauri dev` session,
+  /// even for the windows without `tauri > windows > rememberState`. It is stored in a temporary file
+  /// that is cleared when `tauri dev` starts, so this only affects development.
+  #[clap(long)]
+  pub no_restore_geometry: bool,
+}
+
+/// Replaces each `@file` argument with the arguments listed in that file, one per line,
+/// ignoring blank lines and `#` comments.
+///
+/// Arguments after `--`, including a `--` listed in a response file, are passed to the runner and the app as is.
+pub fn expand_response_files(args: Vec<OsString>) -> std::io::Result<Vec<OsString>> {
+  let mut expanded = Vec::with_capacity(args.len());
+  let mut args = args.into_iter();
+  while let Some(arg) = args.next() {
+    if arg == "--" {
+      expanded.push(arg);
+      expanded.extend(args);
+      break;
+    }
+
+    match arg.to_str().and_then(|a| a.strip_prefix('@')) {
+      Some(path) => {
+        let contents = read_to_string(path).map_err(|e| {
+          std::io::Error::new(
+            e.kind(),
+            format!("failed to read response file {path}: {e}"),
+          )
+        })?;
+        let lines = contents
+          .lines()
+          .map(str::trim)
+          .filter(|line| !line.is_empty() && !line.starts_with('#'))
+          .map(OsString::from)
+          .collect::<Vec<_>>();
+        let separated = lines.iter().any(|line| line == "--");
+        expanded.extend(lines);
+        if separated {
+          expanded.extend(args);
+          break;
+        }
+      }
+      None => expanded.push(arg),
+    }
+  }
+  Ok(expanded)
 }
 
 pub fn command(options: Options) -> Result<()> {
@@ -99,7 +211,23 @@ fn command_internal(mut options: Options) -> Result<()> {
     .as_deref()
     .map(Target::from_triple)
     .unwrap_or_else(Target::current);
+  if options.print_config {
+    return print_config(target, &options);
+  }
+  if let Some(debugger) = &options.debugger {
+    if debugger.trim().is_empty() {
+      bail!("`--debugger` expects a debugger command, e.g. `--debugger lldb`");
+    }
+    if !options.no_watch {
+      warn!("The app is not restarted on file changes while it runs under a debugger, disabling the file watcher.");
+      options.no_watch = true;
+    }
+  }
   let mut interface = setup(target, &mut options, false)?;
+  if options.debugger.is_some() {
+    // leave Ctrl+C to the debugger, this fails if `setup` already set the handler of the `beforeDevCommand`
+    let _ = ctrlc::set_handler(|| {});
+  }
   let exit_on_panic = options.exit_on_panic;
   let no_watch = options.no_watch;
   interface.dev(options.into(), move |status, reason| {
@@ -107,6 +235,17 @@ fn command_internal(mut options: Options) -> Result<()> {
   })
 }
 
+fn print_config(target: Target, options: &Options) -> Result<()> {
+  let (merge_config, _merge_config_path) = resolve_merge_config(&options.config)?;
+  let config = get_config(target, merge_config.as_deref())?;
+  let config = config.lock().unwrap();
+  println!(
+    "{}",
+    serde_json::to_string_pretty(&**config.as_ref().unwrap())?
+  );
+  Ok(())
+}
+
 pub fn local_ip_address(force: bool) -> &'static IpAddr {
   static LOCAL_IP: OnceLock<IpAddr> = OnceLock::new();
   LOCAL_IP.get_or_init(|| {
@@ -152,9 +291,9 @@ pub fn local_ip_address(force: bool) -> &'static IpAddr {
 
 pub fn setup(target: Target, options: &mut Options, mobile: bool) -> Result<AppInterface> {
   let (merge_config, _merge_config_path) = resolve_merge_config(&options.config)?;
-  options.config = merge_config;
+  options.config = merge_config.into_iter().collect();
 
-  let config = get_config(target, options.config.as_deref())?;
+  let config = get_config(target, options.config.first().map(String::as_str))?;
 
   let tauri_path = tauri_dir();
   set_current_dir(tauri_path).with_context(|| "failed to change current working directory")?;
@@ -173,15 +312,20 @@ pub fn setup(target: Target, options: &mut Options, mobile: bool) -> Result<AppI
     .dev_path
     .clone();
 
-  if let Some(before_dev) = config
-    .lock()
-    .unwrap()
-    .as_ref()
-    .unwrap()
-    .build
-    .before_dev_command
-    .clone()
-  {
+  let before_dev = if options.no_before_dev_command {
+    None
+  } else {
+    config
+      .lock()
+      .unwrap()
+      .as_ref()
+      .unwrap()
+      .build
+      .before_dev_command
+      .clone()
+  };
+
+  if let Some(before_dev) = before_dev {
     let (script, script_cwd, wait) = match before_dev {
       BeforeDevCommand::Script(s) if s.is_empty() => (None, None, false),
       BeforeDevCommand::Script(s) => (Some(s), None, false),
@@ -273,9 +417,13 @@ pub fn setup(target: Target, options: &mut Options, mobile: bool) -> Result<AppI
         BEFORE_DEV.set(Mutex::new(child)).unwrap();
         KILL_BEFORE_DEV_FLAG.set(AtomicBool::default()).unwrap();
 
+        let debugging = options.debugger.is_some();
         let _ = ctrlc::set_handler(move || {
-          kill_before_dev_process();
-          exit(130);
+          // the debugger handles Ctrl+C, e.g. to interrupt the app
+          if !debugging {
+            kill_before_dev_process();
+            exit(130);
+          }
         });
       }
     }
@@ -292,6 +440,16 @@ pub fn setup(target: Target, options: &mut Options, mobile: bool) -> Result<AppI
       .clone();
   }
 
+  if !options.no_auto_features {
+    let features = auto_features(config.lock().unwrap().as_ref().unwrap());
+    if !features.is_empty() {
+      options
+        .features
+        .get_or_insert_with(Vec::new)
+        .extend(features);
+    }
+  }
+
   let mut cargo_features = config
     .lock()
     .unwrap()
@@ -315,7 +473,7 @@ pub fn setup(target: Target, options: &mut Options, mobile: bool) -> Result<AppI
     .clone();
   if !options.no_dev_server {
     if let AppUrl::Url(WebviewUrl::App(path)) = &dev_path {
-      use crate::helpers::web_dev_server::start_dev_server;
+      use crate::helpers::web_dev_server::{persist_port, persisted_port, start_dev_server};
       if path.exists() {
         let path = path.canonicalize()?;
         let ip = if mobile {
@@ -323,23 +481,85 @@ pub fn setup(target: Target, options: &mut Options, mobile: bool) -> Result<AppI
         } else {
           Ipv4Addr::new(127, 0, 0, 1).into()
         };
-        let server_url = start_dev_server(path, ip, options.port)?;
+        let live_reload = config
+          .lock()
+          .unwrap()
+          .as_ref()
+          .unwrap()
+          .build
+          .dev_server_live_reload;
+        let preferred_port = if options.fresh_port {
+          None
+        } else {
+          persisted_port(&tauri_dir())
+        };
+        let server_url = start_dev_server(path, ip, options.port, preferred_port, live_reload)?;
+        if options.port.is_none() {
+          if let Err(e) = persist_port(&tauri_dir(), server_url.port()) {
+            warn!("failed to persist the dev server port: {e}");
+          }
+        }
         let server_url = format!("http://{server_url}");
         dev_path = AppUrl::Url(WebviewUrl::External(server_url.parse().unwrap()));
 
-        if let Some(c) = &options.config {
+        if let Some(c) = options.config.first() {
           let mut c: tauri_utils::config::Config = serde_json::from_str(c)?;
           c.build.dev_path = dev_path.clone();
-          options.config = Some(serde_json::to_string(&c).unwrap());
+          options.config = vec![serde_json::to_string(&c).unwrap()];
         } else {
-          options.config = Some(format!(r#"{{ "build": {{ "devPath": "{server_url}" }} }}"#))
+          options.config = vec![format!(r#"{{ "build": {{ "devPath": "{server_url}" }} }}"#)]
         }
 
-        reload_config(options.config.as_deref())?;
+        reload_config(options.config.first().map(String::as_str))?;
+      }
+    }
+  }
+
+  if options.relax_csp {
+    if let AppUrl::Url(WebviewUrl::External(dev_server_url)) = &dev_path {
+      let dev_csp = config
+        .lock()
+        .unwrap()
+        .as_ref()
+        .unwrap()
+        .tauri
+        .security
+        .relaxed_dev_csp(dev_server_url);
+      if let Some(dev_csp) = dev_csp {
+        let mut c: serde_json::Value = match options.config.first() {
+          Some(c) => serde_json::from_str(c)?,
+          None => serde_json::json!({}),
+        };
+        c["tauri"]["security"]["devCsp"] = serde_json::to_value(&dev_csp)?;
+        options.config = vec![c.to_string()];
+        info!(
+          "Allowing {} on the development CSP.",
+          dev_server_url.origin().ascii_serialization()
+        );
+
+        reload_config(options.config.first().map(String::as_str))?;
       }
+    } else {
+      warn!("`--relax-csp` has no effect without a dev server URL.");
     }
   }
 
+  if !(mobile || options.no_restore_geometry) {
+    let identifier = config
+      .lock()
+      .unwrap()
+      .as_ref()
+      .unwrap()
+      .tauri
+      .bundle
+      .identifier
+      .clone();
+    let state_path = std::env::temp_dir().join(format!("{identifier}-dev-window-state.json"));
+    // only keep the window geometry across the restarts of this session
+    let _ = remove_file(&state_path);
+    set_var(DEV_WINDOW_STATE_PATH_ENV, state_path);
+  }
+
   if !options.no_dev_server_wait {
     if let AppUrl::Url(WebviewUrl::External(dev_server_url)) = dev_path {
       let host = dev_server_url
@@ -398,6 +618,9 @@ pub fn setup(target: Target, options: &mut Options, mobile: bool) -> Result<AppI
   Ok(interface)
 }
 
+/// Maximum number of times `--restart-on-crash` relaunches the app in a row.
+pub const MAX_CRASH_RESTARTS: u32 = 3;
+
 pub fn wait_dev_process<
   C: DevProcess + Send + 'static,
   F: Fn(Option<i32>, ExitReason) + Send + Sync + 'static,
@@ -422,6 +645,48 @@ pub fn wait_dev_process<
   });
 }
 
+/// Waits for the dev process spawned by `spawn` like [`wait_dev_process`], spawning it again when it
+/// exits with a non-zero code without being killed, at most [`MAX_CRASH_RESTARTS`] times in a row.
+pub fn wait_dev_process_restarting<
+  C: DevProcess + Send + 'static,
+  S: Fn() -> C + Send + Sync + 'static,
+  F: Fn(Option<i32>, ExitReason) + Send + Sync + 'static,
+>(
+  spawn: S,
+  on_exit: F,
+) {
+  restart_dev_process(Arc::new(spawn), Arc::new(on_exit), 0);
+}
+
+fn restart_dev_process<
+  C: DevProcess + Send + 'static,
+  S: Fn() -> C + Send + Sync + 'static,
+  F: Fn(Option<i32>, ExitReason) + Send + Sync + 'static,
+>(
+  spawn: Arc<S>,
+  on_exit: Arc<F>,
+  restarts: u32,
+) {
+  wait_dev_process(spawn(), move |code, reason| {
+    if matches!(reason, ExitReason::NormalExit) && code != Some(0) {
+      if restarts < MAX_CRASH_RESTARTS {
+        warn!(
+          "App exited with code {}, restarting it ({}/{MAX_CRASH_RESTARTS})...",
+          code.unwrap_or_default(),
+          restarts + 1
+        );
+        restart_dev_process(spawn.clone(), on_exit.clone(), restarts + 1);
+        return;
+      }
+      error!(
+        "App crashed {} times in a row, not restarting it again",
+        MAX_CRASH_RESTARTS + 1
+      );
+    }
+    on_exit(code, reason);
+  });
+}
+
 pub fn on_app_exit(code: Option<i32>, reason: ExitReason, exit_on_panic: bool, no_watch: bool) {
   if no_watch
     || (!matches!(reason, ExitReason::TriggeredKill)
@@ -474,3 +739,132 @@ pub fn kill_before_dev_process() {
     let _ = child.kill();
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::{expand_response_files, wait_dev_process_restarting, MAX_CRASH_RESTARTS};
+  use crate::interface::{DevProcess, ExitReason};
+  use std::{
+    ffi::OsString,
+    process::ExitStatus,
+    sync::{
+      atomic::{AtomicU32, Ordering},
+      mpsc, Arc, Mutex,
+    },
+    time::Duration,
+  };
+
+  fn args(args: &[&str]) -> Vec<OsString> {
+    args.iter().map(OsString::from).collect()
+  }
+
+  #[test]
+  fn response_files() {
+    let dir = std::env::temp_dir().join(format!("tauri-dev-args-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("args.txt");
+    std::fs::write(&file, "# dev flags\n--release\n\n  --features\ncustom  \n").unwrap();
+    let separated = dir.join("separated.txt");
+    std::fs::write(&separated, "--no-watch\n--\n--app-flag\n").unwrap();
+    let file_arg = format!("@{}", file.display());
+    let separated_arg = format!("@{}", separated.display());
+
+    assert_eq!(
+      expand_response_files(args(&["tauri", "dev", &file_arg, "--no-watch"])).unwrap(),
+      args(&[
+        "tauri",
+        "dev",
+        "--release",
+        "--features",
+        "custom",
+        "--no-watch"
+      ])
+    );
+
+    // the arguments after `--` belong to the app
+    assert_eq!(
+      expand_response_files(args(&["tauri", "dev", "--", &file_arg])).unwrap(),
+      args(&["tauri", "dev", "--", &file_arg])
+    );
+    assert_eq!(
+      expand_response_files(args(&["tauri", "dev", &separated_arg, &file_arg])).unwrap(),
+      args(&["tauri", "dev", "--no-watch", "--", "--app-flag", &file_arg])
+    );
+
+    let missing = format!("@{}", dir.join("missing.txt").display());
+    assert!(expand_response_files(args(&["tauri", "dev", &missing])).is_err());
+
+    std::fs::remove_dir_all(dir).unwrap();
+  }
+
+  struct TestProcess {
+    crashed: bool,
+    killed: bool,
+  }
+
+  impl DevProcess for TestProcess {
+    fn kill(&self) -> std::io::Result<()> {
+      Ok(())
+    }
+
+    fn try_wait(&self) -> std::io::Result<Option<ExitStatus>> {
+      self.wait().map(Some)
+    }
+
+    fn wait(&self) -> std::io::Result<ExitStatus> {
+      if self.crashed {
+        Err(std::io::Error::new(std::io::ErrorKind::Other, "crashed"))
+      } else {
+        #[cfg(unix)]
+        let status = std::os::unix::process::ExitStatusExt::from_raw(0);
+        #[cfg(windows)]
+        let status = std::os::windows::process::ExitStatusExt::from_raw(0);
+        Ok(status)
+      }
+    }
+
+    fn manually_killed_process(&self) -> bool {
+      self.killed
+    }
+
+    fn is_building_app(&self) -> bool {
+      false
+    }
+  }
+
+  /// Runs a process that crashes `crashes` times, returning how many times it was spawned and how it exited.
+  fn restart(crashes: u32, killed: bool) -> (u32, Option<i32>, ExitReason) {
+    let spawns = Arc::new(AtomicU32::new(0));
+    let spawns_ = spawns.clone();
+    let (tx, rx) = mpsc::channel();
+    let tx = Mutex::new(tx);
+    wait_dev_process_restarting(
+      move || TestProcess {
+        crashed: spawns_.fetch_add(1, Ordering::Relaxed) < crashes,
+        killed,
+      },
+      move |code, reason| tx.lock().unwrap().send((code, reason)).unwrap(),
+    );
+    let (code, reason) = rx.recv_timeout(Duration::from_secs(5)).unwrap();
+    (spawns.load(Ordering::Relaxed), code, reason)
+  }
+
+  #[test]
+  fn restarts_crashed_process() {
+    let (spawns, code, reason) = restart(0, false);
+    assert_eq!((spawns, code), (1, Some(0)));
+    assert!(matches!(reason, ExitReason::NormalExit));
+
+    let (spawns, code, reason) = restart(MAX_CRASH_RESTARTS, false);
+    assert_eq!((spawns, code), (MAX_CRASH_RESTARTS + 1, Some(0)));
+    assert!(matches!(reason, ExitReason::NormalExit));
+
+    let (spawns, code, reason) = restart(u32::MAX, false);
+    assert_eq!((spawns, code), (MAX_CRASH_RESTARTS + 1, Some(1)));
+    assert!(matches!(reason, ExitReason::NormalExit));
+
+    let (spawns, code, reason) = restart(u32::MAX, true);
+    assert_eq!((spawns, code), (1, Some(1)));
+    assert!(matches!(reason, ExitReason::TriggeredKill));
+  }
+}
@@ -4,7 +4,7 @@
 
 use anyhow::Context;
 use json_patch::merge;
-use log::error;
+use log::{error, info, warn};
 use serde_json::Value as JsonValue;
 
 pub use tauri_utils::{config::*, platform::Target};
@@ -18,6 +18,7 @@ use std::{
 };
 
 pub const MERGE_CONFIG_EXTENSION_NAME: &str = "--config";
+pub const BUNDLE_TARGETS_ENV_VAR: &str = "TAURI_BUNDLE_TARGETS";
 
 pub struct ConfigMetadata {
   /// The current target.
@@ -95,6 +96,8 @@ pub fn wix_settings(config: WixConfig) -> tauri_bundler::WixSettings {
     banner_path: config.banner_path,
     dialog_image_path: config.dialog_image_path,
     fips_compliant: var_os("TAURI_BUNDLER_WIX_FIPS_COMPLIANT").map_or(false, |v| v == "true"),
+    launch_on_finish: config.launch_on_finish,
+    registry_keys: config.registry_keys,
   }
 }
 
@@ -110,6 +113,9 @@ pub fn nsis_settings(config: NsisConfig) -> tauri_bundler::NsisSettings {
     custom_language_files: config.custom_language_files,
     display_language_selector: config.display_language_selector,
     compression: config.compression,
+    launch_on_finish: config.launch_on_finish,
+    registry_keys: config.registry_keys,
+    minimum_windows_version: config.minimum_windows_version,
   }
 }
 
@@ -118,6 +124,28 @@ fn config_handle() -> &'static ConfigHandle {
   CONFIG_HANDLE.get_or_init(Default::default)
 }
 
+/// Reads the `bundle > targets` override from the [`BUNDLE_TARGETS_ENV_VAR`] environment variable.
+///
+/// It takes precedence over the configuration files, but the `--config` argument overrides it.
+fn bundle_targets_from_env() -> crate::Result<Option<JsonValue>> {
+  match var_os(BUNDLE_TARGETS_ENV_VAR) {
+    Some(targets) => targets
+      .to_str()
+      .with_context(|| format!("{BUNDLE_TARGETS_ENV_VAR} is not valid UTF-8"))
+      .and_then(bundle_targets_config)
+      .map(Some),
+    None => Ok(None),
+  }
+}
+
+/// Parses a bundle targets list into a config value that can be merged with the app configuration.
+fn bundle_targets_config(targets: &str) -> crate::Result<JsonValue> {
+  let targets = targets
+    .parse::<BundleTarget>()
+    .with_context(|| format!("invalid {BUNDLE_TARGETS_ENV_VAR} value `{targets}`"))?;
+  Ok(serde_json::json!({ "tauri": { "bundle": { "targets": targets } } }))
+}
+
 /// Gets the static parsed config from `tauri.conf.json`.
 fn get_internal(
   merge_config: Option<&str>,
@@ -144,6 +172,11 @@ fn get_internal(
     );
   }
 
+  if let Some(targets_config) = bundle_targets_from_env()? {
+    merge(&mut config, &targets_config);
+    extensions.insert(BUNDLE_TARGETS_ENV_VAR.into(), targets_config);
+  }
+
   if let Some(merge_config) = merge_config {
     set_var("TAURI_CONFIG", merge_config);
     let merge_config: JsonValue =
@@ -181,6 +214,23 @@ fn get_internal(
   // revert to previous working directory
   set_current_dir(current_dir)?;
 
+  let mut errors = 0;
+  for diagnostic in config.validate(target) {
+    match diagnostic.severity {
+      DiagnosticSeverity::Info => info!("{diagnostic}"),
+      DiagnosticSeverity::Warning => warn!("{diagnostic}"),
+      DiagnosticSeverity::Error => {
+        error!("{diagnostic}");
+        errors += 1;
+      }
+    }
+  }
+  if errors > 0 && !reload {
+    return Err(anyhow::anyhow!(
+      "`{config_file_name}` is invalid, found {errors} error(s)"
+    ));
+  }
+
   for (plugin, conf) in &config.plugins.0 {
     set_var(
       format!(
@@ -200,6 +250,18 @@ fn get_internal(
   Ok(config_handle().clone())
 }
 
+/// The `tauri` features implied by the configuration, e.g. `tauri/isolation` when using the isolation pattern.
+///
+/// Passed to the runner in dev mode so the app has the same functionality as a production build.
+pub fn auto_features(config: &Config) -> Vec<String> {
+  config
+    .tauri
+    .features()
+    .into_iter()
+    .map(|feature| format!("tauri/{feature}"))
+    .collect()
+}
+
 pub fn get(target: Target, merge_config: Option<&str>) -> crate::Result<ConfigHandle> {
   get_internal(merge_config, false, target)
 }
@@ -216,3 +278,48 @@ pub fn reload(merge_config: Option<&str>) -> crate::Result<ConfigHandle> {
     Err(anyhow::anyhow!("config not loaded"))
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use json_patch::merge;
+  use serde_json::json;
+
+  use super::{auto_features, bundle_targets_config, Config};
+
+  #[test]
+  fn bundle_targets_env() {
+    assert_eq!(
+      bundle_targets_config("Deb,appimage").unwrap(),
+      json!({ "tauri": { "bundle": { "targets": ["deb", "appimage"] } } })
+    );
+    assert_eq!(
+      bundle_targets_config("ALL").unwrap(),
+      json!({ "tauri": { "bundle": { "targets": "all" } } })
+    );
+    assert!(bundle_targets_config("deb,exe").is_err());
+
+    // env overrides the config file, `--config` overrides env
+    let mut config = json!({ "tauri": { "bundle": { "targets": "all", "active": true } } });
+    merge(&mut config, &bundle_targets_config("dmg").unwrap());
+    assert_eq!(
+      config,
+      json!({ "tauri": { "bundle": { "targets": ["dmg"], "active": true } } })
+    );
+    merge(
+      &mut config,
+      &json!({ "tauri": { "bundle": { "targets": ["app"] } } }),
+    );
+    assert_eq!(config["tauri"]["bundle"]["targets"], json!(["app"]));
+  }
+
+  #[test]
+  fn isolation_auto_feature() {
+    let config: Config = serde_json::from_value(json!({
+      "tauri": { "pattern": { "use": "isolation", "options": { "dir": "../dist-isolation" } } }
+    }))
+    .unwrap();
+    assert_eq!(auto_features(&config), vec!["tauri/isolation".to_string()]);
+
+    assert!(auto_features(&Config::default()).is_empty());
+  }
+}
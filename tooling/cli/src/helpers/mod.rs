@@ -55,15 +55,76 @@ pub fn cross_command(bin: &str) -> Command {
   cmd
 }
 
-pub fn resolve_merge_config(
-  config: &Option<String>,
+/// Resolves the `--config` values into the JSON string to merge with the tauri.conf.json file
+/// and the path of the last configuration file, if any.
+///
+/// Each value is either inline JSON, when it starts with `{`, or a path to a JSON file,
+/// so files and inline JSON can be mixed. The values are merged from left to right,
+/// so the last one wins on conflicts.
+pub fn resolve_merge_config<'a>(
+  configs: impl IntoIterator<Item = &'a String>,
 ) -> crate::Result<(Option<String>, Option<String>)> {
-  match config {
-    Some(config) if config.starts_with('{') => Ok((Some(config.to_string()), None)),
-    Some(config) => Ok((
-      Some(std::fs::read_to_string(config).with_context(|| "failed to read custom configuration")?),
-      Some(config.clone()),
-    )),
-    None => Ok((None, None)),
+  let mut sources = Vec::new();
+  let mut merge_config_path = None;
+  for config in configs {
+    if config.starts_with('{') {
+      sources.push(config.to_string());
+    } else {
+      sources.push(
+        std::fs::read_to_string(config).with_context(|| "failed to read custom configuration")?,
+      );
+      merge_config_path.replace(config.clone());
+    }
+  }
+
+  let merge_config = if sources.len() > 1 {
+    let mut merged = serde_json::Value::Object(Default::default());
+    for source in &sources {
+      let source: serde_json::Value =
+        serde_json::from_str(source).with_context(|| "failed to parse config to merge")?;
+      json_patch::merge(&mut merged, &source);
+    }
+    Some(merged.to_string())
+  } else {
+    sources.pop()
+  };
+
+  Ok((merge_config, merge_config_path))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::resolve_merge_config;
+
+  #[test]
+  fn merge_config_sources() {
+    let path = std::env::temp_dir().join(format!("tauri-merge-config-{}.json", std::process::id()));
+    std::fs::write(
+      &path,
+      r#"{ "package": { "productName": "file", "version": "1.0.0" } }"#,
+    )
+    .unwrap();
+    let path = path.to_string_lossy().into_owned();
+
+    let (merge_config, merge_config_path) = resolve_merge_config(&vec![
+      path.clone(),
+      r#"{ "package": { "productName": "inline" } }"#.to_string(),
+    ])
+    .unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let merge_config: serde_json::Value = serde_json::from_str(&merge_config.unwrap()).unwrap();
+    assert_eq!(
+      merge_config,
+      serde_json::json!({ "package": { "productName": "inline", "version": "1.0.0" } })
+    );
+    assert_eq!(merge_config_path, Some(path));
+
+    let inline = r#"{ "build": {} }"#.to_string();
+    assert_eq!(
+      resolve_merge_config(&Some(inline.clone())).unwrap(),
+      (Some(inline), None)
+    );
+    assert_eq!(resolve_merge_config(&None).unwrap(), (None, None));
   }
 }
@@ -25,16 +25,40 @@ use tokio::sync::broadcast::{channel, Sender};
 
 const AUTO_RELOAD_SCRIPT: &str = include_str!("./auto-reload.js");
 
+/// The first port tried when the dev server port is picked automatically.
+const DEFAULT_PORT: u16 = 1430;
+/// The file persisting the automatically picked dev server port, relative to the Tauri directory.
+const PORT_FILE: &str = ".tauri/dev-server-port";
+
+/// Reads the dev server port persisted by [`persist_port`] in the Tauri directory, if any.
+pub fn persisted_port(dir: &Path) -> Option<u16> {
+  std::fs::read_to_string(dir.join(PORT_FILE))
+    .ok()
+    .and_then(|port| port.trim().parse().ok())
+}
+
+/// Persists the dev server port in the Tauri directory so the next run can reuse it.
+pub fn persist_port(dir: &Path, port: u16) -> std::io::Result<()> {
+  let path = dir.join(PORT_FILE);
+  if let Some(parent) = path.parent() {
+    std::fs::create_dir_all(parent)?;
+  }
+  std::fs::write(path, port.to_string())
+}
+
 struct State {
   serve_dir: PathBuf,
   address: SocketAddr,
   tx: Sender<()>,
+  live_reload: bool,
 }
 
 pub fn start_dev_server<P: AsRef<Path>>(
   path: P,
   ip: IpAddr,
   port: Option<u16>,
+  preferred_port: Option<u16>,
+  live_reload: bool,
 ) -> crate::Result<SocketAddr> {
   let serve_dir = path.as_ref().to_path_buf();
 
@@ -74,28 +98,40 @@ pub fn start_dev_server<P: AsRef<Path>>(
         let mut auto_port = false;
         let mut port = port.unwrap_or_else(|| {
           auto_port = true;
-          1430
+          DEFAULT_PORT
         });
 
-        let (server, server_url) = loop {
+        // reuse the preferred port if it is still free
+        let preferred = preferred_port.filter(|_| auto_port).and_then(|port| {
           let server_url = SocketAddr::new(ip, port);
-          let server = Server::try_bind(&server_url);
+          Server::try_bind(&server_url)
+            .ok()
+            .map(|server| (Ok(server), server_url))
+        });
 
-          if !auto_port {
-            break (server, server_url);
-          }
+        let (server, server_url) = match preferred {
+          Some(preferred) => preferred,
+          None => loop {
+            let server_url = SocketAddr::new(ip, port);
+            let server = Server::try_bind(&server_url);
 
-          if server.is_ok() {
-            break (server, server_url);
-          }
+            if !auto_port {
+              break (server, server_url);
+            }
 
-          port += 1;
+            if server.is_ok() {
+              break (server, server_url);
+            }
+
+            port += 1;
+          },
         };
 
         let state = Arc::new(State {
           serve_dir,
           tx,
           address: server_url,
+          live_reload,
         });
         let state_ = state.clone();
         let router = Router::new()
@@ -142,7 +178,7 @@ async fn handler(uri: Uri, state: Arc<State>) -> impl IntoResponse {
   file
     .map(|mut f| {
       let mime_type = MimeType::parse_with_fallback(&f, uri, MimeType::OctetStream);
-      if mime_type == MimeType::Html.to_string() {
+      if state.live_reload && mime_type == MimeType::Html.to_string() {
         let mut document = kuchiki::parse_html().one(String::from_utf8_lossy(&f).into_owned());
         fn with_html_head<F: FnOnce(&NodeRef)>(document: &mut NodeRef, f: F) {
           if let Ok(ref node) = document.select_first("head") {
@@ -195,3 +231,22 @@ async fn ws_handler(mut ws: WebSocket, state: Arc<State>) {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::{persist_port, persisted_port};
+
+  #[test]
+  fn persists_port() {
+    let dir = std::env::temp_dir().join(format!("tauri-dev-server-port-{}", std::process::id()));
+    assert_eq!(persisted_port(&dir), None);
+
+    persist_port(&dir, 1431).unwrap();
+    assert_eq!(persisted_port(&dir), Some(1431));
+
+    std::fs::write(dir.join(super::PORT_FILE), "invalid").unwrap();
+    assert_eq!(persisted_port(&dir), None);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+  }
+}
@@ -50,6 +50,8 @@ pub struct Options {
   pub args: Vec<String>,
   pub config: Option<String>,
   pub no_watch: bool,
+  pub restart_on_crash: bool,
+  pub debugger: Option<String>,
 }
 
 impl From<crate::build::Options> for Options {
@@ -62,6 +64,8 @@ impl From<crate::build::Options> for Options {
       args: options.args,
       config: options.config,
       no_watch: true,
+      restart_on_crash: false,
+      debugger: None,
     }
   }
 }
@@ -74,8 +78,11 @@ impl From<crate::dev::Options> for Options {
       target: options.target,
       features: options.features,
       args: options.args,
-      config: options.config,
+      // the config values are merged into a single one by `dev::setup`
+      config: options.config.into_iter().next(),
       no_watch: options.no_watch,
+      restart_on_crash: options.restart_on_crash,
+      debugger: options.debugger,
     }
   }
 }
@@ -676,6 +683,7 @@ pub struct RustAppSettings {
   cargo_settings: CargoSettings,
   cargo_package_settings: CargoPackageSettings,
   package_settings: PackageSettings,
+  binary_name_override: Option<String>,
   cargo_config: CargoConfig,
   target_triple: String,
   target: Target,
@@ -694,12 +702,11 @@ impl AppSettings for RustAppSettings {
     let arch64bits =
       self.target_triple.starts_with("x86_64") || self.target_triple.starts_with("aarch64");
 
-    tauri_config_to_bundle_settings(
-      &self.manifest,
-      features,
-      config.tauri.bundle.clone(),
-      arch64bits,
-    )
+    // the bundler only reads `icon`, so resolve the platform-specific override here
+    let mut bundle = config.tauri.bundle.clone();
+    bundle.icon = bundle.icons_for(self.target).to_vec();
+
+    tauri_config_to_bundle_settings(&self.manifest, features, bundle, arch64bits)
   }
 
   fn app_binary_path(&self, options: &Options) -> crate::Result<PathBuf> {
@@ -812,7 +819,9 @@ impl AppSettings for RustAppSettings {
 
     match binaries.len() {
       0 => binaries.push(BundleBinary::new(
-        if target_os == "linux" {
+        if let Some(binary_name) = &self.binary_name_override {
+          format!("{binary_name}{binary_extension}")
+        } else if target_os == "linux" {
           self.package_settings.product_name.to_kebab_case()
         } else {
           format!(
@@ -973,6 +982,7 @@ impl RustAppSettings {
       cargo_settings,
       cargo_package_settings,
       package_settings,
+      binary_name_override: config.package.binary_name_override.clone(),
       cargo_config,
       target_triple,
       target,
@@ -1169,6 +1179,20 @@ fn tauri_config_to_bundle_settings(
     None => config.macos.provider_short_name,
   };
 
+  let license_file = match config.license_file {
+    Some(license_file) => {
+      let path = tauri_dir().join(license_file);
+      if !path.exists() {
+        return Err(anyhow::anyhow!(
+          "the `bundle > licenseFile` configuration is set to `{}` but this file doesn't exist",
+          path.display()
+        ));
+      }
+      Some(path)
+    }
+    None => None,
+  };
+
   let (resources, resources_map) = match resources {
     BundleResources::List(paths) => (Some(paths), None),
     BundleResources::Map(map) => (None, Some(map)),
@@ -1191,6 +1215,7 @@ fn tauri_config_to_bundle_settings(
     file_associations: config.file_associations,
     short_description: config.short_description,
     long_description: config.long_description,
+    license_file,
     external_bin: config.external_bin,
     deb: DebianSettings {
       depends: if depends_deb.is_empty() {
@@ -1241,6 +1266,10 @@ fn tauri_config_to_bundle_settings(
       signing_identity,
       provider_short_name,
       entitlements: config.macos.entitlements,
+      info_plist: config.macos.info_plist,
+      exclude_from_signing: config.macos.exclude_from_signing,
+      sign_external_binaries: config.macos.sign_external_binaries,
+      notarization: config.macos.notarization,
       info_plist_path: {
         let path = tauri_dir().join("Info.plist");
         if path.exists() {
@@ -1253,6 +1282,7 @@ fn tauri_config_to_bundle_settings(
     windows: WindowsSettings {
       timestamp_url: config.windows.timestamp_url,
       tsp: config.windows.tsp,
+      sign_external_binaries: config.windows.sign_external_binaries,
       digest_algorithm: config.windows.digest_algorithm,
       certificate_thumbprint: config.windows.certificate_thumbprint,
       wix: config.windows.wix.map(|w| {
@@ -1270,6 +1300,7 @@ fn tauri_config_to_bundle_settings(
       active: config.updater.active,
       pubkey: config.updater.pubkey,
       msiexec_args: Some(config.updater.windows.install_mode.msiexec_args()),
+      create_artifacts: config.updater.create_artifacts,
     }),
     ..Default::default()
   })
@@ -78,6 +78,7 @@ pub fn run_dev<F: Fn(Option<i32>, ExitReason) + Send + Sync + 'static>(
   on_exit: F,
 ) -> crate::Result<impl DevProcess> {
   let bin_path = app_settings.app_binary_path(&options)?;
+  let binary_name_override = app_settings.binary_name_override.clone();
   let target_os = options
     .target
     .as_ref()
@@ -85,6 +86,8 @@ pub fn run_dev<F: Fn(Option<i32>, ExitReason) + Send + Sync + 'static>(
     .unwrap_or(std::env::consts::OS)
     .replace("darwin", "macos");
 
+  let restart_on_crash = options.restart_on_crash;
+  let debugger = options.debugger.clone();
   let manually_killed_app = Arc::new(AtomicBool::default());
   let manually_killed_app_ = manually_killed_app.clone();
   let app_child = Arc::new(Mutex::new(None));
@@ -96,23 +99,33 @@ pub fn run_dev<F: Fn(Option<i32>, ExitReason) + Send + Sync + 'static>(
     config_features,
     move |status, reason| {
       if status == Some(0) {
-        let bin_path =
-          rename_app(target_os, &bin_path, product_name.as_deref()).expect("failed to rename app");
-        let mut app = Command::new(bin_path);
-        app.stdout(os_pipe::dup_stdout().unwrap());
-        app.stderr(os_pipe::dup_stderr().unwrap());
-        app.args(run_args);
-        let app_child = Arc::new(SharedChild::spawn(&mut app).unwrap());
-        crate::dev::wait_dev_process(
-          DevChild {
-            manually_killed_app: manually_killed_app_,
-            build_child: None,
-            app_child: Arc::new(Mutex::new(Some(app_child.clone()))),
-          },
-          on_exit,
-        );
-
-        app_child_.lock().unwrap().replace(app_child);
+        let bin_path = rename_app(
+          target_os,
+          &bin_path,
+          product_name.as_deref(),
+          binary_name_override.as_deref(),
+        )
+        .expect("failed to rename app");
+        let spawn_app = move || {
+          let mut app = app_command(debugger.as_deref(), &bin_path, &run_args);
+          app.stdout(os_pipe::dup_stdout().unwrap());
+          app.stderr(os_pipe::dup_stderr().unwrap());
+          let app_child = Arc::new(SharedChild::spawn(&mut app).unwrap());
+          app_child_.lock().unwrap().replace(app_child.clone());
+          app_child
+        };
+
+        let spawn_dev_child = move || DevChild {
+          manually_killed_app: manually_killed_app_.clone(),
+          build_child: None,
+          app_child: Arc::new(Mutex::new(Some(spawn_app()))),
+        };
+
+        if restart_on_crash {
+          crate::dev::wait_dev_process_restarting(spawn_dev_child, on_exit);
+        } else {
+          crate::dev::wait_dev_process(spawn_dev_child(), on_exit);
+        }
       } else {
         on_exit(
           status,
@@ -133,6 +146,31 @@ pub fn run_dev<F: Fn(Option<i32>, ExitReason) + Send + Sync + 'static>(
   })
 }
 
+/// The command running the app binary with its arguments, under the debugger if set.
+fn app_command(debugger: Option<&str>, bin_path: &Path, args: &[String]) -> Command {
+  let Some(debugger) = debugger else {
+    let mut app = Command::new(bin_path);
+    app.args(args);
+    return app;
+  };
+
+  let mut debugger_args = debugger.split_whitespace();
+  let program = debugger_args.next().unwrap_or(debugger);
+  let mut app = Command::new(program);
+  app.args(debugger_args);
+  match Path::new(program).file_stem().and_then(|s| s.to_str()) {
+    Some("lldb" | "rust-lldb") => {
+      app.arg("--");
+    }
+    Some("gdb" | "rust-gdb") => {
+      app.arg("--args");
+    }
+    _ => {}
+  }
+  app.arg(bin_path).args(args);
+  app
+}
+
 pub fn build(
   options: Options,
   app_settings: &RustAppSettings,
@@ -189,7 +227,12 @@ pub fn build(
       .with_context(|| "failed to build app")?;
   }
 
-  rename_app(target_os, &bin_path, product_name.as_deref())?;
+  rename_app(
+    target_os,
+    &bin_path,
+    product_name.as_deref(),
+    app_settings.binary_name_override.as_deref(),
+  )?;
 
   Ok(())
 }
@@ -396,18 +439,19 @@ fn rename_app(
   target_os: String,
   bin_path: &Path,
   product_name: Option<&str>,
+  binary_name_override: Option<&str>,
 ) -> crate::Result<PathBuf> {
-  if let Some(product_name) = product_name {
-    let product_name = if target_os == "linux" {
-      product_name.to_kebab_case()
-    } else {
-      product_name.into()
-    };
+  let binary_name = match (binary_name_override, product_name) {
+    (Some(binary_name), _) => Some(binary_name.to_string()),
+    (None, Some(product_name)) if target_os == "linux" => Some(product_name.to_kebab_case()),
+    (None, product_name) => product_name.map(Into::into),
+  };
 
+  if let Some(binary_name) = binary_name {
     let product_path = bin_path
       .parent()
       .unwrap()
-      .join(product_name)
+      .join(binary_name)
       .with_extension(bin_path.extension().unwrap_or_default());
 
     rename(bin_path, &product_path).with_context(|| {
@@ -501,3 +545,38 @@ mod terminal {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::app_command;
+  use std::{ffi::OsStr, path::Path, process::Command};
+
+  fn command_line(command: &Command) -> Vec<&OsStr> {
+    std::iter::once(command.get_program())
+      .chain(command.get_args())
+      .collect()
+  }
+
+  #[test]
+  fn launches_app_under_debugger() {
+    let bin = Path::new("target/debug/app");
+    let args = ["--flag".to_string()];
+
+    assert_eq!(
+      command_line(&app_command(None, bin, &args)),
+      ["target/debug/app", "--flag"]
+    );
+    assert_eq!(
+      command_line(&app_command(Some("rust-lldb"), bin, &args)),
+      ["rust-lldb", "--", "target/debug/app", "--flag"]
+    );
+    assert_eq!(
+      command_line(&app_command(Some("gdb -q"), bin, &args)),
+      ["gdb", "-q", "--args", "target/debug/app", "--flag"]
+    );
+    assert_eq!(
+      command_line(&app_command(Some("valgrind"), bin, &args)),
+      ["valgrind", "target/debug/app", "--flag"]
+    );
+  }
+}
@@ -156,6 +156,22 @@ where
     None => Cli::command(),
   };
   let cli_ = cli.clone();
+
+  let mut args = args.into_iter().map(Into::into).collect::<Vec<OsString>>();
+  // `tauri dev` accepts `@file` response files, which must be expanded before parsing
+  if args
+    .iter()
+    .find(|arg| !arg.to_string_lossy().starts_with('-'))
+    .is_some_and(|command| command == "dev")
+  {
+    args = match dev::expand_response_files(args) {
+      Ok(args) => args,
+      Err(e) => clap::Error::raw(clap::error::ErrorKind::Io, format!("{e}\n"))
+        .format(&mut cli.clone())
+        .exit(),
+    };
+  }
+
   let matches = cli.get_matches_from(args);
 
   let res = Cli::from_arg_matches(&matches).map_err(format_error::<Cli>);
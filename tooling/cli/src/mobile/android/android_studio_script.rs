@@ -42,7 +42,11 @@ pub fn command(options: Options) -> Result<()> {
     let tauri_config_guard = tauri_config.lock().unwrap();
     let tauri_config_ = tauri_config_guard.as_ref().unwrap();
     let cli_options = read_options(&tauri_config_.tauri.bundle.identifier);
-    let (config, metadata) = get_config(&get_app(tauri_config_), tauri_config_, &cli_options);
+    let (config, metadata) = get_config(
+      &get_app(MobileTarget::Android, tauri_config_),
+      tauri_config_,
+      &cli_options,
+    );
     (config, metadata, cli_options)
   };
   ensure_init(config.project_dir(), MobileTarget::Android)?;
@@ -94,7 +94,7 @@ pub fn command(mut options: Options, noise_level: NoiseLevel) -> Result<()> {
   let (app, config, metadata) = {
     let tauri_config_guard = tauri_config.lock().unwrap();
     let tauri_config_ = tauri_config_guard.as_ref().unwrap();
-    let app = get_app(tauri_config_);
+    let app = get_app(MobileTarget::Android, tauri_config_);
     let (config, metadata) = get_config(&app, tauri_config_, &Default::default());
     (app, config, metadata)
   };
@@ -64,6 +64,9 @@ pub struct Options {
   /// Skip waiting for the frontend dev server to start before building the tauri application.
   #[clap(long, env = "TAURI_CLI_NO_DEV_SERVER_WAIT")]
   pub no_dev_server_wait: bool,
+  /// Skip running the `build.beforeDevCommand`, e.g. when the frontend dev server is started manually.
+  #[clap(long)]
+  pub no_before_dev_command: bool,
   /// Disable the file watcher
   #[clap(long)]
   pub no_watch: bool,
@@ -79,8 +82,18 @@ pub struct Options {
   #[clap(long)]
   pub no_dev_server: bool,
   /// Specify port for the built-in dev server for static files. Defaults to 1430.
+  ///
+  /// When not set, the port picked by the previous run is reused if it is still free,
+  /// so the dev server URL does not change across restarts.
+  /// It is persisted in the `.tauri/dev-server-port` file of the `src-tauri` directory.
   #[clap(long, env = "TAURI_CLI_PORT")]
   pub port: Option<u16>,
+  /// Do not reuse the built-in dev server port of the previous run, picking the first free port from 1430 instead.
+  #[clap(long)]
+  pub fresh_port: bool,
+  /// Allow the dev server origin on the development CSP, see `tauri dev --help`.
+  #[clap(long)]
+  pub relax_csp: bool,
 }
 
 impl From<Options> for DevOptions {
@@ -89,13 +102,21 @@ impl From<Options> for DevOptions {
       runner: None,
       target: None,
       features: options.features,
+      no_auto_features: false,
       exit_on_panic: options.exit_on_panic,
-      config: options.config,
+      restart_on_crash: false,
+      debugger: None,
+      config: options.config.into_iter().collect(),
+      print_config: false,
       args: Vec::new(),
       no_watch: options.no_watch,
       no_dev_server_wait: options.no_dev_server_wait,
+      no_before_dev_command: options.no_before_dev_command,
       no_dev_server: options.no_dev_server,
       port: options.port,
+      fresh_port: options.fresh_port,
+      relax_csp: options.relax_csp,
+      no_restore_geometry: true,
       force_ip_prompt: options.force_ip_prompt,
       release_mode: options.release_mode,
     }
@@ -124,7 +145,7 @@ fn run_command(mut options: Options, noise_level: NoiseLevel) -> Result<()> {
   let (app, config, metadata) = {
     let tauri_config_guard = tauri_config.lock().unwrap();
     let tauri_config_ = tauri_config_guard.as_ref().unwrap();
-    let app = get_app(tauri_config_);
+    let app = get_app(MobileTarget::Android, tauri_config_);
     let (config, metadata) = get_config(&app, tauri_config_, &Default::default());
     (app, config, metadata)
   };
@@ -129,7 +129,13 @@ pub fn get_config(
           }
         }),
     ios_features: ios_options.features.clone(),
-    bundle_version: config.package.version.clone(),
+    bundle_version: config
+      .tauri
+      .bundle
+      .ios
+      .build_number
+      .clone()
+      .or_else(|| config.package.version.clone()),
     bundle_version_short: config.package.version.clone(),
     ios_version: Some(TARGET_IOS_VERSION.into()),
     ..Default::default()
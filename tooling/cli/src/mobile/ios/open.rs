@@ -13,7 +13,11 @@ pub fn command() -> Result<()> {
   let (config, _metadata) = {
     let tauri_config_guard = tauri_config.lock().unwrap();
     let tauri_config_ = tauri_config_guard.as_ref().unwrap();
-    get_config(&get_app(tauri_config_), tauri_config_, &Default::default())
+    get_config(
+      &get_app(MobileTarget::Ios, tauri_config_),
+      tauri_config_,
+      &Default::default(),
+    )
   };
 
   ensure_init(config.project_dir(), MobileTarget::Ios)?;
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
-use super::{env, get_app, get_config, read_options};
+use super::{env, get_app, get_config, read_options, MobileTarget};
 use crate::{
   helpers::config::get as get_tauri_config,
   interface::{AppInterface, AppSettings, Interface, Options as InterfaceOptions},
@@ -74,7 +74,11 @@ pub fn command(options: Options) -> Result<()> {
     let tauri_config_guard = tauri_config.lock().unwrap();
     let tauri_config_ = tauri_config_guard.as_ref().unwrap();
     let cli_options = read_options(&tauri_config_.tauri.bundle.identifier);
-    let (config, metadata) = get_config(&get_app(tauri_config_), tauri_config_, &cli_options);
+    let (config, metadata) = get_config(
+      &get_app(MobileTarget::Ios, tauri_config_),
+      tauri_config_,
+      &cli_options,
+    );
     (config, metadata, cli_options)
   };
 
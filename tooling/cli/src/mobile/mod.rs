@@ -272,8 +272,9 @@ fn read_options(identifier: &str) -> CliOptions {
   options
 }
 
-pub fn get_app(config: &TauriConfig) -> App {
-  let mut s = config.tauri.bundle.identifier.rsplit('.');
+pub fn get_app(target: Target, config: &TauriConfig) -> App {
+  let identifier = config.tauri.bundle.identifier_for(target.platform_target());
+  let mut s = identifier.rsplit('.');
   let app_name = s.next().unwrap_or("app").to_string();
   let mut domain = String::new();
   for w in s {
@@ -281,7 +282,7 @@ pub fn get_app(config: &TauriConfig) -> App {
     domain.push('.');
   }
   if domain.is_empty() {
-    domain = config.tauri.bundle.identifier.clone();
+    domain = identifier.to_string();
     if domain.is_empty() {
       log::error!(
         "Bundle identifier set in `tauri.conf.json > tauri > bundle > identifier` cannot be empty"